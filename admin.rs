@@ -0,0 +1,523 @@
+// Administration: ownership, the multisig/proposal path, roles, selector guards,
+// reentrancy and treasury accounting.
+//
+// Included into `mod simple_token` from `lib.rs`; see the note there.
+
+impl SimpleToken {
+    /// Internal helper that rejects token movements while the contract is paused
+    #[cfg(feature = "pause")]
+    fn ensure_not_paused(&self) -> Result<()> {
+        if self.is_paused {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Without the `pause` feature the contract can never be paused
+    #[cfg(not(feature = "pause"))]
+    #[inline(always)]
+    fn ensure_not_paused(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Internal helper that only lets the owner through
+    fn ensure_owner(&self) -> Result<()> {
+        if self.env().caller() != self.owner {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Internal helper that hands ownership over and clears every pending
+    /// ownership-change path (scheduled transfer, recovery, dead-man switch)
+    fn set_owner(&mut self, new_owner: AccountId) {
+        self.owner = new_owner;
+        self.pending_ownership_transfer = None;
+        self.recovery = None;
+        self.successor = None;
+        self.heartbeat_interval = 0;
+        self.last_heartbeat = self.env().block_timestamp();
+    }
+
+    /// Internal helper for privileged operations: owner only, and only while
+    /// no multisig is configured (otherwise they must be proposed)
+    fn ensure_privileged(&self) -> Result<()> {
+        // A confirmed proposal acts with owner rights; `execute_action` already
+        // checked the emergency stop
+        if self.executing_proposal {
+            return Ok(());
+        }
+        self.ensure_owner()?;
+        if self.multisig_threshold > 0 {
+            return Err(Error::MultisigRequired);
+        }
+        self.ensure_no_emergency()
+    }
+
+    /// Internal helper that rejects the call while the emergency stop is active
+    fn ensure_no_emergency(&self) -> Result<()> {
+        if self.emergency_stopped_at.is_some() {
+            return Err(Error::EmergencyStopActive);
+        }
+        Ok(())
+    }
+
+    /// Shared guard run at the start of every state-changing message
+    ///
+    /// Rejects the call if it re-enters during a recipient callback, if the
+    /// message was paused individually, or if it was restricted to a role the
+    /// caller does not hold.
+    fn guard_selector(&self, selector: [u8; 4]) -> Result<()> {
+        if self.reentrancy_lock.get().unwrap_or(false) {
+            return Err(Error::ReentrancyDetected);
+        }
+        if self.paused_selectors.get(selector).unwrap_or(false) {
+            return Err(Error::SelectorPaused);
+        }
+        if let Some(role) = self.selector_access.get(selector) {
+            if !self.check_role(role, self.env().caller()) {
+                return Err(Error::SelectorAccessDenied);
+            }
+        }
+        Ok(())
+    }
+
+    /// Internal helper that keeps the selector guard setters out of their own reach,
+    /// so pausing or restricting them cannot lock the guard in place
+    fn ensure_unprotected_selector(selector: [u8; 4]) -> Result<()> {
+        if selector == ink::selector_bytes!("set_selector_paused")
+            || selector == ink::selector_bytes!("set_selector_access")
+        {
+            return Err(Error::ProtectedSelector);
+        }
+        Ok(())
+    }
+
+    /// Internal helper that asks a contract new owner whether it can act as owner
+    ///
+    /// Plain accounts always pass; contracts must return `true` from
+    /// `accepts_ownership()`.
+    fn ensure_accepts_ownership(&self, new_owner: AccountId) -> Result<()> {
+        if !self.env().is_contract(&new_owner) {
+            return Ok(());
+        }
+        let gas_limit = self.callback_gas_limit(new_owner)?;
+
+        let result = build_call::<Environment>()
+            .call(new_owner)
+            .ref_time_limit(gas_limit)
+            .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                "accepts_ownership"
+            ))))
+            .returns::<bool>()
+            .try_invoke();
+
+        match result {
+            Ok(Ok(true)) => Ok(()),
+            _ => Err(Error::OwnershipNotAccepted),
+        }
+    }
+
+    /// Internal helper: weight cap for a callback to `callee`, which must be whitelisted
+    fn callback_gas_limit(&self, callee: AccountId) -> Result<u64> {
+        self.callback_gas_limits
+            .get(callee)
+            .ok_or(Error::CalleeNotWhitelisted)
+    }
+
+    /// Internal helper that takes the reentrancy lock before calling out
+    fn enter_non_reentrant(&mut self) -> Result<()> {
+        if self.reentrancy_lock.get().unwrap_or(false) {
+            return Err(Error::ReentrancyDetected);
+        }
+        self.reentrancy_lock.set(&true);
+        Ok(())
+    }
+
+    /// Internal helper that releases the reentrancy lock
+    fn exit_non_reentrant(&mut self) {
+        self.reentrancy_lock.set(&false);
+    }
+
+    /// Internal helper to check if account holds a role
+    fn check_role(&self, role: RoleId, account: AccountId) -> bool {
+        self.roles.get((role, account)).unwrap_or(false)
+    }
+
+    /// Internal helper that only lets holders of `role` through
+    fn ensure_role(&self, role: RoleId) -> Result<()> {
+        if !self.check_role(role, self.env().caller()) {
+            return Err(Error::MissingRole);
+        }
+        // Role holders are admins too, so the emergency stop freezes them as well
+        self.ensure_no_emergency()
+    }
+
+    /// Internal helper that lets the owner (through the multisig while one is
+    /// active) or members of `role`'s admin role through
+    fn ensure_role_admin(&self, role: RoleId) -> Result<()> {
+        let caller = self.env().caller();
+        if caller == self.owner || self.executing_proposal {
+            return self.ensure_privileged();
+        }
+        self.ensure_no_emergency()?;
+        match self.role_admins.get(role) {
+            Some(admin_role) if self.check_role(admin_role, caller) => Ok(()),
+            _ => Err(Error::Unauthorized),
+        }
+    }
+
+    /// Internal helper that adds a role member, keeping the member list enumerable
+    fn add_role_member(&mut self, role: RoleId, account: AccountId) -> Result<()> {
+        if self.check_role(role, account) {
+            return Ok(());
+        }
+
+        let count = self.role_member_count.get(role).unwrap_or(0);
+        self.roles.insert((role, account), &true);
+        self.role_members.insert((role, count), &account);
+        self.role_member_index.insert((role, account), &count);
+        self.role_member_count
+            .insert(role, &count.checked_add(1).ok_or(Error::Overflow)?);
+
+        self.env().emit_event(RoleGranted {
+            role,
+            account,
+            by: self.env().caller(),
+        });
+        self.record_admin_action(AdminLogAction::GrantRole { role, account });
+
+        Ok(())
+    }
+
+    /// Internal helper that removes a role member by swapping in the last member
+    fn remove_role_member(&mut self, role: RoleId, account: AccountId) {
+        if !self.check_role(role, account) {
+            return;
+        }
+
+        let count = self.role_member_count.get(role).unwrap_or(0);
+        let index = self.role_member_index.get((role, account)).unwrap_or(0);
+        let last_index = count.saturating_sub(1);
+
+        // Move the last member into the freed slot
+        if index != last_index {
+            if let Some(last) = self.role_members.get((role, last_index)) {
+                self.role_members.insert((role, index), &last);
+                self.role_member_index.insert((role, last), &index);
+            }
+        }
+
+        self.role_members.remove((role, last_index));
+        self.role_member_index.remove((role, account));
+        self.role_member_count.insert(role, &last_index);
+        self.roles.remove((role, account));
+
+        self.env().emit_event(RoleRevoked {
+            role,
+            account,
+            by: self.env().caller(),
+        });
+        self.record_admin_action(AdminLogAction::RevokeRole { role, account });
+    }
+
+    /// Internal helper to check if account is a multisig admin
+    fn is_multisig_admin(&self, account: AccountId) -> bool {
+        self.multisig_admins.contains(&account)
+    }
+
+    /// Internal helper that pays out of the contract-held treasury
+    fn pay_from_treasury(&mut self, id: Option<u32>, to: AccountId, amount: Balance) -> Result<()> {
+        self.consume_treasury_limit(amount)?;
+
+        let treasury = self.env().account_id();
+        self._transfer(treasury, to, amount)?;
+
+        self.env().emit_event(TreasurySpendExecuted { id, to, amount });
+
+        Ok(())
+    }
+
+    /// Internal helper: treasury spending period as (start, base, spent) as of now
+    ///
+    /// A new period starts once the previous one has run out, measured against
+    /// the treasury balance at that moment.
+    fn current_treasury_period(&self, period: u64) -> (u64, Balance, Balance) {
+        let now = self.env().block_timestamp();
+        if now.saturating_sub(self.treasury_period_start) >= period {
+            (now, self.balance_of(self.env().account_id()), 0)
+        } else {
+            (
+                self.treasury_period_start,
+                self.treasury_period_base,
+                self.treasury_spent_in_period,
+            )
+        }
+    }
+
+    /// Internal helper that counts a payment against the treasury spending cap
+    fn consume_treasury_limit(&mut self, amount: Balance) -> Result<()> {
+        let Some((bps, period)) = self.treasury_spend_limit else {
+            return Ok(());
+        };
+
+        let (start, base, spent) = self.current_treasury_period(period);
+        let cap = Self::mul_div(base, Balance::from(bps), BPS_DENOMINATOR).ok_or(Error::Overflow)?;
+        let spent = spent.checked_add(amount).ok_or(Error::Overflow)?;
+        if spent > cap {
+            return Err(Error::TreasuryLimitExceeded);
+        }
+
+        self.treasury_period_start = start;
+        self.treasury_period_base = base;
+        self.treasury_spent_in_period = spent;
+        Ok(())
+    }
+
+    /// Internal helper that flips the pause flag and emits the matching event
+    #[cfg(feature = "pause")]
+    fn set_paused(&mut self, paused: bool) {
+        self.is_paused = paused;
+        let by = self.env().caller();
+        let timestamp = self.env().block_timestamp();
+        if paused {
+            self.env().emit_event(Paused { by, timestamp });
+            self.record_admin_action(AdminLogAction::Pause);
+        } else {
+            self.env().emit_event(Unpaused { by, timestamp });
+            self.record_admin_action(AdminLogAction::Unpause);
+        }
+    }
+
+    /// Internal helper that appends a privileged action to the audit log
+    ///
+    /// Once `ADMIN_LOG_CAPACITY` entries exist the oldest one is overwritten.
+    fn record_admin_action(&mut self, action: AdminLogAction) {
+        let entry = AdminLogEntry {
+            actor: self.env().caller(),
+            action,
+            timestamp: self.env().block_timestamp(),
+        };
+        self.admin_log.insert(self.admin_log_len % ADMIN_LOG_CAPACITY, &entry);
+        self.admin_log_len = self.admin_log_len.saturating_add(1);
+    }
+
+    /// Internal helper that validates and stores a new multisig configuration
+    ///
+    /// Duplicate admins are dropped, and proposals opened under the previous
+    /// admin set can no longer be confirmed.
+    fn set_multisig(&mut self, admins: Vec<AccountId>, threshold: u32) -> Result<()> {
+        let mut unique: Vec<AccountId> = Vec::new();
+        for admin in admins {
+            if !unique.contains(&admin) {
+                unique.push(admin);
+            }
+        }
+        let admins = unique;
+
+        // Validate: threshold 0 disables the multisig (with no admins), otherwise it must be reachable
+        if threshold as usize > admins.len() || (threshold == 0 && !admins.is_empty()) {
+            return Err(Error::InvalidThreshold);
+        }
+
+        self.multisig_admins = admins.clone();
+        self.multisig_threshold = threshold;
+        self.multisig_epoch = self.multisig_epoch.wrapping_add(1);
+
+        self.env().emit_event(MultisigConfigured { admins, threshold });
+
+        Ok(())
+    }
+
+    /// Internal helper that runs an approved multisig action
+    fn execute_action(&mut self, action: AdminAction) -> Result<()> {
+        // The emergency stop freezes the multisig as well
+        self.ensure_no_emergency()?;
+
+        match action {
+            AdminAction::Mint { to, amount } => {
+                self._mint(to, amount)?;
+                self.record_admin_action(AdminLogAction::Mint { to, amount });
+                Ok(())
+            }
+            #[cfg(feature = "pause")]
+            AdminAction::Pause => {
+                self.set_paused(true);
+                Ok(())
+            }
+            #[cfg(feature = "pause")]
+            AdminAction::Unpause => {
+                self.set_paused(false);
+                Ok(())
+            }
+            #[cfg(feature = "blacklist")]
+            AdminAction::Blacklist(account) => {
+                self.set_blacklisted(account, true);
+                Ok(())
+            }
+            #[cfg(feature = "blacklist")]
+            AdminAction::Unblacklist(account) => {
+                self.set_blacklisted(account, false);
+                Ok(())
+            }
+            AdminAction::SetMultisig { admins, threshold } => {
+                self.set_multisig(admins, threshold)
+            }
+            #[cfg(feature = "fees")]
+            AdminAction::ConfigureFees { fee_bps, routes } => self.set_fees(fee_bps, routes),
+            AdminAction::TreasurySpend { to, amount } => self.pay_from_treasury(None, to, amount),
+            action => {
+                // The rest run through their own messages with owner rights
+                self.executing_proposal = true;
+                let result = self.execute_message_action(action);
+                self.executing_proposal = false;
+                result
+            }
+        }
+    }
+
+    /// Internal helper that runs a proposed action through the message it names
+    fn execute_message_action(&mut self, action: AdminAction) -> Result<()> {
+        match action {
+            AdminAction::SafeMint { to, amount, data } => self.safe_mint(to, amount, data),
+            AdminAction::MintWithVesting {
+                to,
+                amount,
+                start,
+                cliff,
+                duration,
+            } => self.mint_with_vesting(to, amount, start, cliff, duration),
+            #[cfg(feature = "blacklist")]
+            AdminAction::EscalateBlacklist(account) => self.escalate_blacklist(account),
+            AdminAction::SetMinterAllowance { minter, amount } => self.set_minter_allowance(minter, amount),
+            AdminAction::SetMinterDailyQuota { minter, quota } => self.set_minter_daily_quota(minter, quota),
+            AdminAction::SetTreasurySpendLimit(limit) => self.set_treasury_spend_limit(limit),
+            AdminAction::BuybackAndBurn {
+                max_spend,
+                min_tokens_out,
+            } => self.buyback_and_burn(max_spend, min_tokens_out).map(|_| ()),
+            AdminAction::ConfigureLottery { epoch_length } => self.configure_lottery(epoch_length),
+            AdminAction::SetIncidentMode { active } => self.set_incident_mode(active),
+            #[cfg(feature = "pause")]
+            AdminAction::SetCircuitBreaker { threshold, window } => self.set_circuit_breaker(threshold, window),
+            AdminAction::SetBridge { bridge, cap } => self.set_bridge(bridge, cap),
+            AdminAction::RemoveBridge(bridge) => self.remove_bridge(bridge),
+            AdminAction::SetBridgeRateLimits { bridge, mint, burn } => {
+                self.set_bridge_rate_limits(bridge, mint, burn)
+            }
+            AdminAction::SetBalanceObserver(observer) => self.set_balance_observer(observer),
+            AdminAction::SetCallbackGasLimit { callee, gas_limit } => {
+                self.set_callback_gas_limit(callee, gas_limit)
+            }
+            AdminAction::ConfigureEmission {
+                distributor,
+                amount_per_era,
+                era_length,
+                halving_interval,
+            } => self.configure_emission(distributor, amount_per_era, era_length, halving_interval),
+            AdminAction::QueueCapChange(new_cap) => self.queue_cap_change(new_cap),
+            AdminAction::CancelCapChange => self.cancel_cap_change(),
+            AdminAction::SetTokenUri(uri) => self.set_token_uri(uri),
+            AdminAction::SetSelectorPaused { selector, paused } => {
+                self.set_selector_paused(selector, paused)
+            }
+            AdminAction::SetSelectorAccess { selector, role } => self.set_selector_access(selector, role),
+            AdminAction::SetChainGenesis(genesis_hash) => self.set_chain_genesis(genesis_hash),
+            AdminAction::SetGuardian(guardian) => self.set_guardian(guardian),
+            #[cfg(feature = "blacklist")]
+            AdminAction::SetBlacklistGracePeriod(period) => self.set_blacklist_grace_period(period),
+            AdminAction::RescueToken {
+                token_address,
+                to,
+                amount,
+            } => self.rescue_token(token_address, to, amount),
+            AdminAction::WithdrawNative { to, amount } => self.withdraw_native(to, amount),
+            AdminAction::SetRoleAdmin { role, admin_role } => self.set_role_admin(role, admin_role),
+            AdminAction::GrantRole { role, account } => AccessControl::grant_role(self, role, account),
+            AdminAction::RevokeRole { role, account } => AccessControl::revoke_role(self, role, account),
+            AdminAction::SetMintRequestTtl(ttl) => self.set_mint_request_ttl(ttl),
+            AdminAction::SetBurnRequestDelay(delay) => self.set_burn_request_delay(delay),
+            #[cfg(feature = "fees")]
+            AdminAction::SetFeeDiscountTiers(tiers) => self.set_fee_discount_tiers(tiers),
+            AdminAction::CancelTreasurySpend(spend_id) => self.cancel_treasury_spend(spend_id),
+            AdminAction::SetBuybackRouter(router) => self.set_buyback_router(router),
+            AdminAction::ConfigureOracle {
+                oracle,
+                max_age,
+                fallback_price,
+            } => self.configure_oracle(oracle, max_age, fallback_price),
+            AdminAction::EnableTrading => self.enable_trading(),
+            AdminAction::SetLaunchBlocks(launch_blocks) => self.set_launch_blocks(launch_blocks),
+            AdminAction::SetLaunchWhitelisted { account, whitelisted } => {
+                self.set_launch_whitelisted(account, whitelisted)
+            }
+            AdminAction::SetSellLimit { limit, window_blocks } => {
+                self.set_sell_limit(limit, window_blocks)
+            }
+            AdminAction::SetMarketAccount { account, market } => self.set_market_account(account, market),
+            AdminAction::SetSellLimitExempt { account, exempt } => {
+                self.set_sell_limit_exempt(account, exempt)
+            }
+            AdminAction::SetContractRecipientPolicy(policy) => self.set_contract_recipient_policy(policy),
+            AdminAction::SetCodeHashAccepted { code_hash, accepted } => {
+                self.set_code_hash_accepted(code_hash, accepted)
+            }
+            AdminAction::ReindexHolders { start, limit } => self.reindex_holders(start, limit).map(|_| ()),
+            AdminAction::SetClaimSponsor { sponsor, allowed } => self.set_claim_sponsor(sponsor, allowed),
+            AdminAction::DepositDividend { amount, expires_at } => {
+                self.guard_selector(ink::selector_bytes!("deposit_dividend"))?;
+                self.ensure_native_available(amount)?;
+                self.start_dividend(amount, expires_at).map(|_| ())
+            }
+            AdminAction::SweepDividend { id, to } => self.sweep_dividend(id, to).map(|_| ()),
+            AdminAction::Snapshot => self.snapshot().map(|_| ()),
+            AdminAction::SetRewardToken { token, rewards_account } => {
+                self.set_reward_token(token, rewards_account)
+            }
+            AdminAction::FundVeRewards {
+                native_amount,
+                token_amount,
+            } => {
+                self.guard_selector(ink::selector_bytes!("fund_ve_rewards"))?;
+                self.ensure_native_available(native_amount)?;
+                self.add_ve_rewards(native_amount, token_amount)
+            }
+            AdminAction::SetLargeTransferThreshold(threshold) => {
+                self.set_large_transfer_threshold(threshold)
+            }
+            AdminAction::SetOwnershipProbeRequired(required) => {
+                self.set_ownership_probe_required(required)
+            }
+            AdminAction::SetRecoveryGuardians {
+                guardians,
+                threshold,
+                window,
+                delay,
+            } => self.set_recovery_guardians(guardians, threshold, window, delay),
+            AdminAction::CancelRecovery => self.cancel_recovery(),
+            AdminAction::ConfigureDeadManSwitch { successor, interval } => {
+                self.configure_dead_man_switch(successor, interval)
+            }
+            AdminAction::Heartbeat => self.heartbeat(),
+            AdminAction::ScheduleOwnershipTransfer { new_owner, effective_at } => {
+                Ownable::schedule_ownership_transfer(self, new_owner, effective_at)
+            }
+            AdminAction::CancelOwnershipTransfer => Ownable::cancel_ownership_transfer(self),
+            AdminAction::SetIncidentRecoveryAddress(recovery_address) => {
+                self.set_incident_recovery_address(recovery_address)
+            }
+            // Handled directly by `execute_action`
+            _ => Err(Error::Unauthorized),
+        }
+    }
+
+    /// Internal helper that checks the contract holds `amount` of native currency
+    /// beyond what is owed to dividend and locker reward claimants
+    fn ensure_native_available(&self, amount: Balance) -> Result<()> {
+        let reserved = self.dividend_reserve.saturating_add(self.ve_rewards_outstanding.0);
+        if amount > self.env().balance().saturating_sub(reserved) {
+            return Err(Error::InsufficientBalance);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,272 @@
+// Compliance: blacklisting, launch and sell limits, freezes, recipient policy,
+// velocity/volume tracking and seizure escrow.
+//
+// Included into `mod simple_token` from `lib.rs`; see the note there.
+
+impl SimpleToken {
+    /// Internal helper to check if account is blacklisted
+    ///
+    /// A scheduled blacklisting counts once its grace period has run out.
+    #[cfg(feature = "blacklist")]
+    fn check_blacklisted(&self, account: AccountId) -> bool {
+        self.blacklist.get(account).unwrap_or(false)
+            || self
+                .pending_blacklist
+                .get(account)
+                .is_some_and(|effective_at| self.env().block_timestamp() >= effective_at)
+    }
+
+    /// Without the `blacklist` feature nobody is blacklisted and the checks compile out
+    #[cfg(not(feature = "blacklist"))]
+    #[inline(always)]
+    fn check_blacklisted(&self, _account: AccountId) -> bool {
+        false
+    }
+
+    /// Internal helper that restricts token movements while incident mode is on
+    ///
+    /// Only the owner may move tokens freely; everyone else can only send to
+    /// the recovery address.
+    fn ensure_incident_path(&self, from: AccountId, to: AccountId) -> Result<()> {
+        if self.incident_mode && from != self.owner && self.incident_recovery_address != Some(to) {
+            return Err(Error::IncidentModeActive);
+        }
+        Ok(())
+    }
+
+    /// Internal helper that rejects trades before launch and during the launch blocks
+    ///
+    /// A transfer goes through if either side is the owner, this contract or
+    /// whitelisted, so liquidity can be seeded and whitelisted buyers served.
+    fn ensure_trading_open(&self, from: AccountId, to: AccountId) -> Result<()> {
+        if let Some(enabled_at) = self.trading_enabled_at {
+            if self.env().block_number() >= enabled_at.saturating_add(self.launch_blocks) {
+                return Ok(());
+            }
+        }
+
+        if self.is_launch_exempt(from) || self.is_launch_exempt(to) {
+            return Ok(());
+        }
+        Err(Error::TradingNotOpen)
+    }
+
+    /// Internal helper: whether an account can trade while launch protection applies
+    fn is_launch_exempt(&self, account: AccountId) -> bool {
+        account == self.owner
+            || account == self.env().account_id()
+            || self.launch_whitelist.get(account).unwrap_or(false)
+    }
+
+    /// Internal helper that counts a transfer to a market account against the sell limit
+    fn record_sell(&mut self, from: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+        let Some(limit) = self.sell_limit else {
+            return Ok(());
+        };
+        if !self.market_accounts.get(to).unwrap_or(false)
+            || from == self.owner
+            || from == self.env().account_id()
+            || self.sell_limit_exempt.get(from).unwrap_or(false)
+        {
+            return Ok(());
+        }
+
+        // Start a new window once the previous one has passed
+        let now = self.env().block_number();
+        let (window_start, sold) = match self.sells_in_window.get(from) {
+            Some((start, sold)) if now < start.saturating_add(self.sell_window_blocks) => (start, sold),
+            _ => (now, 0),
+        };
+
+        let sold = sold.checked_add(amount).ok_or(Error::Overflow)?;
+        if sold > limit {
+            return Err(Error::SellLimitExceeded);
+        }
+        self.sells_in_window.insert(from, &(window_start, sold));
+
+        Ok(())
+    }
+
+    /// Internal helper that applies the contract-recipient policy to `to`
+    ///
+    /// Plain accounts and this contract itself are always accepted.
+    fn ensure_recipient_accepted(&self, to: AccountId) -> Result<()> {
+        if to == self.env().account_id() || !self.env().is_contract(&to) {
+            return Ok(());
+        }
+
+        let accepted = match self.contract_recipient_policy {
+            ContractRecipientPolicy::Allow => true,
+            ContractRecipientPolicy::Deny => false,
+            ContractRecipientPolicy::AcceptList => self
+                .env()
+                .code_hash(&to)
+                .map(|code_hash| self.accepted_code_hashes.get(code_hash).unwrap_or(false))
+                .unwrap_or(false),
+        };
+        if !accepted {
+            return Err(Error::ContractRecipientRejected);
+        }
+        Ok(())
+    }
+
+    /// Internal helper that rejects spending from an account its guardians froze
+    fn ensure_not_frozen(&self, account: AccountId) -> Result<()> {
+        if self.frozen_accounts.get(account).unwrap_or(false) || self.risk_frozen.get(account).unwrap_or(false) {
+            return Err(Error::AccountFrozen);
+        }
+        Ok(())
+    }
+
+    /// Internal helper that discards all open freeze votes for an account
+    fn reset_freeze_votes(&mut self, account: AccountId) {
+        let round = self.freeze_rounds.get(account).unwrap_or(0);
+        self.freeze_rounds.insert(account, &round.wrapping_add(1));
+    }
+
+    /// Internal helper that adds or removes an account from the blacklist
+    #[cfg(feature = "blacklist")]
+    fn set_blacklisted(&mut self, account: AccountId, blacklisted: bool) {
+        let by = self.env().caller();
+        let listed = self.check_blacklisted(account);
+        if blacklisted {
+            if !listed {
+                let index = self.blacklist_count;
+                self.blacklist_members.insert(index, &account);
+                self.blacklist_index.insert(account, &index);
+                self.blacklist_count = index.saturating_add(1);
+            }
+            self.blacklist.insert(account, &true);
+            self.env().emit_event(Blacklisted { account, by });
+            self.record_admin_action(AdminLogAction::Blacklist(account));
+        } else {
+            if listed {
+                let index = self.blacklist_index.get(account).unwrap_or(0);
+                let last_index = self.blacklist_count.saturating_sub(1);
+
+                // Move the last entry into the freed slot
+                if index != last_index {
+                    if let Some(last) = self.blacklist_members.get(last_index) {
+                        self.blacklist_members.insert(index, &last);
+                        self.blacklist_index.insert(last, &index);
+                    }
+                }
+                self.blacklist_members.remove(last_index);
+                self.blacklist_index.remove(account);
+                self.blacklist_count = last_index;
+            }
+            self.blacklist.remove(account);
+            self.env().emit_event(Unblacklisted { account, by });
+            self.record_admin_action(AdminLogAction::Unblacklist(account));
+        }
+    }
+
+    /// Internal helper that moves a scheduled blacklisting onto the blacklist
+    /// once its grace period has run out
+    #[cfg(feature = "blacklist")]
+    fn promote_pending_blacklist(&mut self, account: AccountId) {
+        let effective = self
+            .pending_blacklist
+            .get(account)
+            .is_some_and(|effective_at| self.env().block_timestamp() >= effective_at);
+        if effective {
+            self.pending_blacklist.remove(account);
+            self.set_blacklisted(account, true);
+        }
+    }
+
+    /// Without the `blacklist` feature there is nothing to promote
+    #[cfg(not(feature = "blacklist"))]
+    #[inline(always)]
+    fn promote_pending_blacklist(&mut self, _account: AccountId) {}
+
+    /// Internal helper that counts a send and flags senders over the velocity limits
+    ///
+    /// An account is flagged at most once per window. The send that crosses a
+    /// limit still goes through; with `auto_freeze` later ones are blocked.
+    fn track_velocity(&mut self, from: AccountId, amount: Balance) {
+        let Some(limits) = self.velocity_limits.clone() else {
+            return;
+        };
+
+        let now = self.env().block_timestamp();
+        let mut activity = self.send_activity.get(from).unwrap_or_default();
+        if now.saturating_sub(activity.window_start) >= limits.window {
+            activity = SendActivity {
+                window_start: now,
+                ..Default::default()
+            };
+        }
+        activity.sends = activity.sends.saturating_add(1);
+        activity.volume = activity.volume.saturating_add(amount);
+
+        if !activity.flagged && (activity.sends > limits.max_sends || activity.volume > limits.max_volume) {
+            activity.flagged = true;
+            if limits.auto_freeze {
+                self.risk_frozen.insert(from, &true);
+            }
+            self.env().emit_event(SuspiciousActivity {
+                account: from,
+                sends: activity.sends,
+                volume: activity.volume,
+                window_start: activity.window_start,
+                frozen: limits.auto_freeze,
+            });
+        }
+        self.send_activity.insert(from, &activity);
+    }
+
+    /// Internal helper that adds to the window volume and trips the circuit breaker
+    ///
+    /// The transfer that crosses the threshold still goes through; every later
+    /// one is stopped by the pause.
+    #[cfg(feature = "pause")]
+    fn track_volume(&mut self, amount: Balance) {
+        let Some(threshold) = self.circuit_breaker_threshold else {
+            return;
+        };
+
+        let now = self.env().block_timestamp();
+        if now.saturating_sub(self.volume_window_start) >= self.circuit_breaker_window {
+            self.volume_window_start = now;
+            self.volume_in_window = 0;
+        }
+        self.volume_in_window = self.volume_in_window.saturating_add(amount);
+
+        if self.volume_in_window > threshold && !self.is_paused {
+            self.is_paused = true;
+            self.env().emit_event(CircuitBreakerTripped {
+                volume: self.volume_in_window,
+                threshold,
+                window_start: self.volume_window_start,
+            });
+        }
+    }
+
+    /// Internal helper: a seizure case that has not been resolved yet
+    fn open_seizure(&self, id: u32) -> Result<SeizureCase> {
+        let case = self.seizures.get(id).ok_or(Error::SeizureNotFound)?;
+        if case.status != SeizureStatus::Open {
+            return Err(Error::SeizureClosed);
+        }
+        Ok(case)
+    }
+
+    /// Internal helper that resolves a seizure case and releases its escrow
+    fn close_seizure(&mut self, id: u32, mut case: SeizureCase, status: SeizureStatus) -> Result<()> {
+        self.seized_escrow_total = self
+            .seized_escrow_total
+            .checked_sub(case.amount)
+            .ok_or(Error::Overflow)?;
+        case.status = status.clone();
+        self.seizures.insert(id, &case);
+
+        self.env().emit_event(SeizureResolved {
+            id,
+            status,
+            by: self.env().caller(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,642 @@
+// Core token logic: balances, allowances, reflections, fees and the PSP22 bodies the
+// messages and trait impls share.
+//
+// Included into `mod simple_token` from `lib.rs`; see the note there.
+
+impl SimpleToken {
+    /// Internal helper that creates new tokens for an account
+    fn _mint(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+        // Validate: No new supply while the emergency stop is active, whoever mints
+        self.ensure_no_emergency()?;
+
+        // Validate: Cannot mint zero tokens
+        if amount == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Credit any pending reflection before the balance changes
+        self.settle_reflection(to)?;
+
+        // Get current balance of the recipient
+        let current_balance = self.balances.get(to).unwrap_or(0);
+
+        // Update the balance (add new tokens) with overflow protection
+        let new_balance = current_balance
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        self.write_balance(to, new_balance);
+
+        // Update total supply with overflow protection
+        self.total_supply = self.total_supply
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+
+        // Validate: Never mint past the supply cap
+        if let Some(cap) = self.max_supply {
+            if self.total_supply > cap {
+                return Err(Error::CapExceeded);
+            }
+        }
+        self.total_minted = self.total_minted.saturating_add(amount);
+        self.last_activity.insert(to, &self.env().block_timestamp());
+
+        // Emit event for transparency
+        self.env().emit_event(Minted {
+            to,
+            amount,
+            timestamp: self.env().block_timestamp(),
+            total_supply: self.total_supply,
+            ref_id: self.active_ref_id,
+        });
+        self.notify_balance_change(to);
+
+        Ok(())
+    }
+
+    /// Internal helper that emits a `Transfer` event for tokens moved between balances
+    fn emit_transfer(&self, from: AccountId, to: AccountId, amount: Balance) {
+        self.env().emit_event(Transfer {
+            from,
+            to,
+            amount,
+            timestamp: self.env().block_timestamp(),
+            ref_id: self.active_ref_id,
+        });
+    }
+
+    /// Internal helper that queues an account for the balance observer
+    ///
+    /// The observer is only called from `flush_balance_notifications`, once
+    /// the message has finished updating state.
+    fn notify_balance_change(&mut self, account: AccountId) {
+        if self.balance_observer.is_some() && !self.pending_balance_notifications.contains(&account) {
+            self.pending_balance_notifications.push(account);
+        }
+    }
+
+    /// Internal helper that tells the balance observer about every queued account's new balance
+    ///
+    /// Called last by each message that moves balances. Calls
+    /// `on_balance_change(account, new_balance)` without allowing reentry, so
+    /// the observer cannot act on the token mid-message. A failing observer does
+    /// not block the token; `BalanceObserverFailed` is emitted instead so the
+    /// observer can be resynced.
+    fn flush_balance_notifications(&mut self) {
+        let accounts = core::mem::take(&mut self.pending_balance_notifications);
+        let Some(observer) = self.balance_observer else {
+            return;
+        };
+
+        for account in accounts {
+            let new_balance = self.balances.get(account).unwrap_or(0);
+            let Ok(gas_limit) = self.callback_gas_limit(observer) else {
+                self.env().emit_event(BalanceObserverFailed { observer, account });
+                continue;
+            };
+            let result = build_call::<Environment>()
+                .call(observer)
+                .ref_time_limit(gas_limit)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("on_balance_change")))
+                        .push_arg(account)
+                        .push_arg(new_balance),
+                )
+                .returns::<()>()
+                .try_invoke();
+
+            if !matches!(result, Ok(Ok(()))) {
+                self.env().emit_event(BalanceObserverFailed { observer, account });
+            }
+        }
+    }
+
+    /// Internal helper: reflection accrued by an account since its last balance touch
+    fn reflection_owed(&self, account: AccountId) -> Balance {
+        let checkpoint = self.reflection_checkpoints.get(account).unwrap_or(0);
+        let delta = self.reflection_index.saturating_sub(checkpoint);
+        if delta == 0 {
+            return 0;
+        }
+        let balance = self.balances.get(account).unwrap_or(0);
+        Self::mul_div(balance, delta, REFLECTION_PRECISION).unwrap_or(Balance::MAX)
+    }
+
+    /// Internal helper computing `a * b / denominator` with a 256-bit intermediate
+    ///
+    /// Only fails (None) if the result does not fit in Balance or `denominator` is 0.
+    fn mul_div(a: Balance, b: Balance, denominator: Balance) -> Option<Balance> {
+        if denominator == 0 {
+            return None;
+        }
+        let result = U256::from(a) * U256::from(b) / U256::from(denominator);
+        Balance::try_from(result).ok()
+    }
+
+    /// Internal helper that credits pending reflection; must run before any balance write
+    fn settle_reflection(&mut self, account: AccountId) -> Result<()> {
+        let owed = self.reflection_owed(account);
+        self.reflection_checkpoints.insert(account, &self.reflection_index);
+        if owed == 0 {
+            return Ok(());
+        }
+
+        let balance = self.balances.get(account).unwrap_or(0);
+        self.write_balance(account, balance.checked_add(owed).ok_or(Error::Overflow)?);
+        self.reflection_pool = self.reflection_pool.saturating_sub(owed);
+
+        Ok(())
+    }
+
+    /// Internal helper that adds tokens to a balance, settling reflection first
+    fn credit(&mut self, account: AccountId, amount: Balance) -> Result<()> {
+        self.settle_reflection(account)?;
+        let balance = self.balances.get(account).unwrap_or(0);
+        self.write_balance(account, balance.checked_add(amount).ok_or(Error::Overflow)?);
+        Ok(())
+    }
+
+    /// Internal helper that stores a balance, pruning the entry once it is empty
+    ///
+    /// Every balance write goes through here so no zero balances are stored.
+    fn write_balance(&mut self, account: AccountId, balance: Balance) {
+        self.record_snapshot_balance(account);
+        if balance > 0 {
+            self.balances.insert(account, &balance);
+            if !self.holder_index.contains(account) {
+                let index = self.holder_count;
+                self.holders.insert(index, &account);
+                self.holder_index.insert(account, &index);
+                self.holder_count = index.saturating_add(1);
+            }
+        } else if self.balances.contains(account) {
+            self.balances.remove(account);
+            // Nothing accrues on a zero balance, so the checkpoint can go too
+            self.reflection_checkpoints.remove(account);
+            self.pruned_accounts = self.pruned_accounts.saturating_add(1);
+            self.remove_holder(account);
+            // Keep it findable for snapshot exports, where it may still hold a balance
+            if self.snapshot_id > 0 && !self.former_holder_index.contains(account) {
+                self.former_holders.insert(self.former_holder_count, &account);
+                self.former_holder_index.insert(account, &());
+                self.former_holder_count = self.former_holder_count.saturating_add(1);
+            }
+        }
+    }
+
+    /// Internal helper that drops an emptied account from the holder set
+    fn remove_holder(&mut self, account: AccountId) {
+        let Some(index) = self.holder_index.get(account) else {
+            return;
+        };
+        let last_index = self.holder_count.saturating_sub(1);
+
+        // Move the last holder into the freed slot
+        if index != last_index {
+            if let Some(last) = self.holders.get(last_index) {
+                self.holders.insert(index, &last);
+                self.holder_index.insert(last, &index);
+            }
+        }
+        self.holders.remove(last_index);
+        self.holder_index.remove(account);
+        self.holder_count = last_index;
+    }
+
+    /// Internal helper that charges the transfer fee on `amount` already debited from `from`
+    ///
+    /// Returns the fee, split across the routing table with rounding dust going
+    /// to the last route. One `TaxApplied` event summarizes the splits.
+    #[cfg(feature = "fees")]
+    fn charge_transfer_fee(&mut self, from: AccountId, amount: Balance) -> Result<Balance> {
+        if self.fee_bps == 0 {
+            return Ok(0);
+        }
+
+        let fee = Self::mul_div(amount, Balance::from(self.fee_bps), BPS_DENOMINATOR)
+            .ok_or(Error::Overflow)?;
+        let discount = Self::mul_div(fee, Balance::from(self.fee_discount_bps(from)), BPS_DENOMINATOR)
+            .ok_or(Error::Overflow)?;
+        let fee = fee.saturating_sub(discount);
+        if fee == 0 {
+            return Ok(0);
+        }
+
+        // The fee has left the sender's balance but is not in anyone else's yet
+        let holders_supply = self.circulating_in_balances().saturating_sub(fee);
+
+        let routes = self.fee_routes.clone();
+        let mut remaining = fee;
+        let mut splits = Vec::with_capacity(routes.len());
+        for (position, (destination, bps)) in routes.iter().enumerate() {
+            let share = if position + 1 == routes.len() {
+                remaining
+            } else {
+                Self::mul_div(fee, Balance::from(*bps), BPS_DENOMINATOR).ok_or(Error::Overflow)?
+            };
+            remaining = remaining.saturating_sub(share);
+            if share > 0 {
+                self.pay_fee_share(from, *destination, share, holders_supply)?;
+                splits.push((*destination, share));
+            }
+        }
+
+        self.env().emit_event(TaxApplied {
+            from,
+            amount,
+            fee,
+            splits,
+        });
+
+        Ok(fee)
+    }
+
+    /// Internal helper that moves one share of a fee to its destination
+    ///
+    /// Shares credited to a balance get their own `Transfer` from the sender and
+    /// burned shares a `Burned`, so balances can be rebuilt from events; pooled
+    /// shares are reported by `TaxApplied` only.
+    #[cfg(feature = "fees")]
+    fn pay_fee_share(
+        &mut self,
+        from: AccountId,
+        destination: FeeDestination,
+        amount: Balance,
+        holders_supply: Balance,
+    ) -> Result<()> {
+        match destination {
+            FeeDestination::Account(account) => {
+                self.credit(account, amount)?;
+                self.emit_transfer(from, account, amount);
+                Ok(())
+            }
+            FeeDestination::Treasury => {
+                let treasury = self.env().account_id();
+                self.credit(treasury, amount)?;
+                self.emit_transfer(from, treasury, amount);
+                Ok(())
+            }
+            FeeDestination::Burn => {
+                // Already debited from the sender, so only the supply changes
+                self.total_supply = self.total_supply.saturating_sub(amount);
+                self.total_burned = self.total_burned.saturating_add(amount);
+                self.env().emit_event(Burned {
+                    from,
+                    amount,
+                    timestamp: self.env().block_timestamp(),
+                    reason: b"fee".to_vec(),
+                    total_supply: self.total_supply,
+                    ref_id: self.active_ref_id,
+                });
+                Ok(())
+            }
+            FeeDestination::Reflection => {
+                // Spread over every token that is sitting in a balance right now
+                if holders_supply > 0 {
+                    let increment = Self::mul_div(amount, REFLECTION_PRECISION, holders_supply)
+                        .ok_or(Error::Overflow)?;
+                    self.reflection_index = self
+                        .reflection_index
+                        .checked_add(increment)
+                        .ok_or(Error::Overflow)?;
+                }
+                self.reflection_pool = self
+                    .reflection_pool
+                    .checked_add(amount)
+                    .ok_or(Error::Overflow)?;
+                Ok(())
+            }
+            FeeDestination::Vault if self.vault_total_shares == 0 => {
+                // Nobody holds shares yet; the first depositor must not pick this up
+                let treasury = self.env().account_id();
+                self.credit(treasury, amount)?;
+                self.emit_transfer(from, treasury, amount);
+                Ok(())
+            }
+            FeeDestination::Vault => {
+                // Raises the value of every vault share
+                self.vault_assets = self
+                    .vault_assets
+                    .checked_add(amount)
+                    .ok_or(Error::Overflow)?;
+                Ok(())
+            }
+            FeeDestination::Lottery => {
+                self.lottery_pot = self
+                    .lottery_pot
+                    .checked_add(amount)
+                    .ok_or(Error::Overflow)?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Internal helper: tokens sitting in account balances (supply minus escrows and pools)
+    fn circulating_in_balances(&self) -> Balance {
+        self.total_supply
+            .saturating_sub(self.burn_escrow_total)
+            .saturating_sub(self.reflection_pool)
+            .saturating_sub(self.vault_assets)
+            .saturating_sub(self.lottery_pot)
+            .saturating_sub(self.htlc_escrow_total)
+            .saturating_sub(self.swap_escrow_total)
+            .saturating_sub(self.ve_locked_total)
+            .saturating_sub(self.airdrop_escrow_total)
+            .saturating_sub(self.seized_escrow_total)
+    }
+
+    /// Internal helper that validates and stores the fee configuration
+    #[cfg(feature = "fees")]
+    fn set_fees(&mut self, fee_bps: u16, routes: Vec<(FeeDestination, u16)>) -> Result<()> {
+        let total = routes
+            .iter()
+            .fold(0, |total: Balance, (_, bps)| total.saturating_add(Balance::from(*bps)));
+
+        // Validate: Fee is capped, and every withheld token has a destination
+        if fee_bps > MAX_FEE_BPS || routes.len() > MAX_FEE_ROUTES {
+            return Err(Error::InvalidFeeConfig);
+        }
+        if (fee_bps > 0 || !routes.is_empty()) && total != BPS_DENOMINATOR {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        self.fee_bps = fee_bps;
+        self.fee_routes = routes.clone();
+
+        self.env().emit_event(FeesConfigured { fee_bps, routes });
+        self.record_admin_action(AdminLogAction::ConfigureFees { fee_bps });
+
+        Ok(())
+    }
+
+    /// Internal helper that takes unlocked tokens out of an account's balance
+    ///
+    /// Applies the same pause, blacklist and lock rules as a transfer; the
+    /// caller is responsible for putting the tokens somewhere.
+    fn debit_unlocked(&mut self, from: AccountId, amount: Balance) -> Result<()> {
+        self.ensure_not_paused()?;
+        self.ensure_no_emergency()?;
+        self.ensure_incident_path(from, self.env().account_id())?;
+
+        if self.check_blacklisted(from) {
+            return Err(Error::AccountBlacklisted);
+        }
+        self.ensure_not_frozen(from)?;
+
+        if amount == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        self.settle_reflection(from)?;
+        let balance = self.balances.get(from).unwrap_or(0);
+        if balance < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        if balance.saturating_sub(self.vesting_locked(from)) < amount {
+            return Err(Error::InsufficientUnlockedBalance);
+        }
+
+        self.write_balance(from, balance - amount);
+
+        Ok(())
+    }
+
+    /// Internal helper that stores an allowance and emits `Approval` and `AllowanceChanged`
+    fn set_allowance(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        amount: Balance,
+        cause: AllowanceChangeCause,
+    ) {
+        let old = self.allowances.get((owner, spender)).unwrap_or(0);
+        self.allowances.insert((owner, spender), &amount);
+        self.env().emit_event(Approval {
+            owner,
+            spender,
+            amount,
+            ref_id: self.active_ref_id,
+        });
+        self.env().emit_event(AllowanceChanged {
+            owner,
+            spender,
+            old,
+            new: amount,
+            cause,
+        });
+    }
+
+    /// Internal helper that lets `spender` use part of `owner`'s allowance
+    fn spend_allowance(&mut self, owner: AccountId, spender: AccountId, amount: Balance) -> Result<()> {
+        let allowance = self.allowances.get((owner, spender)).unwrap_or(0);
+
+        // Validate: Spender must be approved for at least this amount
+        if allowance < amount {
+            return Err(Error::InsufficientAllowance);
+        }
+
+        let remaining = allowance - amount;
+        self.allowances.insert((owner, spender), &remaining);
+
+        self.env().emit_event(AllowanceSpent {
+            owner,
+            spender,
+            amount,
+            remaining,
+        });
+        self.env().emit_event(AllowanceChanged {
+            owner,
+            spender,
+            old: allowance,
+            new: remaining,
+            cause: AllowanceChangeCause::Spend,
+        });
+
+        Ok(())
+    }
+
+    /// Internal helper that destroys tokens held by an account
+    fn _burn(&mut self, from: AccountId, amount: Balance, reason: Vec<u8>) -> Result<()> {
+        // Check if contract is paused or emergency-stopped
+        self.ensure_not_paused()?;
+        self.ensure_no_emergency()?;
+
+        // Check if the holder is blacklisted or frozen by its guardians
+        if self.check_blacklisted(from) {
+            return Err(Error::AccountBlacklisted);
+        }
+        self.ensure_not_frozen(from)?;
+
+        // Validate: Cannot burn zero tokens
+        if amount == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Validate: Keep reasons short so events stay cheap
+        if reason.len() > MAX_BURN_REASON_LEN {
+            return Err(Error::ReasonTooLong);
+        }
+
+        self.settle_reflection(from)?;
+        let balance = self.balances.get(from).unwrap_or(0);
+
+        // Validate: Holder must have enough tokens
+        if balance < amount {
+            return Err(Error::InsufficientBalance);
+        }
+
+        // Validate: Vesting tokens cannot be burned before they unlock
+        if balance.saturating_sub(self.vesting_locked(from)) < amount {
+            return Err(Error::InsufficientUnlockedBalance);
+        }
+
+        self.write_balance(from, balance - amount);
+        self.total_supply = self.total_supply
+            .checked_sub(amount)
+            .ok_or(Error::Overflow)?;
+        self.total_burned = self.total_burned.saturating_add(amount);
+
+        // Emit event for transparency
+        self.env().emit_event(Burned {
+            from,
+            amount,
+            timestamp: self.env().block_timestamp(),
+            reason,
+            total_supply: self.total_supply,
+            ref_id: self.active_ref_id,
+        });
+        self.notify_balance_change(from);
+
+        Ok(())
+    }
+
+    /// Internal helper that moves tokens between two accounts
+    fn _transfer(&mut self, from: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+        // Check if contract is paused, emergency-stopped or in incident mode
+        self.ensure_not_paused()?;
+        self.ensure_no_emergency()?;
+        self.ensure_incident_path(from, to)?;
+
+        // Check if sender or recipient is blacklisted, or the sender is frozen
+        self.promote_pending_blacklist(from);
+        self.promote_pending_blacklist(to);
+        if self.check_blacklisted(from) || self.check_blacklisted(to) {
+            return Err(Error::AccountBlacklisted);
+        }
+        self.ensure_not_frozen(from)?;
+
+        // Check launch protection, the anti-dump sell limit and contract recipients
+        self.ensure_trading_open(from, to)?;
+        self.record_sell(from, to, amount)?;
+        self.ensure_recipient_accepted(to)?;
+
+        // Validate: Cannot transfer zero tokens
+        if amount == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Credit any pending reflection before the sender's balance is checked
+        self.settle_reflection(from)?;
+
+        // Get sender's balance
+        let from_balance = self.balances.get(from).unwrap_or(0);
+
+        // Validate: Sender must have enough tokens
+        if from_balance < amount {
+            return Err(Error::InsufficientBalance);
+        }
+
+        // Validate: Vesting tokens cannot move before they unlock
+        if from_balance.saturating_sub(self.vesting_locked(from)) < amount {
+            return Err(Error::InsufficientUnlockedBalance);
+        }
+
+        // Update balances with overflow/underflow protection
+        let new_from_balance = from_balance
+            .checked_sub(amount)
+            .ok_or(Error::Overflow)?;
+        self.write_balance(from, new_from_balance);
+
+        // Take the fee out of the amount before it reaches the recipient
+        #[cfg(feature = "fees")]
+        let received = amount - self.charge_transfer_fee(from, amount)?;
+        #[cfg(not(feature = "fees"))]
+        let received = amount;
+
+        // Credit the recipient after writing the sender so self-transfers stay consistent
+        self.credit(to, received)?;
+
+        let now = self.env().block_timestamp();
+        self.last_activity.insert(from, &now);
+        self.last_activity.insert(to, &now);
+
+        // Emit event for transparency
+        self.emit_transfer(from, to, received);
+
+        // Flag whale movements on a dedicated event
+        if let Some(threshold) = self.large_transfer_threshold {
+            if amount >= threshold {
+                self.env().emit_event(LargeTransfer { from, to, amount });
+            }
+        }
+
+        self.notify_balance_change(from);
+        if to != from {
+            self.notify_balance_change(to);
+        }
+
+        #[cfg(feature = "pause")]
+        self.track_volume(amount);
+        self.track_velocity(from, amount);
+
+        Ok(())
+    }
+
+    /// Check the balance of an account - on-chain this is `PSP22::balance_of`
+    pub fn balance_of(&self, account: AccountId) -> Balance {
+        // Get balance, return 0 if account doesn't exist
+        self.balances.get(account).unwrap_or(0)
+    }
+
+    /// Transfer tokens from caller to another account - on-chain this is `PSP22::transfer`
+    ///
+    /// Shared by the PSP22 message and batched calls, so both honour the same guard.
+    pub fn transfer(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+        self.guard_selector(ink::selector_bytes!("PSP22::transfer"))?;
+
+        let caller = self.env().caller();
+        self._transfer(caller, to, amount)?;
+        self.flush_balance_notifications();
+        Ok(())
+    }
+
+    /// Allow `spender` to spend up to `amount` of the caller's tokens - on-chain this is `PSP22::approve`
+    pub fn approve(&mut self, spender: AccountId, amount: Balance) -> Result<()> {
+        self.guard_selector(ink::selector_bytes!("PSP22::approve"))?;
+
+        let caller = self.env().caller();
+        self.set_allowance(caller, spender, amount, AllowanceChangeCause::Approve);
+        Ok(())
+    }
+
+    /// Get how much `spender` may still spend on behalf of `owner` - on-chain this is `PSP22::allowance`
+    pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+        self.allowances.get((owner, spender)).unwrap_or(0)
+    }
+
+    /// Transfer tokens on behalf of `from` using the caller's allowance - on-chain this is
+    /// `PSP22::transfer_from`
+    pub fn transfer_from(&mut self, from: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+        self.guard_selector(ink::selector_bytes!("PSP22::transfer_from"))?;
+
+        let caller = self.env().caller();
+        self.spend_allowance(from, caller, amount)?;
+        self._transfer(from, to, amount)?;
+        self.flush_balance_notifications();
+        Ok(())
+    }
+
+    /// Get the total supply of tokens - on-chain this is `PSP22::total_supply`
+    pub fn total_supply(&self) -> Balance {
+        self.total_supply
+    }
+}
@@ -0,0 +1,59 @@
+//! Chain environment the token runs in
+
+use ink::env::{DefaultEnvironment, Environment};
+
+/// Chain extension giving access to the runtime's randomness source
+#[ink::chain_extension(extension = 0)]
+pub trait FetchRandom {
+    type ErrorCode = RandomReadErr;
+
+    /// Get a random seed for `subject` from the runtime
+    #[ink(function = 1101)]
+    fn fetch_random(subject: [u8; 32]) -> [u8; 32];
+}
+
+/// Error returned by the randomness chain extension
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum RandomReadErr {
+    /// Runtime could not provide randomness
+    FailGetRandomSource,
+}
+
+impl ink::env::chain_extension::FromStatusCode for RandomReadErr {
+    fn from_status_code(status_code: u32) -> core::result::Result<(), Self> {
+        match status_code {
+            0 => Ok(()),
+            _ => Err(Self::FailGetRandomSource),
+        }
+    }
+}
+
+/// Default environment plus the randomness chain extension
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(TypeInfo)]
+pub enum CustomEnvironment {}
+
+impl Environment for CustomEnvironment {
+    const MAX_EVENT_TOPICS: usize = <DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+
+    type AccountId = <DefaultEnvironment as Environment>::AccountId;
+    type Balance = <DefaultEnvironment as Environment>::Balance;
+    type Hash = <DefaultEnvironment as Environment>::Hash;
+    type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
+    type Timestamp = <DefaultEnvironment as Environment>::Timestamp;
+
+    type ChainExtension = FetchRandom;
+}
+
+/// Account identifier used by the token and its trait definitions
+pub type AccountId = <CustomEnvironment as Environment>::AccountId;
+
+/// Token amount type used by the token and its trait definitions
+pub type Balance = <CustomEnvironment as Environment>::Balance;
+
+/// Hash type used by the token's events
+pub type Hash = <CustomEnvironment as Environment>::Hash;
+
+/// Block number type used by the token's events
+pub type BlockNumber = <CustomEnvironment as Environment>::BlockNumber;
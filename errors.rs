@@ -0,0 +1,274 @@
+//! Errors returned by the token
+
+use ink::prelude::string::String;
+
+/// Custom error types for better error handling
+#[derive(Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum Error {
+    /// Trying to spend more tokens than available
+    InsufficientBalance,
+    /// Only owner can perform this operation
+    Unauthorized,
+    /// Cannot transfer zero tokens
+    InvalidAmount,
+    /// Arithmetic overflow occurred
+    Overflow,
+    /// Insufficient allowance for transfer
+    InsufficientAllowance,
+    /// Contract is currently paused
+    ContractPaused,
+    /// Account is blacklisted
+    AccountBlacklisted,
+    /// Cross-contract call to the recipient failed or was rejected
+    RecipientCallFailed,
+    /// Operation must go through a multisig proposal
+    MultisigRequired,
+    /// Threshold is zero for a non-empty admin set, or larger than the admin set
+    InvalidThreshold,
+    /// No proposal exists with the given id
+    ProposalNotFound,
+    /// Proposal was already executed
+    ProposalAlreadyExecuted,
+    /// Admin already confirmed this proposal
+    AlreadyConfirmed,
+    /// Guardian cannot be the owner itself
+    InvalidGuardian,
+    /// Emergency stop is active
+    EmergencyStopActive,
+    /// Emergency stop is not active
+    EmergencyStopNotActive,
+    /// Emergency stop cannot be lifted before the mandatory delay
+    EmergencyDelayNotElapsed,
+    /// Cross-contract PSP22 transfer failed
+    TokenTransferFailed,
+    /// Native currency transfer failed
+    NativeTransferFailed,
+    /// A recovery for a different new owner is already in progress
+    RecoveryInProgress,
+    /// No recovery is in progress
+    NoActiveRecovery,
+    /// Recovery has not been approved or its delay has not passed yet
+    RecoveryNotReady,
+    /// Owner has sent a heartbeat recently enough
+    OwnerStillActive,
+    /// Emission schedule is not configured
+    EmissionDisabled,
+    /// No full era has passed since the last drip
+    NothingToDrip,
+    /// Minting would exceed the maximum supply
+    CapExceeded,
+    /// No cap change is queued
+    NoPendingCapChange,
+    /// Queued cap change is not yet effective
+    CapChangeNotReady,
+    /// Vesting parameters are inconsistent
+    InvalidSchedule,
+    /// Account already holds the maximum number of vesting schedules
+    TooManySchedules,
+    /// Part of the balance is still locked
+    InsufficientUnlockedBalance,
+    /// Burn reason is longer than `MAX_BURN_REASON_LEN`
+    ReasonTooLong,
+    /// Stored allowance differs from the expected value
+    AllowanceMismatch,
+    /// Signature does not match the signer and payload
+    InvalidSignature,
+    /// Nonce is not the signer's next nonce
+    InvalidNonce,
+    /// Signed authorization is not valid yet
+    AuthorizationNotYetValid,
+    /// Signed authorization has expired
+    AuthorizationExpired,
+    /// Chain genesis hash was already recorded
+    ChainContextAlreadySet,
+    /// Caller does not hold the required role
+    MissingRole,
+    /// No mint request exists with the given id
+    MintRequestNotFound,
+    /// Mint request is past its expiry
+    MintRequestExpired,
+    /// This message is paused on its own
+    SelectorPaused,
+    /// This message is restricted to a role the caller does not hold
+    SelectorAccessDenied,
+    /// Fee configuration is out of range or missing a collector
+    InvalidFeeConfig,
+    /// No treasury spend exists with the given id
+    TreasurySpendNotFound,
+    /// Treasury spend timelock has not passed yet
+    TreasurySpendNotReady,
+    /// No DEX router is configured for buybacks
+    NoBuybackRouter,
+    /// DEX swap failed or returned fewer tokens than required
+    BuybackFailed,
+    /// Neither a fresh oracle price nor a fallback price is available
+    PriceUnavailable,
+    /// Not enough vault shares
+    InsufficientShares,
+    /// Lottery is not configured
+    LotteryDisabled,
+    /// Caller already takes part in the lottery
+    AlreadyParticipating,
+    /// Caller does not take part in the lottery
+    NotParticipating,
+    /// Lottery is full
+    TooManyParticipants,
+    /// Current lottery epoch has not ended yet
+    LotteryEpochNotOver,
+    /// Runtime randomness could not be read
+    RandomnessUnavailable,
+    /// Trading has already been enabled
+    TradingAlreadyEnabled,
+    /// Only whitelisted accounts can trade before launch and during the launch blocks
+    TradingNotOpen,
+    /// Transfer would exceed the sender's sell limit for this window
+    SellLimitExceeded,
+    /// Recipient contract is not allowed to receive tokens
+    ContractRecipientRejected,
+    /// Call re-entered the contract while a recipient callback was running
+    ReentrancyDetected,
+    /// No session key registered for this account
+    SessionKeyNotFound,
+    /// Session key has expired
+    SessionKeyExpired,
+    /// Transfer would exceed the session key's budget
+    SessionBudgetExceeded,
+    /// Account was frozen by its guardians
+    AccountFrozen,
+    /// No ownership handover is scheduled
+    NoPendingOwnershipTransfer,
+    /// Scheduled ownership handover is not effective yet
+    OwnershipTransferNotReady,
+    /// New owner contract did not confirm it accepts ownership
+    OwnershipNotAccepted,
+    /// Child token contract could not be instantiated
+    TokenCreationFailed,
+    /// Payment request ID does not exist
+    PaymentRequestNotFound,
+    /// Payment request is past its expiry
+    PaymentRequestExpired,
+    /// HTLC ID does not exist
+    HtlcNotFound,
+    /// Preimage does not hash to the hashlock
+    InvalidPreimage,
+    /// HTLC timelock has passed, so it can only be refunded
+    HtlcExpired,
+    /// HTLC timelock has not passed yet
+    HtlcNotExpired,
+    /// Swap offer ID does not exist
+    SwapOfferNotFound,
+    /// Swap offer has expired
+    SwapOfferExpired,
+    /// Swap offer has not expired yet
+    SwapOfferNotExpired,
+    /// No burn request exists with the given id
+    BurnRequestNotFound,
+    /// Burn request delay has not passed yet
+    BurnRequestNotReady,
+    /// Incident mode only allows transfers to the recovery address
+    IncidentModeActive,
+    /// Mint would exceed the requesting minter's daily quota
+    MintQuotaExceeded,
+    /// Mint would exceed the requesting minter's remaining allowance
+    MinterAllowanceExceeded,
+    /// Caller or account is not a registered bridge
+    UnknownBridge,
+    /// Mint would exceed the bridge's cap
+    BridgeCapExceeded,
+    /// Burn exceeds what the bridge has outstanding, or removal while some is outstanding
+    BridgeOutstanding,
+    /// Bridge mint or burn exceeds its rate limit
+    BridgeRateLimited,
+    /// Callback target is not on the callback whitelist
+    CalleeNotWhitelisted,
+    /// Payment would exceed the treasury spending cap for this period
+    TreasuryLimitExceeded,
+    /// Account already has a vote-escrow lock
+    LockAlreadyExists,
+    /// Account has no vote-escrow lock
+    NoLock,
+    /// Vote-escrow lock has expired and can only be withdrawn
+    LockExpired,
+    /// Vote-escrow lock has not expired yet
+    LockNotExpired,
+    /// No airdrop with this ID
+    AirdropNotFound,
+    /// Allocation was already claimed
+    AlreadyClaimed,
+    /// Merkle proof does not match the airdrop root
+    InvalidProof,
+    /// Sponsor tip exceeds the airdrop's limit
+    TipTooHigh,
+    /// Account has no Ethereum signer registered
+    NoEthSigner,
+    /// Transaction executed after its deadline
+    DeadlinePassed,
+    /// No dividend with this ID
+    DividendNotFound,
+    /// Dividend claim period is over
+    DividendExpired,
+    /// Dividend claim period has not ended yet
+    DividendNotExpired,
+    /// No reward token or rewards account configured
+    NoRewardToken,
+    /// Rewards in the current reward token are still unclaimed
+    RewardsOutstanding,
+    /// Label is empty or longer than `MAX_LABEL_LEN`
+    InvalidLabel,
+    /// No seizure case with this ID
+    SeizureNotFound,
+    /// Seizure case was already resolved
+    SeizureClosed,
+    /// Seizure is still inside its dispute window
+    DisputeWindowOpen,
+    /// Seizure dispute window has ended
+    DisputeWindowClosed,
+    /// Account has no scheduled blacklisting
+    NoPendingBlacklist,
+    /// Scheduled blacklisting is still inside its grace period
+    BlacklistGracePeriodActive,
+    /// Proposal was created under a previous multisig admin set
+    ProposalStale,
+    /// Metadata URI exceeds `MAX_TOKEN_URI_LEN`
+    UriTooLong,
+    /// The selector guard setters cannot be paused or restricted themselves
+    ProtectedSelector,
+    /// Incident mode needs a recovery address configured first
+    NoIncidentRecoveryAddress,
+}
+
+/// Standard PSP22 error, returned by the `PSP22` messages and used to decode replies from other tokens
+#[derive(Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum PSP22Error {
+    /// Custom error with a message
+    Custom(String),
+    /// Not enough balance
+    InsufficientBalance,
+    /// Not enough allowance
+    InsufficientAllowance,
+    /// Recipient is the zero address
+    ZeroRecipientAddress,
+    /// Sender is the zero address
+    ZeroSenderAddress,
+    /// Safe transfer check failed
+    SafeTransferCheckFailed(String),
+}
+
+/// Result type alias for cleaner error handling
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl From<Error> for PSP22Error {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::InsufficientBalance | Error::InsufficientUnlockedBalance => PSP22Error::InsufficientBalance,
+            Error::InsufficientAllowance => PSP22Error::InsufficientAllowance,
+            Error::RecipientCallFailed | Error::ContractRecipientRejected => {
+                PSP22Error::SafeTransferCheckFailed(ink::prelude::format!("{:?}", error))
+            }
+            other => PSP22Error::Custom(ink::prelude::format!("{:?}", other)),
+        }
+    }
+}
@@ -0,0 +1,1494 @@
+//! Events emitted by the token
+
+use ink::prelude::{string::String, vec::Vec};
+
+use crate::environment::{AccountId, Balance, BlockNumber, Hash};
+use crate::types::{
+    AdminAction, AllowanceChangeCause, ChainId, ContractRecipientPolicy, FeeDestination, MerkleHash,
+    RoleId, SeizureStatus, VelocityLimits,
+};
+
+/// Event emitted when tokens are minted (created)
+#[ink::event]
+pub struct Minted {
+    /// Account that received the new tokens
+    #[ink(topic)]
+    pub to: AccountId,
+    /// Amount of tokens created
+    pub amount: Balance,
+    /// When the minting happened
+    pub timestamp: u64,
+    /// Total supply after the mint
+    pub total_supply: Balance,
+    /// Caller reference passed to `call_with_ref`, if any
+    pub ref_id: Option<[u8; 32]>,
+}
+
+/// Event emitted when tokens are transferred
+#[ink::event]
+pub struct Transfer {
+    /// Account that sent the tokens
+    #[ink(topic)]
+    pub from: AccountId,
+    /// Account that received the tokens
+    #[ink(topic)]
+    pub to: AccountId,
+    /// Amount of tokens transferred
+    pub amount: Balance,
+    /// When the transfer happened
+    pub timestamp: u64,
+    /// Caller reference passed to `call_with_ref`, if any
+    pub ref_id: Option<[u8; 32]>,
+}
+
+/// Event emitted when tokens are burned
+#[ink::event]
+pub struct Burned {
+    /// Account that burned the tokens
+    #[ink(topic)]
+    pub from: AccountId,
+    /// Amount of tokens burned
+    pub amount: Balance,
+    /// When the burning happened
+    pub timestamp: u64,
+    /// Free-form reason code (empty for plain burns)
+    pub reason: Vec<u8>,
+    /// Total supply after the burn
+    pub total_supply: Balance,
+    /// Caller reference passed to `call_with_ref`, if any
+    pub ref_id: Option<[u8; 32]>,
+}
+
+/// Event emitted when spending approval is granted
+#[ink::event]
+pub struct Approval {
+    /// Account that owns the tokens
+    #[ink(topic)]
+    pub owner: AccountId,
+    /// Account that can spend the tokens
+    #[ink(topic)]
+    pub spender: AccountId,
+    /// Amount approved for spending
+    pub amount: Balance,
+    /// Caller reference passed to `call_with_ref`, if any
+    pub ref_id: Option<[u8; 32]>,
+}
+
+/// Event emitted when a spender uses part of an allowance
+#[ink::event]
+pub struct AllowanceSpent {
+    /// Account that owns the tokens
+    #[ink(topic)]
+    pub owner: AccountId,
+    /// Account that spent the tokens
+    #[ink(topic)]
+    pub spender: AccountId,
+    /// Amount taken from the allowance
+    pub amount: Balance,
+    /// Allowance left afterwards
+    pub remaining: Balance,
+}
+
+/// Event emitted when contract is paused
+#[ink::event]
+pub struct Paused {
+    /// Account that paused the contract
+    pub by: AccountId,
+    /// When it was paused
+    pub timestamp: u64,
+}
+
+/// Event emitted when contract is unpaused
+#[ink::event]
+pub struct Unpaused {
+    /// Account that unpaused the contract
+    pub by: AccountId,
+    /// When it was unpaused
+    pub timestamp: u64,
+}
+
+/// Event emitted when an account is blacklisted
+#[ink::event]
+pub struct Blacklisted {
+    /// Account that was blacklisted
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Account that did the blacklisting
+    pub by: AccountId,
+}
+
+/// Event emitted when an account is removed from blacklist
+#[ink::event]
+pub struct Unblacklisted {
+    /// Account that was removed from blacklist
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Account that did the removal
+    pub by: AccountId,
+}
+
+/// Event emitted when the multisig admin set or threshold changes
+#[ink::event]
+pub struct MultisigConfigured {
+    /// New admin accounts
+    pub admins: Vec<AccountId>,
+    /// New confirmation threshold (0 = disabled)
+    pub threshold: u32,
+}
+
+/// Event emitted when a multisig proposal is created
+#[ink::event]
+pub struct ProposalCreated {
+    /// Id of the new proposal
+    #[ink(topic)]
+    pub id: u32,
+    /// Admin that created it
+    #[ink(topic)]
+    pub proposer: AccountId,
+    /// Action to be executed
+    pub action: AdminAction,
+}
+
+/// Event emitted when an admin confirms a proposal
+#[ink::event]
+pub struct ProposalConfirmed {
+    /// Id of the proposal
+    #[ink(topic)]
+    pub id: u32,
+    /// Admin that confirmed
+    #[ink(topic)]
+    pub by: AccountId,
+    /// Confirmations collected so far
+    pub confirmations: u32,
+}
+
+/// Event emitted when a proposal reaches the threshold and is executed
+#[ink::event]
+pub struct ProposalExecuted {
+    /// Id of the proposal
+    #[ink(topic)]
+    pub id: u32,
+}
+
+/// Event emitted when the guardian account changes
+#[ink::event]
+pub struct GuardianChanged {
+    /// New guardian (None = removed)
+    pub guardian: Option<AccountId>,
+    /// Account that made the change
+    pub by: AccountId,
+}
+
+/// Event emitted when the emergency stop is triggered
+#[ink::event]
+pub struct EmergencyStopped {
+    /// Account that triggered it
+    pub by: AccountId,
+    /// When it was triggered
+    pub timestamp: u64,
+    /// Earliest time it can be lifted
+    pub lift_after: u64,
+}
+
+/// Event emitted when the emergency stop is lifted
+#[ink::event]
+pub struct EmergencyStopLifted {
+    /// Account that lifted it
+    pub by: AccountId,
+    /// When it was lifted
+    pub timestamp: u64,
+}
+
+/// Event emitted when foreign tokens are recovered from this contract
+#[ink::event]
+pub struct TokenRescued {
+    /// PSP22 token that was recovered
+    #[ink(topic)]
+    pub token: AccountId,
+    /// Account that received the tokens
+    #[ink(topic)]
+    pub to: AccountId,
+    /// Amount recovered
+    pub amount: Balance,
+}
+
+/// Event emitted when native currency is withdrawn from this contract
+#[ink::event]
+pub struct NativeWithdrawn {
+    /// Account that received the funds
+    #[ink(topic)]
+    pub to: AccountId,
+    /// Amount withdrawn
+    pub amount: Balance,
+}
+
+/// Event emitted when the recovery guardian set changes
+#[ink::event]
+pub struct RecoveryGuardiansSet {
+    /// New recovery guardians
+    pub guardians: Vec<AccountId>,
+    /// Approvals needed
+    pub threshold: u32,
+    /// Window (ms) to collect approvals
+    pub window: u64,
+    /// Delay (ms) before ownership moves
+    pub delay: u64,
+}
+
+/// Event emitted when a guardian starts a new recovery round
+#[ink::event]
+pub struct RecoveryStarted {
+    /// Recovery round
+    #[ink(topic)]
+    pub round: u32,
+    /// Proposed new owner
+    #[ink(topic)]
+    pub new_owner: AccountId,
+    /// Guardian that started it
+    pub by: AccountId,
+}
+
+/// Event emitted for every guardian approval
+#[ink::event]
+pub struct RecoveryApproval {
+    /// Recovery round
+    #[ink(topic)]
+    pub round: u32,
+    /// Guardian that approved
+    #[ink(topic)]
+    pub by: AccountId,
+    /// Approvals collected so far
+    pub approvals: u32,
+}
+
+/// Event emitted when a recovery reaches the threshold
+#[ink::event]
+pub struct RecoveryApproved {
+    /// Recovery round
+    #[ink(topic)]
+    pub round: u32,
+    /// Account that will become owner
+    #[ink(topic)]
+    pub new_owner: AccountId,
+    /// Earliest time the recovery can be finalized
+    pub executable_at: u64,
+}
+
+/// Event emitted when the owner cancels a recovery
+#[ink::event]
+pub struct RecoveryCancelled {
+    /// Recovery round
+    #[ink(topic)]
+    pub round: u32,
+}
+
+/// Event emitted when ownership moves through recovery
+#[ink::event]
+pub struct OwnershipRecovered {
+    /// Previous owner
+    #[ink(topic)]
+    pub previous_owner: AccountId,
+    /// New owner
+    #[ink(topic)]
+    pub new_owner: AccountId,
+}
+
+/// Event emitted when the dead-man switch is configured
+#[ink::event]
+pub struct DeadManSwitchConfigured {
+    /// Account allowed to claim ownership
+    pub successor: Option<AccountId>,
+    /// Maximum time (ms) between heartbeats (0 = disabled)
+    pub interval: u64,
+}
+
+/// Event emitted when the owner sends a heartbeat
+#[ink::event]
+pub struct Heartbeat {
+    /// Owner that sent it
+    pub owner: AccountId,
+    /// When it was sent
+    pub timestamp: u64,
+}
+
+/// Event emitted when the successor claims ownership
+#[ink::event]
+pub struct OwnershipClaimed {
+    /// Previous (inactive) owner
+    #[ink(topic)]
+    pub previous_owner: AccountId,
+    /// New owner
+    #[ink(topic)]
+    pub new_owner: AccountId,
+}
+
+/// Event emitted when the emission schedule changes
+#[ink::event]
+pub struct EmissionConfigured {
+    /// Account receiving emissions
+    pub distributor: Option<AccountId>,
+    /// Tokens emitted per era
+    pub amount_per_era: Balance,
+    /// Era length in blocks
+    pub era_length: BlockNumber,
+    /// Blocks between halvings (0 = constant emission)
+    pub halving_interval: BlockNumber,
+}
+
+/// Event emitted when accrued emission is minted
+#[ink::event]
+pub struct EmissionDripped {
+    /// Account that received the emission
+    #[ink(topic)]
+    pub to: AccountId,
+    /// Amount minted
+    pub amount: Balance,
+    /// Number of eras covered
+    pub eras: u32,
+}
+
+/// Event emitted when a supply cap change is queued
+#[ink::event]
+pub struct CapChangeQueued {
+    /// Cap that will apply (None = uncapped)
+    pub new_cap: Option<Balance>,
+    /// Earliest time the change can be applied
+    pub effective_at: u64,
+}
+
+/// Event emitted when a queued cap change is cancelled
+#[ink::event]
+pub struct CapChangeCancelled {
+    /// Cap that was queued
+    pub new_cap: Option<Balance>,
+}
+
+/// Event emitted when the supply cap changes
+#[ink::event]
+pub struct CapChanged {
+    /// Previous cap
+    pub old_cap: Option<Balance>,
+    /// New cap
+    pub new_cap: Option<Balance>,
+}
+
+/// Event emitted when tokens are minted under a vesting schedule
+#[ink::event]
+pub struct VestingScheduleCreated {
+    /// Account whose tokens vest
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Amount under vesting
+    pub amount: Balance,
+    /// When vesting starts
+    pub start: u64,
+    /// Cliff after start (ms)
+    pub cliff: u64,
+    /// Duration after start (ms)
+    pub duration: u64,
+}
+
+/// Event emitted when a signed authorization is consumed
+#[ink::event]
+pub struct AuthorizationUsed {
+    /// Account that signed the authorization
+    #[ink(topic)]
+    pub authorizer: AccountId,
+    /// Nonce that was consumed
+    pub nonce: u64,
+}
+
+/// Event emitted when the chain genesis hash is recorded
+#[ink::event]
+pub struct ChainContextSet {
+    /// Genesis hash of the chain
+    pub genesis_hash: Hash,
+    /// Resulting domain separator
+    pub domain_separator: Hash,
+}
+
+/// Event emitted alongside `Transfer` for transfers at or above the alert threshold
+#[ink::event]
+pub struct LargeTransfer {
+    /// Account that sent the tokens
+    #[ink(topic)]
+    pub from: AccountId,
+    /// Account that received the tokens
+    #[ink(topic)]
+    pub to: AccountId,
+    /// Amount of tokens transferred
+    pub amount: Balance,
+}
+
+/// Event emitted when the large-transfer alert threshold changes
+#[ink::event]
+pub struct LargeTransferThresholdSet {
+    /// New threshold (None = alerts off)
+    pub threshold: Option<Balance>,
+}
+
+/// Event emitted when a role is granted
+#[ink::event]
+pub struct RoleGranted {
+    /// Role that was granted
+    #[ink(topic)]
+    pub role: RoleId,
+    /// Account that received the role
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Account that granted it
+    pub by: AccountId,
+}
+
+/// Event emitted when a role is revoked
+#[ink::event]
+pub struct RoleRevoked {
+    /// Role that was revoked
+    #[ink(topic)]
+    pub role: RoleId,
+    /// Account that lost the role
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Account that revoked it
+    pub by: AccountId,
+}
+
+/// Event emitted when a minter requests a mint
+#[ink::event]
+pub struct MintRequested {
+    /// Id of the request
+    #[ink(topic)]
+    pub id: u32,
+    /// Account that will receive the tokens
+    #[ink(topic)]
+    pub to: AccountId,
+    /// Amount to mint
+    pub amount: Balance,
+    /// Minter that created the request
+    pub by: AccountId,
+    /// When the request expires
+    pub expires_at: u64,
+}
+
+/// Event emitted when an approver confirms a mint request
+#[ink::event]
+pub struct MintRequestApproved {
+    /// Id of the request
+    #[ink(topic)]
+    pub id: u32,
+    /// Approver that confirmed it
+    pub by: AccountId,
+}
+
+/// Event emitted when a mint request is cancelled or rejected
+#[ink::event]
+pub struct MintRequestCancelled {
+    /// Id of the request
+    #[ink(topic)]
+    pub id: u32,
+    /// Account that cancelled it
+    pub by: AccountId,
+}
+
+/// Event emitted when tokens are escrowed for a delayed burn
+#[ink::event]
+pub struct BurnRequested {
+    /// Id of the request
+    #[ink(topic)]
+    pub id: u32,
+    /// Account redeeming the tokens
+    #[ink(topic)]
+    pub from: AccountId,
+    /// Amount in escrow
+    pub amount: Balance,
+    /// Earliest time the burn can be finalized
+    pub ready_at: u64,
+}
+
+/// Event emitted when an escrowed burn is finalized
+#[ink::event]
+pub struct BurnRequestFinalized {
+    /// Id of the request
+    #[ink(topic)]
+    pub id: u32,
+}
+
+/// Event emitted when compliance cancels a burn and returns the tokens
+#[ink::event]
+pub struct BurnRequestCancelled {
+    /// Id of the request
+    #[ink(topic)]
+    pub id: u32,
+    /// Compliance account that cancelled it
+    pub by: AccountId,
+}
+
+/// Event emitted when the admin role of a role changes
+#[ink::event]
+pub struct RoleAdminChanged {
+    /// Role whose admin changed
+    #[ink(topic)]
+    pub role: RoleId,
+    /// Previous admin role (None = owner only)
+    pub previous_admin_role: Option<RoleId>,
+    /// New admin role (None = owner only)
+    pub new_admin_role: Option<RoleId>,
+}
+
+/// Event emitted when a single message is paused or resumed
+#[ink::event]
+pub struct SelectorPauseChanged {
+    /// Selector of the affected message
+    #[ink(topic)]
+    pub selector: [u8; 4],
+    /// Whether it is now paused
+    pub paused: bool,
+}
+
+/// Event emitted when a message is restricted to a role or opened up again
+#[ink::event]
+pub struct SelectorAccessChanged {
+    /// Selector of the affected message
+    #[ink(topic)]
+    pub selector: [u8; 4],
+    /// Role now required (None = unrestricted)
+    pub role: Option<RoleId>,
+}
+
+/// Event emitted when the transfer fee configuration changes
+#[ink::event]
+pub struct FeesConfigured {
+    /// Fee in basis points of each transfer
+    pub fee_bps: u16,
+    /// Routing table, in basis points of the fee
+    pub routes: Vec<(FeeDestination, u16)>,
+}
+
+/// Event emitted once per taxed transfer, summarizing where the fee went
+#[ink::event]
+pub struct TaxApplied {
+    /// Account whose transfer paid the fee
+    #[ink(topic)]
+    pub from: AccountId,
+    /// Amount that was taxed
+    pub amount: Balance,
+    /// Total fee withheld
+    pub fee: Balance,
+    /// Tokens sent to each destination
+    pub splits: Vec<(FeeDestination, Balance)>,
+}
+
+/// Event emitted when a treasury spend is proposed
+#[ink::event]
+pub struct TreasurySpendProposed {
+    /// Id of the spend
+    #[ink(topic)]
+    pub id: u32,
+    /// Account that will be paid
+    #[ink(topic)]
+    pub to: AccountId,
+    /// Amount to pay
+    pub amount: Balance,
+    /// Earliest time it can be executed
+    pub executable_at: u64,
+}
+
+/// Event emitted when tokens leave the treasury
+#[ink::event]
+pub struct TreasurySpendExecuted {
+    /// Id of the spend (None when executed directly by the multisig)
+    #[ink(topic)]
+    pub id: Option<u32>,
+    /// Account that was paid
+    #[ink(topic)]
+    pub to: AccountId,
+    /// Amount paid
+    pub amount: Balance,
+}
+
+/// Event emitted when a treasury spend is cancelled
+#[ink::event]
+pub struct TreasurySpendCancelled {
+    /// Id of the spend
+    #[ink(topic)]
+    pub id: u32,
+}
+
+/// Event emitted when the buyback router changes
+#[ink::event]
+pub struct BuybackRouterSet {
+    /// DEX contract and swap selector (None = buybacks disabled)
+    pub router: Option<(AccountId, [u8; 4])>,
+}
+
+/// Event emitted when native funds are used to buy back and burn tokens
+#[ink::event]
+pub struct Buyback {
+    /// Native currency spent
+    pub native_spent: Balance,
+    /// Tokens bought and burned
+    pub tokens_burned: Balance,
+}
+
+/// Event emitted when the price oracle configuration changes
+#[ink::event]
+pub struct OracleConfigured {
+    /// Oracle contract (None = fallback price only)
+    pub oracle: Option<AccountId>,
+    /// Maximum accepted age (ms) of an oracle answer
+    pub max_age: u64,
+    /// Price used when the oracle cannot be used
+    pub fallback_price: Option<Balance>,
+}
+
+/// Event emitted when tokens are deposited into the savings vault
+#[ink::event]
+pub struct VaultDeposit {
+    /// Depositor
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Tokens deposited
+    pub amount: Balance,
+    /// Shares minted
+    pub shares: Balance,
+}
+
+/// Event emitted when tokens are withdrawn from the savings vault
+#[ink::event]
+pub struct VaultWithdraw {
+    /// Withdrawer
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Tokens paid out
+    pub amount: Balance,
+    /// Shares burned
+    pub shares: Balance,
+}
+
+/// Event emitted when the fee lottery is configured
+#[ink::event]
+pub struct LotteryConfigured {
+    /// Epoch length in ms (0 = disabled)
+    pub epoch_length: u64,
+}
+
+/// Event emitted when an account opts in or out of the lottery
+#[ink::event]
+pub struct LotteryParticipation {
+    /// Account that changed its participation
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Whether it now takes part
+    pub participating: bool,
+}
+
+/// Event emitted when a lottery epoch is drawn
+#[ink::event]
+pub struct LotteryDrawn {
+    /// Epoch that was drawn
+    #[ink(topic)]
+    pub epoch: u32,
+    /// Winning account (None if no participant held tokens)
+    #[ink(topic)]
+    pub winner: Option<AccountId>,
+    /// Tokens awarded
+    pub prize: Balance,
+    /// Random seed the winner was derived from
+    pub seed: [u8; 32],
+}
+
+/// Event emitted when trading is switched on
+#[ink::event]
+pub struct TradingEnabled {
+    /// Block at which trading was enabled
+    pub block: BlockNumber,
+    /// Blocks during which only whitelisted accounts can still trade
+    pub launch_blocks: BlockNumber,
+}
+
+/// Event emitted when an account is added to or removed from the launch whitelist
+#[ink::event]
+pub struct LaunchWhitelistUpdated {
+    /// Affected account
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Whether it is now whitelisted
+    pub whitelisted: bool,
+}
+
+/// Event emitted when the sell limit changes
+#[ink::event]
+pub struct SellLimitSet {
+    /// Most an account can sell per window (None = no limit)
+    pub limit: Option<Balance>,
+    /// Window length in blocks
+    pub window_blocks: BlockNumber,
+}
+
+/// Event emitted when an account is marked as a market or exempted from the sell limit
+#[ink::event]
+pub struct SellLimitAccountUpdated {
+    /// Affected account
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Whether transfers to it count as sells
+    pub market: bool,
+    /// Whether it is exempt from the sell limit
+    pub exempt: bool,
+}
+
+/// Event emitted when the contract-recipient policy changes
+#[ink::event]
+pub struct ContractRecipientPolicySet {
+    /// New policy
+    pub policy: ContractRecipientPolicy,
+}
+
+/// Event emitted when a code hash is added to or removed from the accept-list
+#[ink::event]
+pub struct CodeHashAcceptanceChanged {
+    /// Affected code hash
+    #[ink(topic)]
+    pub code_hash: Hash,
+    /// Whether contracts with this code can now receive tokens
+    pub accepted: bool,
+}
+
+/// Event emitted when an account registers or replaces a session key
+#[ink::event]
+pub struct SessionKeyRegistered {
+    /// Account the key spends for
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Session key
+    #[ink(topic)]
+    pub key: AccountId,
+    /// Tokens the key can spend
+    pub limit: Balance,
+    /// Time after which the key can no longer be used
+    pub expires_at: u64,
+}
+
+/// Event emitted when an account revokes a session key
+#[ink::event]
+pub struct SessionKeyRevoked {
+    /// Account the key spent for
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Revoked session key
+    #[ink(topic)]
+    pub key: AccountId,
+}
+
+/// Event emitted when a session key spends from its budget
+#[ink::event]
+pub struct SessionKeyUsed {
+    /// Account the key spent for
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Session key
+    #[ink(topic)]
+    pub key: AccountId,
+    /// Amount spent
+    pub amount: Balance,
+    /// Budget left afterwards
+    pub remaining: Balance,
+}
+
+/// Event emitted when an account changes its freeze guardians
+#[ink::event]
+pub struct AccountGuardiansSet {
+    /// Account protected by the guardians
+    #[ink(topic)]
+    pub account: AccountId,
+    /// New guardians (empty = none)
+    pub guardians: Vec<AccountId>,
+    /// Votes needed to freeze or unfreeze
+    pub threshold: u32,
+}
+
+/// Event emitted when a guardian votes to freeze or unfreeze an account
+#[ink::event]
+pub struct FreezeVoteCast {
+    /// Account being voted on
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Guardian that voted
+    #[ink(topic)]
+    pub guardian: AccountId,
+    /// Whether the vote is to freeze (false = unfreeze)
+    pub freeze: bool,
+    /// Votes collected so far in this round
+    pub votes: u32,
+}
+
+/// Event emitted when guardians freeze or unfreeze an account
+#[ink::event]
+pub struct AccountFreezeChanged {
+    /// Affected account
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Whether it is now frozen
+    pub frozen: bool,
+}
+
+/// Event emitted when an ownership handover is scheduled
+#[ink::event]
+pub struct OwnershipTransferScheduled {
+    /// Account that will become owner
+    #[ink(topic)]
+    pub new_owner: AccountId,
+    /// Time from which the handover can be finalized
+    pub effective_at: u64,
+}
+
+/// Event emitted when a scheduled ownership handover is cancelled
+#[ink::event]
+pub struct OwnershipTransferCancelled {
+    /// Account that would have become owner
+    #[ink(topic)]
+    pub new_owner: AccountId,
+}
+
+/// Event emitted when a scheduled ownership handover takes effect
+#[ink::event]
+pub struct OwnershipTransferred {
+    /// Previous owner
+    #[ink(topic)]
+    pub previous_owner: AccountId,
+    /// New owner
+    #[ink(topic)]
+    pub new_owner: AccountId,
+}
+
+/// Event emitted when a child token contract is created
+#[ink::event]
+pub struct TokenCreated {
+    /// Account that requested the token
+    #[ink(topic)]
+    pub creator: AccountId,
+    /// New token contract
+    #[ink(topic)]
+    pub token: AccountId,
+    /// Position in the list of created tokens
+    pub index: u32,
+}
+
+/// Event emitted once per `burn_batch` with the aggregate burned
+#[ink::event]
+pub struct BatchBurned {
+    /// BURNER that ran the batch
+    #[ink(topic)]
+    pub burner: AccountId,
+    /// Number of accounts burned from
+    pub accounts: u32,
+    /// Total tokens burned
+    pub total: Balance,
+}
+
+/// Event emitted when a payee creates a payment request
+#[ink::event]
+pub struct PaymentRequested {
+    /// Request ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Account that will be paid
+    #[ink(topic)]
+    pub payee: AccountId,
+    /// Account asked to pay
+    #[ink(topic)]
+    pub payer: AccountId,
+    /// Amount requested
+    pub amount: Balance,
+    /// Free-form reference
+    pub memo: Vec<u8>,
+    /// When the request stops being payable
+    pub expires_at: u64,
+}
+
+/// Event emitted when a payment request is paid
+#[ink::event]
+pub struct PaymentRequestPaid {
+    /// Request ID
+    #[ink(topic)]
+    pub id: u32,
+}
+
+/// Event emitted when a payment request is cancelled or declined
+#[ink::event]
+pub struct PaymentRequestCancelled {
+    /// Request ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Payee or payer that dropped it
+    pub by: AccountId,
+}
+
+/// Event emitted when tokens are locked in an HTLC
+#[ink::event]
+pub struct HtlcLocked {
+    /// HTLC ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Account that locked the tokens
+    #[ink(topic)]
+    pub sender: AccountId,
+    /// Account that can claim them
+    #[ink(topic)]
+    pub recipient: AccountId,
+    /// Amount locked
+    pub amount: Balance,
+    /// SHA-256 hash of the secret preimage
+    pub hashlock: [u8; 32],
+    /// Time from which the sender can refund
+    pub timelock: u64,
+}
+
+/// Event emitted when an HTLC is claimed, revealing the preimage
+#[ink::event]
+pub struct HtlcClaimed {
+    /// HTLC ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Secret that unlocked it (lets the counterparty claim on the other chain)
+    pub preimage: Vec<u8>,
+}
+
+/// Event emitted when an expired HTLC is refunded to its sender
+#[ink::event]
+pub struct HtlcRefunded {
+    /// HTLC ID
+    #[ink(topic)]
+    pub id: u32,
+}
+
+/// Event emitted when a swap offer is created
+#[ink::event]
+pub struct SwapOfferCreated {
+    /// Offer ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Account that escrowed the tokens
+    #[ink(topic)]
+    pub maker: AccountId,
+    /// Amount of this token escrowed
+    pub amount: Balance,
+    /// PSP22 token wanted in return
+    #[ink(topic)]
+    pub ask_token: AccountId,
+    /// Amount of `ask_token` wanted
+    pub ask_amount: Balance,
+    /// Only account allowed to accept (None = anyone)
+    pub taker: Option<AccountId>,
+    /// Time after which the offer can only be refunded
+    pub expires_at: u64,
+}
+
+/// Event emitted when both legs of a swap settle
+#[ink::event]
+pub struct SwapSettled {
+    /// Offer ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Account that accepted the offer
+    #[ink(topic)]
+    pub taker: AccountId,
+}
+
+/// Event emitted when a swap offer is cancelled or refunded after expiry
+#[ink::event]
+pub struct SwapOfferClosed {
+    /// Offer ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Account that closed it
+    pub by: AccountId,
+}
+
+/// Event emitted when the token metadata URI changes
+#[ink::event]
+pub struct TokenUriUpdated {
+    /// New URI (None = cleared)
+    pub uri: Option<String>,
+    /// Account that changed it
+    pub by: AccountId,
+}
+
+/// Event emitted when incident mode is switched on or off
+#[ink::event]
+pub struct IncidentModeChanged {
+    /// Whether incident mode is now on
+    pub active: bool,
+    /// Account that may still receive tokens
+    pub recovery_address: Option<AccountId>,
+    /// Account that changed it
+    pub by: AccountId,
+}
+
+/// Event emitted when the circuit breaker settings change
+#[ink::event]
+pub struct CircuitBreakerConfigured {
+    /// Volume per window that trips the breaker (None = off)
+    pub threshold: Option<Balance>,
+    /// Window length in milliseconds
+    pub window: u64,
+}
+
+/// Event emitted when abnormal volume pauses the contract
+#[ink::event]
+pub struct CircuitBreakerTripped {
+    /// Volume transferred in the current window
+    pub volume: Balance,
+    /// Threshold that was crossed
+    pub threshold: Balance,
+    /// When the window started
+    pub window_start: u64,
+}
+
+/// Event emitted when the velocity limits change
+#[ink::event]
+pub struct VelocityLimitsSet {
+    /// New limits (None = checks off)
+    pub limits: Option<VelocityLimits>,
+    /// Account that changed them
+    pub by: AccountId,
+}
+
+/// Event emitted when a sender exceeds the velocity limits
+#[ink::event]
+pub struct SuspiciousActivity {
+    /// Flagged sender
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Sends in the current window
+    pub sends: u32,
+    /// Tokens sent in the current window
+    pub volume: Balance,
+    /// When the window started
+    pub window_start: u64,
+    /// Whether the account was frozen pending review
+    pub frozen: bool,
+}
+
+/// Event emitted when COMPLIANCE releases a velocity freeze
+#[ink::event]
+pub struct RiskFreezeReleased {
+    /// Released account
+    #[ink(topic)]
+    pub account: AccountId,
+    /// COMPLIANCE member that released it
+    pub by: AccountId,
+}
+
+/// Event emitted when a minter's daily quota changes
+#[ink::event]
+pub struct MinterQuotaSet {
+    /// Minter the quota applies to
+    #[ink(topic)]
+    pub minter: AccountId,
+    /// New quota (None = unlimited)
+    pub quota: Option<Balance>,
+}
+
+/// Event emitted when a minter's mint budget is set
+#[ink::event]
+pub struct MinterAllowanceSet {
+    /// Minter the budget applies to
+    #[ink(topic)]
+    pub minter: AccountId,
+    /// New budget
+    pub allowance: Balance,
+}
+
+/// Event emitted when a bridge is registered or its cap changes
+#[ink::event]
+pub struct BridgeConfigured {
+    /// Bridge account
+    #[ink(topic)]
+    pub bridge: AccountId,
+    /// Most the bridge may have outstanding
+    pub cap: Balance,
+}
+
+/// Event emitted when a bridge is unregistered
+#[ink::event]
+pub struct BridgeRemoved {
+    /// Bridge account
+    #[ink(topic)]
+    pub bridge: AccountId,
+}
+
+/// Event emitted when a bridge mints incoming tokens
+#[ink::event]
+pub struct BridgeMinted {
+    /// Bridge that minted
+    #[ink(topic)]
+    pub bridge: AccountId,
+    /// Recipient
+    #[ink(topic)]
+    pub to: AccountId,
+    /// Amount minted
+    pub amount: Balance,
+}
+
+/// Event emitted when a bridge burns outgoing tokens
+#[ink::event]
+pub struct BridgeBurned {
+    /// Bridge that burned
+    #[ink(topic)]
+    pub bridge: AccountId,
+    /// Account the tokens were burned from
+    #[ink(topic)]
+    pub from: AccountId,
+    /// Amount burned
+    pub amount: Balance,
+    /// Destination chain
+    pub chain_id: ChainId,
+}
+
+/// Event emitted when a bridge's rate limits change
+#[ink::event]
+pub struct BridgeRateLimitsSet {
+    /// Bridge account
+    #[ink(topic)]
+    pub bridge: AccountId,
+    /// Mint limit and period in ms (None = unlimited)
+    pub mint: Option<(Balance, u64)>,
+    /// Burn limit and period in ms (None = unlimited)
+    pub burn: Option<(Balance, u64)>,
+}
+
+/// Event emitted when the balance observer changes
+#[ink::event]
+pub struct BalanceObserverSet {
+    /// New observer (None = off)
+    pub observer: Option<AccountId>,
+}
+
+/// Event emitted when the balance observer rejects a notification
+#[ink::event]
+pub struct BalanceObserverFailed {
+    /// Observer that failed
+    pub observer: AccountId,
+    /// Account whose balance it missed
+    #[ink(topic)]
+    pub account: AccountId,
+}
+
+/// Event emitted when a contract is added to, updated on or removed from the callback whitelist
+#[ink::event]
+pub struct CallbackWhitelistUpdated {
+    /// Callback contract
+    #[ink(topic)]
+    pub callee: AccountId,
+    /// Weight cap per call (None = removed)
+    pub gas_limit: Option<u64>,
+}
+
+/// Event emitted when the treasury spending cap changes
+#[ink::event]
+pub struct TreasurySpendLimitSet {
+    /// New cap as (bps of the treasury, period in ms) (None = uncapped)
+    pub limit: Option<(u16, u64)>,
+}
+
+/// Event emitted when a vote-escrow lock is created or extended
+#[ink::event]
+pub struct VeLockUpdated {
+    /// Lock owner
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Total locked amount
+    pub amount: Balance,
+    /// Unlock time
+    pub end: u64,
+}
+
+/// Event emitted when an expired vote-escrow lock is withdrawn
+#[ink::event]
+pub struct VeWithdrawn {
+    /// Lock owner
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Amount returned
+    pub amount: Balance,
+}
+
+/// Event emitted when the fee discount schedule changes
+#[ink::event]
+pub struct FeeDiscountTiersSet {
+    /// (minimum vote-escrow weight, discount bps) per tier
+    pub tiers: Vec<(Balance, u16)>,
+}
+
+/// Event emitted when a merkle airdrop is funded
+#[ink::event]
+pub struct AirdropCreated {
+    /// Airdrop ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Account that funded it
+    #[ink(topic)]
+    pub creator: AccountId,
+    /// Root of the allocation tree
+    pub merkle_root: [u8; 32],
+    /// Hash function the tree was built with
+    pub hash: MerkleHash,
+    /// Tokens escrowed
+    pub amount: Balance,
+}
+
+/// Event emitted when an airdrop allocation is claimed
+#[ink::event]
+pub struct AirdropClaimed {
+    /// Airdrop ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Account the allocation belongs to
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Full allocation, including any tip
+    pub amount: Balance,
+    /// Relayer that submitted the claim, if sponsored
+    pub sponsor: Option<AccountId>,
+    /// Part of the allocation paid to the sponsor
+    pub tip: Balance,
+}
+
+/// Event emitted when an airdrop is closed and its remainder refunded
+#[ink::event]
+pub struct AirdropClosed {
+    /// Airdrop ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Tokens returned to the creator
+    pub refunded: Balance,
+}
+
+/// Event emitted when a claim sponsor is added or removed
+#[ink::event]
+pub struct ClaimSponsorSet {
+    /// Relayer account
+    #[ink(topic)]
+    pub sponsor: AccountId,
+    /// Whether it may submit sponsored claims
+    pub allowed: bool,
+}
+
+/// Event emitted when an account registers or clears its Ethereum signer
+#[ink::event]
+pub struct EthSignerSet {
+    /// Account the signer acts for
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Registered Ethereum address (None = cleared)
+    pub eth_address: Option<[u8; 20]>,
+}
+
+/// Event emitted when a signed cheque is cashed
+#[ink::event]
+pub struct ChequeCashed {
+    /// Account that signed the cheque
+    #[ink(topic)]
+    pub payer: AccountId,
+    /// Account that cashed it
+    #[ink(topic)]
+    pub beneficiary: AccountId,
+    /// Amount paid
+    pub amount: Balance,
+    /// Cheque nonce
+    pub nonce: u64,
+}
+
+/// Event emitted when a payer cancels an uncashed cheque
+#[ink::event]
+pub struct ChequeCancelled {
+    /// Account that signed the cheque
+    #[ink(topic)]
+    pub payer: AccountId,
+    /// Cheque nonce
+    pub nonce: u64,
+}
+
+/// Event emitted when native currency is deposited as a dividend
+#[ink::event]
+pub struct DividendDeposited {
+    /// Dividend ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Balance snapshot the shares are based on
+    pub snapshot_id: u32,
+    /// Native currency deposited
+    pub amount: Balance,
+    /// End of the claim period
+    pub expires_at: u64,
+}
+
+/// Event emitted when a holder claims a dividend share
+#[ink::event]
+pub struct DividendClaimed {
+    /// Dividend ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Holder that claimed
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Native currency paid
+    pub amount: Balance,
+}
+
+/// Event emitted when the unclaimed rest of an expired dividend is swept
+#[ink::event]
+pub struct DividendSwept {
+    /// Dividend ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Account that received the rest
+    pub to: AccountId,
+    /// Native currency swept
+    pub amount: Balance,
+}
+
+/// Event emitted when the second reward token changes
+#[ink::event]
+pub struct RewardTokenSet {
+    /// PSP22 reward token (None = native rewards only)
+    pub token: Option<AccountId>,
+    /// Account the reward token is pulled from
+    pub rewards_account: Option<AccountId>,
+}
+
+/// Event emitted when vote-escrow locker rewards are funded
+#[ink::event]
+pub struct VeRewardsFunded {
+    /// Native currency added
+    pub native_amount: Balance,
+    /// Reward tokens added
+    pub token_amount: Balance,
+}
+
+/// Event emitted when a locker claims its rewards
+#[ink::event]
+pub struct VeRewardsClaimed {
+    /// Locker that claimed
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Native currency paid
+    pub native_amount: Balance,
+    /// Reward tokens paid
+    pub token_amount: Balance,
+}
+
+/// Event emitted on every allowance change, with what caused it
+#[ink::event]
+pub struct AllowanceChanged {
+    /// Account that owns the tokens
+    #[ink(topic)]
+    pub owner: AccountId,
+    /// Account that can spend the tokens
+    #[ink(topic)]
+    pub spender: AccountId,
+    /// Allowance before the change
+    pub old: Balance,
+    /// Allowance after the change
+    pub new: Balance,
+    /// What changed it
+    pub cause: AllowanceChangeCause,
+}
+
+/// Event emitted when a balance snapshot is taken
+#[ink::event]
+pub struct SnapshotTaken {
+    /// Snapshot ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Total supply at the snapshot
+    pub total_supply: Balance,
+}
+
+/// Event emitted when an account's label is set or cleared
+#[ink::event]
+pub struct AccountLabelSet {
+    /// Labeled account
+    #[ink(topic)]
+    pub account: AccountId,
+    /// New label (None = cleared)
+    pub label: Option<Vec<u8>>,
+    /// Labeler that made the change
+    pub by: AccountId,
+}
+
+/// Event emitted when an account is scheduled to be blacklisted after the grace period
+#[ink::event]
+pub struct BlacklistScheduled {
+    /// Account to be blacklisted
+    #[ink(topic)]
+    pub account: AccountId,
+    /// When the blacklisting takes effect
+    pub effective_at: u64,
+}
+
+/// Event emitted when the blacklist grace period changes
+#[ink::event]
+pub struct BlacklistGracePeriodSet {
+    /// New grace period (ms, 0 = immediate)
+    pub period: u64,
+}
+
+/// Event emitted when tokens are seized into escrow
+#[ink::event]
+pub struct TokensSeized {
+    /// Seizure case ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Account the tokens were taken from
+    #[ink(topic)]
+    pub account: AccountId,
+    /// Amount seized
+    pub amount: Balance,
+    /// Free-form reason code
+    pub reason: Vec<u8>,
+    /// End of the dispute window
+    pub dispute_ends: u64,
+}
+
+/// Event emitted when a seizure case is reversed, burned or redistributed
+#[ink::event]
+pub struct SeizureResolved {
+    /// Seizure case ID
+    #[ink(topic)]
+    pub id: u32,
+    /// Outcome
+    pub status: SeizureStatus,
+    /// Account that resolved it
+    pub by: AccountId,
+}
+
+/// Event emitted as a periodic checkpoint of the supply breakdown
+#[ink::event]
+pub struct SupplyAttested {
+    /// Account that requested it
+    #[ink(topic)]
+    pub by: AccountId,
+    /// Total supply
+    pub total_supply: Balance,
+    /// Tokens in the savings vault and vote-escrow locks
+    pub total_staked: Balance,
+    /// Tokens in burn, HTLC, swap, airdrop and seizure escrows
+    pub total_locked: Balance,
+    /// Tokens held by the contract treasury
+    pub treasury: Balance,
+    /// Block the checkpoint was taken at
+    pub block: BlockNumber,
+    /// Timestamp of that block
+    pub timestamp: u64,
+}
+
+/// Event emitted when the incident mode recovery address changes
+#[ink::event]
+pub struct IncidentRecoveryAddressSet {
+    /// Account that may still receive tokens during incident mode
+    pub recovery_address: Option<AccountId>,
+    /// Account that changed it
+    pub by: AccountId,
+}
@@ -1,8 +1,201 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-#[ink::contract]
+pub mod environment;
+pub mod errors;
+pub mod events;
+pub mod traits;
+pub mod types;
+
+pub use environment::{AccountId, Balance, CustomEnvironment, FetchRandom, RandomReadErr};
+pub use errors::{Error, PSP22Error, Result};
+pub use traits::{AccessControl, Ownable, Pausable, PSP22};
+pub use types::RoleId;
+
+#[ink::contract(env = crate::CustomEnvironment)]
 mod simple_token {
-    use ink::storage::Mapping;
+    use ink::codegen::{Env as _, StaticEnv as _};
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::CallFlags;
+    use ink::env::hash::{Blake2x256, Keccak256, Sha2x256};
+    use ink::prelude::{string::String, vec::Vec};
+    use ink::storage::{Lazy, Mapping};
+    use primitive_types::U256;
+
+    use crate::errors::{Error, PSP22Error, Result};
+    use crate::events::*;
+    use crate::traits::{AccessControl, Ownable, Pausable, PSP22};
+    use crate::types::*;
+
+    /// Role allowed to propose mint requests
+    pub const MINTER: RoleId = ink::selector_id!("MINTER");
+
+    /// Role allowed to approve or reject mint requests
+    pub const APPROVER: RoleId = ink::selector_id!("APPROVER");
+
+    /// Role allowed to cancel pending burn (redemption) requests
+    pub const COMPLIANCE: RoleId = ink::selector_id!("COMPLIANCE");
+
+    /// Role allowed to burn from many accounts at once with `burn_batch`
+    pub const BURNER: RoleId = ink::selector_id!("BURNER");
+
+    /// Role allowed to set the velocity limits used to flag suspicious senders
+    pub const RISK: RoleId = ink::selector_id!("RISK");
+
+    /// Role allowed to tag accounts with labels
+    pub const LABELER: RoleId = ink::selector_id!("LABELER");
+
+    /// Role allowed to reverse a seizure during its dispute window
+    pub const APPEALS: RoleId = ink::selector_id!("APPEALS");
+
+    /// Role allowed to run periodic upkeep such as supply attestations
+    pub const KEEPER: RoleId = ink::selector_id!("KEEPER");
+
+    /// Identifier of an interface reported by `supports`
+    pub type InterfaceId = [u8; 4];
+
+    /// Core PSP22 messages (balance_of, transfer, approve, transfer_from, ...)
+    pub const INTERFACE_PSP22: InterfaceId = ink::selector_bytes!("PSP22");
+
+    /// Token name, symbol and decimals
+    pub const INTERFACE_METADATA: InterfaceId = ink::selector_bytes!("PSP22Metadata");
+
+    /// burn / burn_from
+    pub const INTERFACE_BURNABLE: InterfaceId = ink::selector_bytes!("PSP22Burnable");
+
+    /// Owner/role-gated minting
+    pub const INTERFACE_MINTABLE: InterfaceId = ink::selector_bytes!("PSP22Mintable");
+
+    /// Global pause and per-message pause
+    pub const INTERFACE_PAUSABLE: InterfaceId = ink::selector_bytes!("Pausable");
+
+    /// Account blacklist
+    pub const INTERFACE_BLACKLIST: InterfaceId = ink::selector_bytes!("Blacklist");
+
+    /// Role-based access control
+    pub const INTERFACE_ACCESS_CONTROL: InterfaceId = ink::selector_bytes!("AccessControl");
+
+    /// Vesting schedules on minted tokens
+    pub const INTERFACE_VESTING: InterfaceId = ink::selector_bytes!("Vesting");
+
+    /// Signed transfer authorizations
+    pub const INTERFACE_PERMIT: InterfaceId = ink::selector_bytes!("Permit");
+
+    /// Transfer fees (enabled while a fee is configured)
+    pub const INTERFACE_FEES: InterfaceId = ink::selector_bytes!("Fees");
+
+    /// Savings vault
+    pub const INTERFACE_VAULT: InterfaceId = ink::selector_bytes!("Vault");
+
+    /// Fee lottery (enabled while configured)
+    pub const INTERFACE_LOTTERY: InterfaceId = ink::selector_bytes!("Lottery");
+
+    /// Emission schedule (enabled while configured)
+    pub const INTERFACE_EMISSION: InterfaceId = ink::selector_bytes!("Emission");
+
+    /// Multisig administration (enabled while a threshold is set)
+    pub const INTERFACE_MULTISIG: InterfaceId = ink::selector_bytes!("Multisig");
+
+    /// Hashed timelocks and cross-token swaps
+    pub const INTERFACE_ESCROW: InterfaceId = ink::selector_bytes!("Escrow");
+
+    /// Default time (ms) a mint request stays approvable
+    pub const DEFAULT_MINT_REQUEST_TTL: u64 = 24 * 60 * 60 * 1000;
+
+    /// Length (ms) of the window a minter's daily quota applies to
+    pub const MINT_QUOTA_WINDOW: u64 = 24 * 60 * 60 * 1000;
+
+    /// Default time (ms) escrowed tokens wait before a burn request can be finalized
+    pub const DEFAULT_BURN_REQUEST_DELAY: u64 = 24 * 60 * 60 * 1000;
+
+    /// Time (ms) a payment request stays payable
+    pub const PAYMENT_REQUEST_TTL: u64 = 30 * 24 * 60 * 60 * 1000;
+
+    /// Maximum length in bytes of the token metadata URI
+    pub const MAX_TOKEN_URI_LEN: usize = 256;
+
+    /// Maximum length in bytes of an account label
+    pub const MAX_LABEL_LEN: usize = 32;
+
+    /// Maximum length in bytes of a payment request memo
+    pub const MAX_MEMO_LEN: usize = 128;
+
+    /// Denominator for all basis-point values (10_000 bps = 100%)
+    pub const BPS_DENOMINATOR: Balance = 10_000;
+
+    /// Highest transfer fee the owner can set (10%)
+    pub const MAX_FEE_BPS: u16 = 1_000;
+
+    /// Fixed-point scale of the reflection-per-token index
+    pub const REFLECTION_PRECISION: Balance = 1_000_000_000_000;
+
+    /// Fixed-point scale of the savings vault share price
+    pub const SHARE_PRICE_PRECISION: Balance = 1_000_000_000_000;
+
+    /// Maximum number of accounts that can take part in the fee lottery
+    pub const MAX_LOTTERY_PARTICIPANTS: u32 = 256;
+
+    /// Time (ms) between proposing and executing a treasury spend
+    pub const TREASURY_TIMELOCK: u64 = 2 * 24 * 60 * 60 * 1000;
+
+    /// Minimum time (ms) an emergency stop stays active before it can be lifted
+    pub const EMERGENCY_STOP_DELAY: u64 = 3 * 24 * 60 * 60 * 1000;
+
+    /// Time (ms) after a seizure during which it can be appealed and reversed
+    pub const SEIZURE_DISPUTE_WINDOW: u64 = 14 * 24 * 60 * 60 * 1000;
+
+    /// Time (ms) a queued supply cap change waits before it can be applied
+    pub const CAP_CHANGE_DELAY: u64 = 7 * 24 * 60 * 60 * 1000;
+
+    /// Longest vote-escrow lock (4 years, in ms); a lock this long gives full weight
+    pub const MAX_VE_LOCK_DURATION: u64 = 4 * 365 * 24 * 60 * 60 * 1000;
+
+    /// Maximum number of vesting schedules an account can hold at once
+    pub const MAX_VESTING_SCHEDULES: u32 = 16;
+
+    /// Maximum length in bytes of a burn reason
+    pub const MAX_BURN_REASON_LEN: usize = 128;
+
+    /// Maximum number of entries in the fee routing table
+    pub const MAX_FEE_ROUTES: usize = 8;
+
+    /// Maximum number of fee discount tiers
+    pub const MAX_FEE_DISCOUNT_TIERS: usize = 8;
+
+    /// Maximum number of operations in one `multicall`
+    pub const MAX_MULTICALL_CALLS: usize = 16;
+
+    /// Maximum number of recipients in one `transfer_split`
+    pub const MAX_SPLIT_RECIPIENTS: usize = 32;
+
+    /// Maximum number of holders kept in the ranked holder report
+    pub const MAX_HOLDER_REPORT: u32 = 1_000;
+
+    /// Number of most recent entries kept in the admin audit log
+    pub const ADMIN_LOG_CAPACITY: u32 = 1_000;
+
+    /// Maximum number of entries in one `burn_batch`
+    pub const MAX_BURN_BATCH: usize = 64;
+
+    /// Maximum number of freeze guardians an account can register
+    pub const MAX_ACCOUNT_GUARDIANS: usize = 8;
+
+    /// Name mixed into the signing domain separator
+    pub const DOMAIN_NAME: &[u8; 11] = b"SimpleToken";
+
+    /// Version mixed into the signing domain separator; bump to invalidate old signatures
+    pub const DOMAIN_VERSION: &[u8; 1] = b"1";
+
+    /// Type tag mixed into signed `transfer_with_authorization` payloads
+    pub const TRANSFER_AUTHORIZATION_TYPE: &[u8; 27] = b"transfer_with_authorization";
+
+    /// Type tag mixed into signed `permit_ecdsa` payloads
+    pub const ECDSA_PERMIT_TYPE: &[u8; 12] = b"permit_ecdsa";
+
+    /// Type tag mixed into signed cheques
+    pub const CHEQUE_TYPE: &[u8; 6] = b"cheque";
+
+    /// Prefix Ethereum wallets add before signing a 32-byte message (`personal_sign`)
+    pub const ETH_SIGNED_MESSAGE_PREFIX: &[u8; 28] = b"\x19Ethereum Signed Message:\n32";
 
     /// Storage structure for our simple token contract
     #[ink(storage)]
@@ -10,130 +203,373 @@ mod simple_token {
         /// Owner of the contract (can mint new tokens)
         owner: AccountId,
         /// Mapping from account to token balance (like a phone book: person -> amount)
-        balances: Mapping<AccountId, u128>,
+        balances: Mapping<AccountId, Balance>,
         /// Total supply of tokens
-        total_supply: u128,
+        total_supply: Balance,
         /// Allowances for spending (owner, spender) -> amount
-        allowances: Mapping<(AccountId, AccountId), u128>,
+        allowances: Mapping<(AccountId, AccountId), Balance>,
         /// Whether the contract is paused
         is_paused: bool,
         /// Blacklist mapping (account -> is_blacklisted)
         blacklist: Mapping<AccountId, bool>,
+        /// Multisig admins that replace the owner for privileged operations
+        multisig_admins: Vec<AccountId>,
+        /// Confirmations needed to execute a proposal (0 = multisig disabled)
+        multisig_threshold: u32,
+        /// Pending and executed multisig proposals (id -> proposal)
+        proposals: Mapping<u32, Proposal>,
+        /// Which admin confirmed which proposal (id, admin) -> confirmed
+        proposal_confirmations: Mapping<(u32, AccountId), bool>,
+        /// Id that will be given to the next proposal
+        next_proposal_id: u32,
+        /// Emergency key that may pause (but do nothing else)
+        guardian: Option<AccountId>,
+        /// When the emergency stop was triggered (None = not active)
+        emergency_stopped_at: Option<u64>,
+        /// Accounts that can jointly recover ownership if the owner key is lost
+        recovery_guardians: Vec<AccountId>,
+        /// Recovery guardian approvals needed to move ownership
+        recovery_threshold: u32,
+        /// Time (ms) within which the approvals must be collected
+        recovery_window: u64,
+        /// Time (ms) between reaching the threshold and ownership moving
+        recovery_delay: u64,
+        /// Recovery currently in progress, if any
+        recovery: Option<RecoveryRequest>,
+        /// Which guardian approved which recovery round (round, guardian) -> approved
+        recovery_approvals: Mapping<(u32, AccountId), bool>,
+        /// Counter that makes every recovery attempt a fresh round
+        recovery_round: u32,
+        /// Account that may claim ownership if the owner stops sending heartbeats
+        successor: Option<AccountId>,
+        /// Maximum time (ms) between heartbeats (0 = dead-man switch disabled)
+        heartbeat_interval: u64,
+        /// When the owner last proved they are active
+        last_heartbeat: u64,
+        /// Account that receives scheduled emissions (None = emission disabled)
+        emission_distributor: Option<AccountId>,
+        /// Tokens emitted per completed era (before any halving)
+        emission_per_era: Balance,
+        /// Length of one emission era in blocks
+        emission_era_length: BlockNumber,
+        /// Blocks after which the per-era emission halves (0 = constant emission)
+        emission_halving_interval: BlockNumber,
+        /// Block at which the current schedule started
+        emission_start: BlockNumber,
+        /// Number of eras since `emission_start` that have already been minted
+        emission_eras_dripped: u32,
+        /// Maximum total supply (None = uncapped)
+        max_supply: Option<Balance>,
+        /// Cap change waiting for its delay to pass
+        pending_cap_change: Option<PendingCapChange>,
+        /// Vesting schedules locking part of an account's balance (account, index) -> schedule
+        vesting_schedules: Mapping<(AccountId, u32), VestingSchedule>,
+        /// Number of vesting schedules per account
+        vesting_schedule_count: Mapping<AccountId, u32>,
+        /// Next unused nonce per account and kind of signed operation
+        nonces: Mapping<(AccountId, NonceKind), u64>,
+        /// Genesis hash of the chain this contract lives on (part of the signing domain)
+        chain_genesis: Option<Hash>,
+        /// Transfers at or above this amount also emit `LargeTransfer` (None = off)
+        large_transfer_threshold: Option<Balance>,
+        /// Individually paused messages (selector -> paused)
+        paused_selectors: Mapping<[u8; 4], bool>,
+        /// Messages restricted to members of a role (selector -> role)
+        selector_access: Mapping<[u8; 4], RoleId>,
+        /// Role membership (role, account) -> has_role
+        roles: Mapping<(RoleId, AccountId), bool>,
+        /// Enumerable role members (role, index) -> account
+        role_members: Mapping<(RoleId, u32), AccountId>,
+        /// Position of each member in `role_members` (role, account) -> index
+        role_member_index: Mapping<(RoleId, AccountId), u32>,
+        /// Number of members per role
+        role_member_count: Mapping<RoleId, u32>,
+        /// Role whose members may grant/revoke a role (unset = owner only)
+        role_admins: Mapping<RoleId, RoleId>,
+        /// Mint requests waiting for approval (id -> request)
+        mint_requests: Mapping<u32, MintRequest>,
+        /// Id that will be given to the next mint request
+        next_mint_request_id: u32,
+        /// Time (ms) a mint request stays approvable
+        mint_request_ttl: u64,
+        /// Escrowed redemption burns (id -> request)
+        burn_requests: Mapping<u32, BurnRequest>,
+        /// Id that will be given to the next burn request
+        next_burn_request_id: u32,
+        /// Time (ms) before an escrowed burn can be finalized
+        burn_request_delay: u64,
+        /// Tokens currently held in burn escrow (still part of total supply)
+        burn_escrow_total: Balance,
+        /// Fee charged on every transfer, in basis points of the amount
+        fee_bps: u16,
+        /// Where the fee goes, in basis points of the fee (adds up to `BPS_DENOMINATOR`)
+        fee_routes: Vec<(FeeDestination, u16)>,
+        /// Reflected tokens accrued per held token, scaled by `REFLECTION_PRECISION`
+        reflection_index: Balance,
+        /// Index value at each account's last balance touch
+        reflection_checkpoints: Mapping<AccountId, Balance>,
+        /// Reflected tokens not yet credited to holders (still part of total supply)
+        reflection_pool: Balance,
+        /// Timelocked spends from the contract-held treasury (id -> spend)
+        treasury_spends: Mapping<u32, TreasurySpend>,
+        /// Id that will be given to the next treasury spend
+        next_treasury_spend_id: u32,
+        /// DEX contract and payable swap message used for buybacks
+        buyback_router: Option<(AccountId, [u8; 4])>,
+        /// Price oracle contract exposing `latest_price`
+        price_oracle: Option<AccountId>,
+        /// Oldest oracle answer (ms) still considered fresh
+        oracle_max_age: u64,
+        /// Price used when the oracle is unset, failing or stale
+        fallback_price: Option<Balance>,
+        /// Tokens held by the savings vault (still part of total supply)
+        vault_assets: Balance,
+        /// Savings vault shares per account
+        vault_shares: Mapping<AccountId, Balance>,
+        /// Total savings vault shares
+        vault_total_shares: Balance,
+        /// Tokens waiting to be awarded (still part of total supply)
+        lottery_pot: Balance,
+        /// Length of a lottery epoch in ms (0 = lottery disabled)
+        lottery_epoch_length: u64,
+        /// When the last lottery epoch ended
+        lottery_last_draw: u64,
+        /// Number of completed draws
+        lottery_epoch: u32,
+        /// Opted-in lottery participants (index -> account)
+        lottery_participants: Mapping<u32, AccountId>,
+        /// Position of each participant in `lottery_participants`
+        lottery_participant_index: Mapping<AccountId, u32>,
+        /// Number of lottery participants
+        lottery_participant_count: u32,
+        /// Block at which trading was enabled (None = launch not started)
+        trading_enabled_at: Option<BlockNumber>,
+        /// Blocks after `enable_trading` during which only whitelisted accounts trade
+        launch_blocks: BlockNumber,
+        /// Accounts allowed to trade before and right after launch
+        launch_whitelist: Mapping<AccountId, bool>,
+        /// Accounts whose incoming transfers count as sells (e.g. DEX pairs)
+        market_accounts: Mapping<AccountId, bool>,
+        /// Most an account can sell per window (None = no limit)
+        sell_limit: Option<Balance>,
+        /// Length of a sell-limit window in blocks (1 = per block)
+        sell_window_blocks: BlockNumber,
+        /// Start of each account's current window and what it sold in it
+        sells_in_window: Mapping<AccountId, (BlockNumber, Balance)>,
+        /// Accounts not subject to the sell limit
+        sell_limit_exempt: Mapping<AccountId, bool>,
+        /// Tokens ever minted
+        total_minted: Balance,
+        /// Tokens ever burned
+        total_burned: Balance,
+        /// When each account last sent or received tokens
+        last_activity: Mapping<AccountId, u64>,
+        /// How transfers to contract accounts are treated
+        contract_recipient_policy: ContractRecipientPolicy,
+        /// Code hashes of contracts that may receive tokens under `AcceptList`
+        accepted_code_hashes: Mapping<Hash, bool>,
+        /// Set while a recipient callback runs; lazy so re-entrant calls see it
+        reentrancy_lock: Lazy<bool>,
+        /// Session keys allowed to spend for an account (account, key) -> budget
+        session_keys: Mapping<(AccountId, AccountId), SessionKey>,
+        /// Guardians each account registered to freeze it if its key is compromised
+        account_guardians: Mapping<AccountId, AccountGuardians>,
+        /// Accounts frozen by their guardians
+        frozen_accounts: Mapping<AccountId, bool>,
+        /// Current freeze voting round per account; bumped whenever votes reset
+        freeze_rounds: Mapping<AccountId, u32>,
+        /// Guardian votes to flip an account's freeze state (account, round, guardian) -> voted
+        freeze_votes: Mapping<(AccountId, u32, AccountId), bool>,
+        /// Number of votes in each round (account, round) -> votes
+        freeze_vote_counts: Mapping<(AccountId, u32), u32>,
+        /// Scheduled ownership handover, if any
+        pending_ownership_transfer: Option<PendingOwnershipTransfer>,
+        /// Whether contract owners must answer `accepts_ownership()` before a handover
+        ownership_probe_required: bool,
+        /// Token name
+        name: Option<String>,
+        /// Token symbol
+        symbol: Option<String>,
+        /// Number of decimals used for display
+        decimals: u8,
+        /// Token contracts created through `create_token` (index -> contract)
+        created_tokens: Mapping<u32, AccountId>,
+        /// Number of token contracts created through `create_token`
+        created_token_count: u32,
+        /// Open payment requests (id -> request)
+        payment_requests: Mapping<u32, PaymentRequest>,
+        /// Id that will be given to the next payment request
+        next_payment_request_id: u32,
+        /// Hashed timelock contracts (id -> lock)
+        htlcs: Mapping<u32, Htlc>,
+        /// Id that will be given to the next HTLC
+        next_htlc_id: u32,
+        /// Tokens currently locked in HTLCs (still part of total supply)
+        htlc_escrow_total: Balance,
+        /// Open cross-token swap offers (id -> offer)
+        swap_offers: Mapping<u32, SwapOffer>,
+        /// Id that will be given to the next swap offer
+        next_swap_offer_id: u32,
+        /// Tokens currently escrowed in swap offers (still part of total supply)
+        swap_escrow_total: Balance,
+        /// Emptied balance entries removed from storage so far
+        pruned_accounts: u64,
+        /// Blacklisted accounts (index -> account)
+        blacklist_members: Mapping<u32, AccountId>,
+        /// Position of each blacklisted account in `blacklist_members`
+        blacklist_index: Mapping<AccountId, u32>,
+        /// Number of blacklisted accounts
+        blacklist_count: u32,
+        /// Accounts with a non-zero balance (index -> account)
+        holders: Mapping<u32, AccountId>,
+        /// Position of each holder in `holders`
+        holder_index: Mapping<AccountId, u32>,
+        /// Number of accounts with a non-zero balance
+        holder_count: u32,
+        /// Holders by descending balance as of the last re-index (rank -> account)
+        holder_ranking: Mapping<u32, AccountId>,
+        /// Number of ranked holders
+        holder_ranking_len: u32,
+        /// When the ranking was last rebuilt
+        holder_ranking_updated_at: u64,
+        /// Link to JSON metadata (logo, description) for wallets and explorers
+        token_uri: Option<String>,
+        /// Admin audit log ring buffer (sequence number % capacity -> entry)
+        admin_log: Mapping<u32, AdminLogEntry>,
+        /// Number of admin actions ever recorded
+        admin_log_len: u32,
+        /// Whether transfers are restricted to the incident allow-path
+        incident_mode: bool,
+        /// Recovery or exchange account that may still receive tokens during an incident
+        incident_recovery_address: Option<AccountId>,
+        /// Transfer volume per window that pauses the contract (None = breaker off)
+        circuit_breaker_threshold: Option<Balance>,
+        /// Length (ms) of a volume window
+        circuit_breaker_window: u64,
+        /// When the current volume window started
+        volume_window_start: u64,
+        /// Volume transferred in the current window
+        volume_in_window: Balance,
+        /// Per-sender limits above which accounts are flagged (None = off)
+        velocity_limits: Option<VelocityLimits>,
+        /// Each sender's activity in its current velocity window
+        send_activity: Mapping<AccountId, SendActivity>,
+        /// Accounts frozen by the velocity check until COMPLIANCE releases them
+        risk_frozen: Mapping<AccountId, bool>,
+        /// Most a minter's requests can mint per `MINT_QUOTA_WINDOW` (missing = no quota)
+        minter_daily_quota: Mapping<AccountId, Balance>,
+        /// Start of each minter's current quota window and the amount minted in it
+        minted_in_window: Mapping<AccountId, (u64, Balance)>,
+        /// Remaining mint budget of each minter, used up by its approved requests
+        minter_allowances: Mapping<AccountId, Balance>,
+        /// Registered bridges with their own cap and outstanding minted amount
+        bridges: Mapping<AccountId, Bridge>,
+        /// Staking/rewards contract told about every balance change (None = off)
+        balance_observer: Option<AccountId>,
+        /// Contracts allowed to receive callbacks, with the ref-time weight each call may use
+        callback_gas_limits: Mapping<AccountId, u64>,
+        /// Treasury spending cap as (bps of the treasury, period in ms) (None = uncapped)
+        treasury_spend_limit: Option<(u16, u64)>,
+        /// When the current treasury spending period started
+        treasury_period_start: u64,
+        /// Treasury balance at the start of the current period
+        treasury_period_base: Balance,
+        /// Amount spent from the treasury in the current period
+        treasury_spent_in_period: Balance,
+        /// Vote-escrow lock of each account
+        ve_locks: Mapping<AccountId, VeLock>,
+        /// Tokens held in vote-escrow locks
+        ve_locked_total: Balance,
+        /// Fee discount tiers: (minimum vote-escrow weight, discount bps), ascending
+        fee_discount_tiers: Vec<(Balance, u16)>,
+        /// Merkle airdrops by ID
+        airdrops: Mapping<u32, Airdrop>,
+        /// Next airdrop ID to assign
+        next_airdrop_id: u32,
+        /// Tokens escrowed for unclaimed airdrop allocations
+        airdrop_escrow_total: Balance,
+        /// Airdrop allocations already claimed, keyed by (airdrop ID, account)
+        airdrop_claimed: Mapping<(u32, AccountId), ()>,
+        /// Relayers allowed to submit airdrop claims on behalf of others
+        claim_sponsors: Mapping<AccountId, ()>,
+        /// Ethereum address each account has registered for ECDSA signatures
+        eth_signers: Mapping<AccountId, [u8; 20]>,
+        /// Cheque nonces already cashed or cancelled, keyed by (payer, nonce)
+        used_cheques: Mapping<(AccountId, u64), ()>,
+        /// Idempotency keys already used, keyed by (sender, key)
+        idempotency_keys: Mapping<(AccountId, [u8; 32]), ()>,
+        /// Caller reference of the `call_with_ref` being executed, echoed in events
+        active_ref_id: Option<[u8; 32]>,
+        /// Latest balance snapshot ID (0 = none taken yet)
+        snapshot_id: u32,
+        /// Balance at a snapshot, keyed by (snapshot ID, account), with the ID of the
+        /// account's previous record (0 = none); written on the first change after it
+        snapshot_balances: Mapping<(u32, AccountId), (Balance, u32)>,
+        /// Latest snapshot ID recorded for each account
+        last_snapshot_recorded: Mapping<AccountId, u32>,
+        /// Native-currency dividends by ID
+        dividends: Mapping<u32, Dividend>,
+        /// Next dividend ID to assign
+        next_dividend_id: u32,
+        /// Dividends already claimed, keyed by (dividend ID, account)
+        dividend_claimed: Mapping<(u32, AccountId), ()>,
+        /// Native currency owed to dividend claimants
+        dividend_reserve: Balance,
+        /// PSP22 token paid as a second vote-escrow reward (None = native only)
+        reward_token: Option<AccountId>,
+        /// Account holding the reward token that has approved this contract to pull from it
+        rewards_account: Option<AccountId>,
+        /// Rewards per locked token as (native, reward token), scaled by `REFLECTION_PRECISION`
+        ve_reward_index: (Balance, Balance),
+        /// Reward index each locker last settled at
+        ve_reward_checkpoints: Mapping<AccountId, (Balance, Balance)>,
+        /// Settled but unclaimed rewards per locker as (native, reward token)
+        ve_rewards_owed: Mapping<AccountId, (Balance, Balance)>,
+        /// Rewards funded but not yet claimed as (native, reward token)
+        ve_rewards_outstanding: (Balance, Balance),
+        /// Accounts that left the holder set after the first snapshot (index -> account)
+        former_holders: Mapping<u32, AccountId>,
+        /// Accounts already listed in `former_holders`
+        former_holder_index: Mapping<AccountId, ()>,
+        /// Number of former holders
+        former_holder_count: u32,
+        /// Short labels on accounts, e.g. "treasury" or "DEX pair"
+        account_labels: Mapping<AccountId, Vec<u8>>,
+        /// Delay (ms) before a new blacklisting takes effect (0 = immediately)
+        blacklist_grace_period: u64,
+        /// Blacklistings waiting out the grace period (account -> effective time)
+        pending_blacklist: Mapping<AccountId, u64>,
+        /// Seizure cases by ID
+        seizures: Mapping<u32, SeizureCase>,
+        /// Next seizure ID to assign
+        next_seizure_id: u32,
+        /// Seized tokens awaiting the end of their dispute window
+        seized_escrow_total: Balance,
+        /// Tokens bridge-burned toward each destination chain
+        bridged_supply: Mapping<ChainId, Balance>,
+        /// Tokens bridge-burned toward all destination chains
+        total_bridged_supply: Balance,
+        /// Set while a confirmed multisig proposal runs, so privileged checks let it through
+        executing_proposal: bool,
+        /// Bumped whenever the admin set changes; older proposals can no longer be confirmed
+        multisig_epoch: u32,
+        /// Whether the active emergency stop was triggered by the guardian rather than the owner
+        emergency_stopped_by_guardian: bool,
+        /// Accounts whose new balance the observer hears about once the message is done
+        pending_balance_notifications: Vec<AccountId>,
     }
 
-    /// Custom error types for better error handling
-    #[derive(Debug, PartialEq, Eq)]
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub enum Error {
-        /// Trying to spend more tokens than available
-        InsufficientBalance,
-        /// Only owner can perform this operation
-        Unauthorized,
-        /// Cannot transfer zero tokens
-        InvalidAmount,
-        /// Arithmetic overflow occurred
-        Overflow,
-        /// Insufficient allowance for transfer
-        InsufficientAllowance,
-        /// Contract is currently paused
-        ContractPaused,
-        /// Account is blacklisted
-        AccountBlacklisted,
-    }
-
-    /// Result type alias for cleaner error handling
-    pub type Result<T> = core::result::Result<T, Error>;
-
-    /// Event emitted when tokens are minted (created)
-    #[ink(event)]
-    pub struct Minted {
-        /// Account that received the new tokens
-        #[ink(topic)]
-        pub to: AccountId,
-        /// Amount of tokens created
-        pub amount: u128,
-        /// When the minting happened
-        pub timestamp: u64,
-    }
-
-    /// Event emitted when tokens are transferred
-    #[ink(event)]
-    pub struct Transfer {
-        /// Account that sent the tokens
-        #[ink(topic)]
-        pub from: AccountId,
-        /// Account that received the tokens
-        #[ink(topic)]
-        pub to: AccountId,
-        /// Amount of tokens transferred
-        pub amount: u128,
-        /// When the transfer happened
-        pub timestamp: u64,
-    }
-
-    /// Event emitted when tokens are burned
-    #[ink(event)]
-    pub struct Burned {
-        /// Account that burned the tokens
-        #[ink(topic)]
-        pub from: AccountId,
-        /// Amount of tokens burned
-        pub amount: u128,
-        /// When the burning happened
-        pub timestamp: u64,
-    }
-
-    /// Event emitted when spending approval is granted
-    #[ink(event)]
-    pub struct Approval {
-        /// Account that owns the tokens
-        #[ink(topic)]
-        pub owner: AccountId,
-        /// Account that can spend the tokens
-        #[ink(topic)]
-        pub spender: AccountId,
-        /// Amount approved for spending
-        pub amount: u128,
-    }
-
-    /// Event emitted when contract is paused
-    #[ink(event)]
-    pub struct Paused {
-        /// Account that paused the contract
-        pub by: AccountId,
-        /// When it was paused
-        pub timestamp: u64,
-    }
-
-    /// Event emitted when contract is unpaused
-    #[ink(event)]
-    pub struct Unpaused {
-        /// Account that unpaused the contract
-        pub by: AccountId,
-        /// When it was unpaused
-        pub timestamp: u64,
-    }
-
-    /// Event emitted when an account is blacklisted
-    #[ink(event)]
-    pub struct Blacklisted {
-        /// Account that was blacklisted
-        #[ink(topic)]
-        pub account: AccountId,
-        /// Account that did the blacklisting
-        pub by: AccountId,
-    }
-
-    /// Event emitted when an account is removed from blacklist
-    #[ink(event)]
-    pub struct Unblacklisted {
-        /// Account that was removed from blacklist
-        #[ink(topic)]
-        pub account: AccountId,
-        /// Account that did the removal
-        pub by: AccountId,
-    }
+    // The contract is split across files by concern: core token logic (`core.rs`),
+    // administration (`admin.rs`) and compliance checks (`compliance.rs`) hold the
+    // internal helpers, and each feature's messages below sit in their own `impl`
+    // block. ink! needs every message and the storage struct inline in this module,
+    // and out-of-line `mod` items inside the macro input are not stable, so the
+    // helper files are textually included rather than declared as submodules. The
+    // storage struct stays whole: splitting it into nested storage items would
+    // change the on-chain layout of deployed instances.
+    include!("core.rs");
+    include!("admin.rs");
+    include!("compliance.rs");
 
     impl SimpleToken {
         /// Constructor - called once when contract is deployed
@@ -146,123 +582,7862 @@ mod simple_token {
                 allowances: Mapping::default(),
                 is_paused: false,
                 blacklist: Mapping::default(),
+                multisig_admins: Vec::new(),
+                multisig_threshold: 0,
+                proposals: Mapping::default(),
+                proposal_confirmations: Mapping::default(),
+                next_proposal_id: 0,
+                guardian: None,
+                emergency_stopped_at: None,
+                recovery_guardians: Vec::new(),
+                recovery_threshold: 0,
+                recovery_window: 0,
+                recovery_delay: 0,
+                recovery: None,
+                recovery_approvals: Mapping::default(),
+                recovery_round: 0,
+                successor: None,
+                heartbeat_interval: 0,
+                last_heartbeat: 0,
+                emission_distributor: None,
+                emission_per_era: 0,
+                emission_era_length: 0,
+                emission_halving_interval: 0,
+                emission_start: 0,
+                emission_eras_dripped: 0,
+                max_supply: None,
+                pending_cap_change: None,
+                vesting_schedules: Mapping::default(),
+                vesting_schedule_count: Mapping::default(),
+                nonces: Mapping::default(),
+                chain_genesis: None,
+                large_transfer_threshold: None,
+                paused_selectors: Mapping::default(),
+                selector_access: Mapping::default(),
+                roles: Mapping::default(),
+                role_members: Mapping::default(),
+                role_member_index: Mapping::default(),
+                role_member_count: Mapping::default(),
+                role_admins: Mapping::default(),
+                mint_requests: Mapping::default(),
+                next_mint_request_id: 0,
+                mint_request_ttl: DEFAULT_MINT_REQUEST_TTL,
+                burn_requests: Mapping::default(),
+                next_burn_request_id: 0,
+                burn_request_delay: DEFAULT_BURN_REQUEST_DELAY,
+                burn_escrow_total: 0,
+                fee_bps: 0,
+                fee_routes: Vec::new(),
+                reflection_index: 0,
+                reflection_checkpoints: Mapping::default(),
+                reflection_pool: 0,
+                treasury_spends: Mapping::default(),
+                next_treasury_spend_id: 0,
+                buyback_router: None,
+                price_oracle: None,
+                oracle_max_age: 0,
+                fallback_price: None,
+                vault_assets: 0,
+                vault_shares: Mapping::default(),
+                vault_total_shares: 0,
+                lottery_pot: 0,
+                lottery_epoch_length: 0,
+                lottery_last_draw: 0,
+                lottery_epoch: 0,
+                lottery_participants: Mapping::default(),
+                lottery_participant_index: Mapping::default(),
+                lottery_participant_count: 0,
+                trading_enabled_at: None,
+                launch_blocks: 0,
+                launch_whitelist: Mapping::default(),
+                market_accounts: Mapping::default(),
+                sell_limit: None,
+                sell_window_blocks: 1,
+                sells_in_window: Mapping::default(),
+                sell_limit_exempt: Mapping::default(),
+                total_minted: 0,
+                total_burned: 0,
+                last_activity: Mapping::default(),
+                contract_recipient_policy: ContractRecipientPolicy::Allow,
+                accepted_code_hashes: Mapping::default(),
+                reentrancy_lock: Lazy::default(),
+                session_keys: Mapping::default(),
+                account_guardians: Mapping::default(),
+                frozen_accounts: Mapping::default(),
+                freeze_rounds: Mapping::default(),
+                freeze_votes: Mapping::default(),
+                freeze_vote_counts: Mapping::default(),
+                pending_ownership_transfer: None,
+                ownership_probe_required: false,
+                name: None,
+                symbol: None,
+                decimals: 0,
+                created_tokens: Mapping::default(),
+                created_token_count: 0,
+                payment_requests: Mapping::default(),
+                next_payment_request_id: 0,
+                htlcs: Mapping::default(),
+                next_htlc_id: 0,
+                htlc_escrow_total: 0,
+                swap_offers: Mapping::default(),
+                next_swap_offer_id: 0,
+                swap_escrow_total: 0,
+                pruned_accounts: 0,
+                blacklist_members: Mapping::default(),
+                blacklist_index: Mapping::default(),
+                blacklist_count: 0,
+                holders: Mapping::default(),
+                holder_index: Mapping::default(),
+                holder_count: 0,
+                holder_ranking: Mapping::default(),
+                holder_ranking_len: 0,
+                holder_ranking_updated_at: 0,
+                token_uri: None,
+                admin_log: Mapping::default(),
+                admin_log_len: 0,
+                incident_mode: false,
+                incident_recovery_address: None,
+                circuit_breaker_threshold: None,
+                circuit_breaker_window: 0,
+                volume_window_start: 0,
+                volume_in_window: 0,
+                velocity_limits: None,
+                send_activity: Mapping::default(),
+                risk_frozen: Mapping::default(),
+                minter_daily_quota: Mapping::default(),
+                minted_in_window: Mapping::default(),
+                minter_allowances: Mapping::default(),
+                bridges: Mapping::default(),
+                balance_observer: None,
+                callback_gas_limits: Mapping::default(),
+                treasury_spend_limit: None,
+                treasury_period_start: 0,
+                treasury_period_base: 0,
+                treasury_spent_in_period: 0,
+                ve_locks: Mapping::default(),
+                ve_locked_total: 0,
+                fee_discount_tiers: Vec::new(),
+                airdrops: Mapping::default(),
+                next_airdrop_id: 0,
+                airdrop_escrow_total: 0,
+                airdrop_claimed: Mapping::default(),
+                claim_sponsors: Mapping::default(),
+                eth_signers: Mapping::default(),
+                used_cheques: Mapping::default(),
+                idempotency_keys: Mapping::default(),
+                active_ref_id: None,
+                snapshot_id: 0,
+                snapshot_balances: Mapping::default(),
+                last_snapshot_recorded: Mapping::default(),
+                dividends: Mapping::default(),
+                next_dividend_id: 0,
+                dividend_claimed: Mapping::default(),
+                dividend_reserve: 0,
+                reward_token: None,
+                rewards_account: None,
+                ve_reward_index: (0, 0),
+                ve_reward_checkpoints: Mapping::default(),
+                ve_rewards_owed: Mapping::default(),
+                ve_rewards_outstanding: (0, 0),
+                former_holders: Mapping::default(),
+                former_holder_index: Mapping::default(),
+                former_holder_count: 0,
+                account_labels: Mapping::default(),
+                blacklist_grace_period: 0,
+                pending_blacklist: Mapping::default(),
+                seizures: Mapping::default(),
+                next_seizure_id: 0,
+                seized_escrow_total: 0,
+                bridged_supply: Mapping::default(),
+                total_bridged_supply: 0,
+                executing_proposal: false,
+                multisig_epoch: 0,
+                emergency_stopped_by_guardian: false,
+                pending_balance_notifications: Vec::new(),
+            }
+        }
+
+        /// Constructor taking the whole initial setup as one struct
+        #[ink(constructor)]
+        pub fn new_with_config(config: TokenConfig) -> Result<Self> {
+            let mut token = Self::new();
+            token.name = config.name;
+            token.symbol = config.symbol;
+            token.decimals = config.decimals;
+            token.max_supply = config.max_supply;
+            #[cfg(feature = "pause")]
+            {
+                token.is_paused = config.paused;
+            }
+            if let Some(owner) = config.owner {
+                token.owner = owner;
+            }
+            #[cfg(feature = "fees")]
+            token.set_fees(config.fee_bps, config.fee_routes)?;
+            #[cfg(not(feature = "fees"))]
+            if config.fee_bps > 0 {
+                return Err(Error::InvalidFeeConfig);
+            }
+
+            // Genesis allocations are minted like any other mint, cap included
+            for (account, amount) in config.allocations {
+                token._mint(account, amount)?;
+            }
+
+            Ok(token)
+        }
+    }
+
+    // ========== PRIVATE HELPER FUNCTIONS ==========
+    impl SimpleToken {
+        /// Internal helper that asks the oracle for a fresh price
+        ///
+        /// The oracle must expose `latest_price() -> (Balance, u64)` returning the
+        /// price and the timestamp (ms) it was last updated. Like every callback it
+        /// must be on the callback whitelist and is capped at its gas limit there.
+        fn oracle_price(&self) -> Option<Balance> {
+            let oracle = self.price_oracle?;
+            let gas_limit = self.callback_gas_limit(oracle).ok()?;
+            let result = build_call::<Environment>()
+                .call(oracle)
+                .ref_time_limit(gas_limit)
+                .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "latest_price"
+                ))))
+                .returns::<(Balance, u64)>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok((price, updated_at)))
+                    if self.env().block_timestamp().saturating_sub(updated_at) <= self.oracle_max_age =>
+                {
+                    Some(price)
+                }
+                _ => None,
+            }
+        }
+
+        /// Internal helper that calls `PSP22::transfer` on another token contract
+        fn call_psp22_transfer(&self, token: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("PSP22::transfer")))
+                        .push_arg(to)
+                        .push_arg(amount)
+                        .push_arg(Vec::<u8>::new()),
+                )
+                .returns::<core::result::Result<(), PSP22Error>>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::TokenTransferFailed),
+            }
+        }
+
+        /// Internal helper that calls `PSP22::transfer_from` on another token contract
+        fn call_psp22_transfer_from(&self, token: AccountId, from: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+            let result = build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("PSP22::transfer_from")))
+                        .push_arg(from)
+                        .push_arg(to)
+                        .push_arg(amount)
+                        .push_arg(Vec::<u8>::new()),
+                )
+                .returns::<core::result::Result<(), PSP22Error>>()
+                .try_invoke();
+
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(Error::TokenTransferFailed),
+            }
+        }
+
+        /// Internal helper that closes a swap offer and returns the escrow to its maker
+        fn close_swap_offer(&mut self, id: u32, offer: SwapOffer) -> Result<()> {
+            self.swap_offers.remove(id);
+            self.swap_escrow_total = self
+                .swap_escrow_total
+                .checked_sub(offer.amount)
+                .ok_or(Error::Overflow)?;
+            self.credit(offer.maker, offer.amount)?;
+
+            self.env().emit_event(SwapOfferClosed {
+                id,
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Internal helper: number of full eras completed by `block` since the schedule started
+        fn completed_eras_at(&self, block: BlockNumber) -> u32 {
+            if self.emission_era_length == 0 {
+                return 0;
+            }
+            block.saturating_sub(self.emission_start) / self.emission_era_length
+        }
+
+        /// Internal helper: emission of a single era after applying halvings
+        fn era_emission(&self, era: u32) -> Balance {
+            if self.emission_halving_interval == 0 {
+                return self.emission_per_era;
+            }
+            // Halvings are counted from the block at which the era starts
+            let era_start = u64::from(era).saturating_mul(u64::from(self.emission_era_length));
+            let halvings = era_start / u64::from(self.emission_halving_interval);
+            if halvings >= 128 {
+                return 0;
+            }
+            self.emission_per_era >> halvings
+        }
+
+        /// Internal helper: total emission of eras `from..to`, summed per halving period
+        fn emission_for_eras(&self, from: u32, to: u32) -> Balance {
+            let mut total: Balance = 0;
+            let mut era = from;
+            while era < to {
+                let rate = self.era_emission(era);
+                if rate == 0 {
+                    break;
+                }
+
+                // Find the first era after `era` that falls into the next halving period
+                let next = if self.emission_halving_interval == 0 {
+                    to
+                } else {
+                    let interval = u64::from(self.emission_halving_interval);
+                    let length = u64::from(self.emission_era_length);
+                    let halvings = u64::from(era).saturating_mul(length) / interval;
+                    let boundary = halvings.saturating_add(1).saturating_mul(interval);
+                    let next_era = boundary.div_ceil(length);
+                    u32::try_from(next_era).unwrap_or(u32::MAX).min(to)
+                };
+
+                total = total.saturating_add(rate.saturating_mul(Balance::from(next - era)));
+                era = next;
+            }
+            total
+        }
+
+        /// Internal helper: amount of a schedule that is unlocked at `now`
+        fn vested_amount(schedule: &VestingSchedule, now: u64) -> Balance {
+            let elapsed = now.saturating_sub(schedule.start);
+            if now < schedule.start || elapsed < schedule.cliff {
+                return 0;
+            }
+            if elapsed >= schedule.duration {
+                return schedule.amount;
+            }
+            // Linear unlock; duration > elapsed here so it is non-zero
+            Self::mul_div(
+                schedule.amount,
+                Balance::from(elapsed),
+                Balance::from(schedule.duration),
+            )
+            .unwrap_or(schedule.amount)
+        }
+
+        /// Internal helper: part of an account's balance still locked by vesting
+        fn vesting_locked(&self, account: AccountId) -> Balance {
+            let now = self.env().block_timestamp();
+            let count = self.vesting_schedule_count.get(account).unwrap_or(0);
+            (0..count)
+                .filter_map(|index| self.vesting_schedules.get((account, index)))
+                .map(|schedule| schedule.amount.saturating_sub(Self::vested_amount(&schedule, now)))
+                .fold(0, |total: Balance, locked| total.saturating_add(locked))
+        }
+
+        /// Internal helper that stores a new schedule, dropping fully vested ones to make room
+        fn add_vesting_schedule(&mut self, account: AccountId, schedule: VestingSchedule) -> Result<()> {
+            let now = self.env().block_timestamp();
+            let count = self.vesting_schedule_count.get(account).unwrap_or(0);
+
+            // Compact the list, keeping only schedules that still lock something
+            let mut kept: u32 = 0;
+            for index in 0..count {
+                if let Some(existing) = self.vesting_schedules.get((account, index)) {
+                    if Self::vested_amount(&existing, now) < existing.amount {
+                        self.vesting_schedules.insert((account, kept), &existing);
+                        kept = kept.saturating_add(1);
+                    }
+                }
+            }
+            for index in kept..count {
+                self.vesting_schedules.remove((account, index));
+            }
+
+            if kept >= MAX_VESTING_SCHEDULES {
+                return Err(Error::TooManySchedules);
             }
+
+            self.vesting_schedules.insert((account, kept), &schedule);
+            self.vesting_schedule_count.insert(account, &kept.saturating_add(1));
+
+            Ok(())
+        }
+
+        /// Internal helper: hash binding signatures to this contract, chain and version
+        fn compute_domain_separator(&self) -> [u8; 32] {
+            self.env().hash_encoded::<Blake2x256, _>(&(
+                DOMAIN_NAME,
+                DOMAIN_VERSION,
+                self.chain_genesis.unwrap_or_default(),
+                self.env().account_id(),
+            ))
+        }
+
+        /// Internal helper that checks an sr25519 signature made by `signer` over `payload`
+        ///
+        /// The signed message is the blake2-256 hash of the SCALE-encoded
+        /// `(domain_separator, payload)` pair, so signatures cannot be replayed
+        /// against another contract, chain or contract version.
+        fn verify_signature<T: scale::Encode>(
+            &self,
+            signer: AccountId,
+            payload: &T,
+            signature: &[u8; 64],
+        ) -> Result<()> {
+            let message = self
+                .env()
+                .hash_encoded::<Blake2x256, _>(&(self.compute_domain_separator(), payload));
+            let public_key: &[u8; 32] = signer.as_ref();
+            self.env()
+                .sr25519_verify(signature, &message, public_key)
+                .map_err(|_| Error::InvalidSignature)
         }
 
-        // ========== PRIVATE HELPER FUNCTIONS ==========
+        /// Internal helper that checks an Ethereum wallet signature for `signer` over `payload`
+        ///
+        /// The wallet signs the keccak-256 hash of the SCALE-encoded
+        /// `(domain_separator, payload)` with `personal_sign`; the recovered address
+        /// must be the one `signer` registered.
+        fn verify_ecdsa_signature<T: scale::Encode>(
+            &self,
+            signer: AccountId,
+            payload: &T,
+            signature: &[u8; 65],
+        ) -> Result<()> {
+            let eth_address = self.eth_signers.get(signer).ok_or(Error::NoEthSigner)?;
+
+            let digest = self
+                .env()
+                .hash_encoded::<Keccak256, _>(&(self.compute_domain_separator(), payload));
+            let mut prefixed = [0u8; 60];
+            prefixed[..28].copy_from_slice(ETH_SIGNED_MESSAGE_PREFIX);
+            prefixed[28..].copy_from_slice(&digest);
+            let message = self.env().hash_bytes::<Keccak256>(&prefixed);
+
+            let mut public_key = [0u8; 33];
+            ink::env::ecdsa_recover(signature, &message, &mut public_key)
+                .map_err(|_| Error::InvalidSignature)?;
+            let mut recovered = [0u8; 20];
+            ink::env::ecdsa_to_eth_address(&public_key, &mut recovered)
+                .map_err(|_| Error::InvalidSignature)?;
+
+            if recovered != eth_address {
+                return Err(Error::InvalidSignature);
+            }
+            Ok(())
+        }
 
-        /// Internal helper to check if account is blacklisted
-        fn check_blacklisted(&self, account: AccountId) -> bool {
-            self.blacklist.get(account).unwrap_or(false)
+        /// Internal helper that consumes `nonce` if it is the account's next nonce of `kind`
+        ///
+        /// Each kind of signed operation counts separately, so a queue of pending
+        /// permits does not hold up meta-transactions and vice versa.
+        fn use_nonce(&mut self, account: AccountId, kind: NonceKind, nonce: u64) -> Result<()> {
+            let current = self.nonces.get((account, kind)).unwrap_or(0);
+            if nonce != current {
+                return Err(Error::InvalidNonce);
+            }
+            self.nonces
+                .insert((account, kind), &current.checked_add(1).ok_or(Error::Overflow)?);
+            Ok(())
         }
+    }
 
+    // ========== CORE TOKEN ==========
+    impl SimpleToken {
         /// Mint (create) new tokens - only owner can do this
         #[ink(message)]
-        pub fn mint(&mut self, to: AccountId, amount: u128) -> Result<()> {
+        pub fn mint(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("mint"))?;
+
             // Validate: Only owner can mint tokens
-            if self.env().caller() != self.owner {
-                return Err(Error::Unauthorized);
-            }
+            self.ensure_privileged()?;
+            self._mint(to, amount)?;
+            self.record_admin_action(AdminLogAction::Mint { to, amount });
+            self.flush_balance_notifications();
+            Ok(())
+        }
 
-            // Validate: Cannot mint zero tokens
-            if amount == 0 {
-                return Err(Error::InvalidAmount);
+        /// Mint tokens, first asking a contract recipient whether it can handle them - only owner
+        ///
+        /// A contract recipient must be on the callback whitelist and answer
+        /// `accepts_tokens(operator: AccountId, amount: Balance, data: Vec<u8>) -> bool`
+        /// with `true`; plain accounts are minted to directly.
+        #[ink(message)]
+        pub fn safe_mint(&mut self, to: AccountId, amount: Balance, data: Vec<u8>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("safe_mint"))?;
+            self.ensure_privileged()?;
+
+            if self.env().is_contract(&to) {
+                let gas_limit = self.callback_gas_limit(to)?;
+                let result = build_call::<Environment>()
+                    .call(to)
+                    .ref_time_limit(gas_limit)
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(ink::selector_bytes!("accepts_tokens")))
+                            .push_arg(self.env().caller())
+                            .push_arg(amount)
+                            .push_arg(data),
+                    )
+                    .returns::<bool>()
+                    .try_invoke();
+
+                if !matches!(result, Ok(Ok(true))) {
+                    return Err(Error::ContractRecipientRejected);
+                }
             }
 
-            // Get current balance of the recipient
-            let current_balance = self.balances.get(to).unwrap_or(0);
+            self._mint(to, amount)?;
+            self.record_admin_action(AdminLogAction::Mint { to, amount });
+            self.flush_balance_notifications();
+            Ok(())
+        }
 
-            // Update the balance (add new tokens) with overflow protection
-            let new_balance = current_balance
-                .checked_add(amount)
-                .ok_or(Error::Overflow)?;
-            self.balances.insert(to, &new_balance);
+        /// Mint tokens that unlock linearly over time - only owner can do this
+        ///
+        /// Nothing unlocks before `start + cliff`; everything is unlocked at
+        /// `start + duration`. The lock is applied in the same call as the mint.
+        #[ink(message)]
+        pub fn mint_with_vesting(
+            &mut self,
+            to: AccountId,
+            amount: Balance,
+            start: u64,
+            cliff: u64,
+            duration: u64,
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("mint_with_vesting"))?;
+            self.ensure_privileged()?;
 
-            // Update total supply with overflow protection
-            self.total_supply = self.total_supply
-                .checked_add(amount)
-                .ok_or(Error::Overflow)?;
+            // Validate: The cliff must fall inside a non-empty vesting period
+            if duration == 0 || cliff > duration {
+                return Err(Error::InvalidSchedule);
+            }
 
-            // Emit event for transparency
-            self.env().emit_event(Minted {
+            self._mint(to, amount)?;
+            self.record_admin_action(AdminLogAction::Mint { to, amount });
+            self.add_vesting_schedule(
                 to,
+                VestingSchedule {
+                    amount,
+                    start,
+                    cliff,
+                    duration,
+                },
+            )?;
+
+            self.env().emit_event(VestingScheduleCreated {
+                account: to,
                 amount,
-                timestamp: self.env().block_timestamp(),
+                start,
+                cliff,
+                duration,
             });
+            self.flush_balance_notifications();
 
             Ok(())
         }
 
-        /// Check the balance of an account
+        /// Get how much of an account's balance is still locked by vesting
         #[ink(message)]
-        pub fn balance_of(&self, account: AccountId) -> u128 {
-            // Get balance, return 0 if account doesn't exist
-            self.balances.get(account).unwrap_or(0)
+        pub fn vesting_locked_of(&self, account: AccountId) -> Balance {
+            self.vesting_locked(account)
         }
 
-        /// Transfer tokens from caller to another account
+        /// Get every vesting schedule on an account with its progress
         #[ink(message)]
-        pub fn transfer(&mut self, to: AccountId, amount: u128) -> Result<()> {
-            let caller = self.env().caller();
+        pub fn vesting_of(&self, account: AccountId) -> Vec<VestingInfo> {
+            let now = self.env().block_timestamp();
+            let count = self.vesting_schedule_count.get(account).unwrap_or(0);
+            (0..count)
+                .filter_map(|index| self.vesting_schedules.get((account, index)))
+                .map(|schedule| {
+                    let unlocked = Self::vested_amount(&schedule, now);
+                    VestingInfo {
+                        amount: schedule.amount,
+                        start: schedule.start,
+                        cliff_end: schedule.start.saturating_add(schedule.cliff),
+                        end: schedule.start.saturating_add(schedule.duration),
+                        unlocked,
+                        locked: schedule.amount.saturating_sub(unlocked),
+                    }
+                })
+                .collect()
+        }
 
-            // Check if contract is paused
-            if self.is_paused {
-                return Err(Error::ContractPaused);
+        /// Get the locks holding an account's tokens: its vote-escrow lock and any freeze
+        #[ink(message)]
+        pub fn locks_of(&self, account: AccountId) -> Vec<LockInfo> {
+            let mut locks = Vec::new();
+            if let Some(lock) = self.ve_locks.get(account) {
+                locks.push(LockInfo {
+                    kind: LockKind::VoteEscrow,
+                    amount: lock.amount,
+                    unlock_at: Some(lock.end),
+                });
+            }
+            let frozen = self.frozen_of(account);
+            if frozen > 0 {
+                locks.push(LockInfo {
+                    kind: LockKind::Freeze,
+                    amount: frozen,
+                    unlock_at: None,
+                });
             }
+            locks
+        }
 
-            // Check if caller or recipient is blacklisted
-            if self.check_blacklisted(caller) || self.check_blacklisted(to) {
-                return Err(Error::AccountBlacklisted);
+        /// Get how much of an account's balance is held by compliance freezes
+        ///
+        /// Guardian freezes, velocity (risk) freezes and blacklisting each stop the
+        /// whole balance from moving, so this is either the full balance or zero.
+        #[ink(message)]
+        pub fn frozen_of(&self, account: AccountId) -> Balance {
+            if self.ensure_not_frozen(account).is_err() || self.check_blacklisted(account) {
+                self.balances.get(account).unwrap_or(0)
+            } else {
+                0
             }
+        }
 
-            // Validate: Cannot transfer zero tokens
-            if amount == 0 {
+        /// Get how much an account can move right now
+        ///
+        /// Balance plus pending reflection, minus tokens still vesting; zero while
+        /// the account is frozen or transfers are paused or emergency-stopped.
+        /// Tokens in vote-escrow locks, timelocks and other escrows have already
+        /// left the balance. Recipient-specific rules (sell limits, incident mode)
+        /// are not applied.
+        #[ink(message)]
+        pub fn transferable_balance_of(&self, account: AccountId) -> Balance {
+            if self.ensure_not_paused().is_err() || self.ensure_no_emergency().is_err() || self.frozen_of(account) > 0 {
+                return 0;
+            }
+            self.balances
+                .get(account)
+                .unwrap_or(0)
+                .saturating_add(self.reflection_owed(account))
+                .saturating_sub(self.vesting_locked(account))
+        }
+
+        /// Transfer tokens from caller to another account, unless `deadline` has passed
+        ///
+        /// Guards against a transaction that sits in the pool and lands much later
+        /// than the sender intended.
+        #[ink(message)]
+        pub fn transfer_with_deadline(&mut self, to: AccountId, amount: Balance, deadline: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("transfer_with_deadline"))?;
+
+            if self.env().block_timestamp() > deadline {
+                return Err(Error::DeadlinePassed);
+            }
+
+            let caller = self.env().caller();
+            self._transfer(caller, to, amount)?;
+            self.flush_balance_notifications();
+            Ok(())
+        }
+
+        /// Transfer tokens from caller to another account at most once per `key`
+        ///
+        /// Replaying a key the caller already used moves nothing and returns
+        /// `TransferOutcome::Duplicate`, so payout systems can retry after a timeout
+        /// without sending twice.
+        #[ink(message)]
+        pub fn transfer_idempotent(
+            &mut self,
+            to: AccountId,
+            amount: Balance,
+            key: [u8; 32],
+        ) -> Result<TransferOutcome> {
+            self.guard_selector(ink::selector_bytes!("transfer_idempotent"))?;
+
+            let caller = self.env().caller();
+            if self.idempotency_keys.contains((caller, key)) {
+                return Ok(TransferOutcome::Duplicate);
+            }
+
+            self._transfer(caller, to, amount)?;
+            self.idempotency_keys.insert((caller, key), &());
+            self.flush_balance_notifications();
+
+            Ok(TransferOutcome::Executed)
+        }
+
+        /// Check whether an account has already used an idempotency key
+        #[ink(message)]
+        pub fn is_idempotency_key_used(&self, account: AccountId, key: [u8; 32]) -> bool {
+            self.idempotency_keys.contains((account, key))
+        }
+
+        /// Divide `amount` among several recipients by basis points
+        ///
+        /// Shares must add up to `BPS_DENOMINATOR`; rounding dust goes to the
+        /// first recipient.
+        #[ink(message)]
+        pub fn transfer_split(&mut self, amount: Balance, recipients: Vec<(AccountId, u16)>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("transfer_split"))?;
+
+            // Validate: Bounded list whose shares cover the whole amount
+            if amount == 0 || recipients.is_empty() || recipients.len() > MAX_SPLIT_RECIPIENTS {
+                return Err(Error::InvalidAmount);
+            }
+            let total_bps = recipients
+                .iter()
+                .fold(0, |total: Balance, (_, bps)| total.saturating_add(Balance::from(*bps)));
+            if total_bps != BPS_DENOMINATOR {
                 return Err(Error::InvalidAmount);
             }
 
-            // Get caller's balance
-            let caller_balance = self.balances.get(caller).unwrap_or(0);
+            let mut shares = Vec::with_capacity(recipients.len());
+            for (to, bps) in &recipients {
+                let share = Self::mul_div(amount, Balance::from(*bps), BPS_DENOMINATOR).ok_or(Error::Overflow)?;
+                shares.push((*to, share));
+            }
+            let distributed = shares.iter().fold(0, |total: Balance, (_, share)| total + share);
+            shares[0].1 += amount - distributed;
 
-            // Validate: Caller must have enough tokens
-            if caller_balance < amount {
-                return Err(Error::InsufficientBalance);
+            let caller = self.env().caller();
+            for (to, share) in shares {
+                if share > 0 {
+                    self._transfer(caller, to, share)?;
+                }
             }
+            self.flush_balance_notifications();
 
-            // Get recipient's balance
-            let to_balance = self.balances.get(to).unwrap_or(0);
+            Ok(())
+        }
 
-            // Update balances with overflow/underflow protection
-            let new_caller_balance = caller_balance
-                .checked_sub(amount)
-                .ok_or(Error::Overflow)?;
-            let new_to_balance = to_balance
-                .checked_add(amount)
-                .ok_or(Error::Overflow)?;
+        /// Transfer tokens to a contract and then call it with the given payload
+        ///
+        /// The recipient must be on the callback whitelist and expose a message at
+        /// `selector` taking `(from: AccountId, amount: Balance, data: Vec<u8>)` and
+        /// returning nothing.
+        /// If that call fails the whole message returns an error, which reverts
+        /// the transfer as well.
+        #[ink(message)]
+        pub fn transfer_and_call(
+            &mut self,
+            to: AccountId,
+            amount: Balance,
+            selector: [u8; 4],
+            data: Vec<u8>,
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("transfer_and_call"))?;
 
-            self.balances.insert(caller, &new_caller_balance);
-            self.balances.insert(to, &new_to_balance);
+            let caller = self.env().caller();
 
-            // Emit event for transparency
-            self.env().emit_event(Transfer {
-                from: caller,
+            // Move the tokens first so the recipient sees its new balance
+            self._transfer(caller, to, amount)?;
+
+            // Notify the recipient; any failure rolls back the transfer above.
+            // Re-entry is allowed so it can query us, but state changes are locked out.
+            let gas_limit = self.callback_gas_limit(to)?;
+            self.enter_non_reentrant()?;
+            let result = build_call::<Environment>()
+                .call(to)
+                .ref_time_limit(gas_limit)
+                .call_flags(CallFlags::ALLOW_REENTRY)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selector))
+                        .push_arg(caller)
+                        .push_arg(amount)
+                        .push_arg(data),
+                )
+                .returns::<()>()
+                .try_invoke();
+            self.exit_non_reentrant();
+
+            if !matches!(result, Ok(Ok(()))) {
+                return Err(Error::RecipientCallFailed);
+            }
+            self.flush_balance_notifications();
+
+            Ok(())
+        }
+
+        /// Change an allowance only if it still equals `expected_current`
+        ///
+        /// Protects against a spender front-running an allowance change and
+        /// spending both the old and the new amount.
+        #[ink(message)]
+        pub fn approve_from_to(
+            &mut self,
+            spender: AccountId,
+            expected_current: Balance,
+            new_amount: Balance,
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("approve_from_to"))?;
+
+            let caller = self.env().caller();
+
+            // Validate: Allowance must not have been used or changed meanwhile
+            if self.allowances.get((caller, spender)).unwrap_or(0) != expected_current {
+                return Err(Error::AllowanceMismatch);
+            }
+
+            self.set_allowance(caller, spender, new_amount, AllowanceChangeCause::Approve);
+            Ok(())
+        }
+
+        /// Execute a transfer signed off-chain by `from` - anyone (e.g. a relayer) can submit
+        ///
+        /// `from` signs `(TRANSFER_AUTHORIZATION_TYPE, from, to, amount, valid_after,
+        /// valid_before, nonce)` under the domain separator (see `verify_signature`).
+        /// The nonce must be `from`'s next `NonceKind::MetaTx` nonce.
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn transfer_with_authorization(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            amount: Balance,
+            valid_after: u64,
+            valid_before: u64,
+            nonce: u64,
+            signature: [u8; 64],
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("transfer_with_authorization"))?;
+
+            let now = self.env().block_timestamp();
+
+            // Validate: Authorization must be inside its validity window
+            if now <= valid_after {
+                return Err(Error::AuthorizationNotYetValid);
+            }
+            if now >= valid_before {
+                return Err(Error::AuthorizationExpired);
+            }
+
+            let payload = (
+                TRANSFER_AUTHORIZATION_TYPE,
+                from,
                 to,
                 amount,
-                timestamp: self.env().block_timestamp(),
+                valid_after,
+                valid_before,
+                nonce,
+            );
+            self.verify_signature(from, &payload, &signature)?;
+
+            // Mark the nonce used so the authorization cannot be replayed
+            self.use_nonce(from, NonceKind::MetaTx, nonce)?;
+            self._transfer(from, to, amount)?;
+
+            self.env().emit_event(AuthorizationUsed {
+                authorizer: from,
+                nonce,
             });
+            self.flush_balance_notifications();
 
             Ok(())
         }
 
-        /// Get the total supply of tokens
+        /// Set an allowance signed by `owner` from an Ethereum wallet - anyone can submit
+        ///
+        /// `owner` signs `(ECDSA_PERMIT_TYPE, owner, spender, amount, deadline, nonce)`
+        /// with the address registered via `register_eth_signer` (see
+        /// `verify_ecdsa_signature`). The nonce must be `owner`'s next
+        /// `NonceKind::Permit` nonce.
         #[ink(message)]
-        pub fn total_supply(&self) -> u128 {
-            self.total_supply
+        pub fn permit_ecdsa(
+            &mut self,
+            owner: AccountId,
+            spender: AccountId,
+            amount: Balance,
+            deadline: u64,
+            nonce: u64,
+            signature: [u8; 65],
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("permit_ecdsa"))?;
+
+            if self.env().block_timestamp() >= deadline {
+                return Err(Error::AuthorizationExpired);
+            }
+
+            let payload = (ECDSA_PERMIT_TYPE, owner, spender, amount, deadline, nonce);
+            self.verify_ecdsa_signature(owner, &payload, &signature)?;
+
+            // Mark the nonce used so the permit cannot be replayed
+            self.use_nonce(owner, NonceKind::Permit, nonce)?;
+            self.set_allowance(owner, spender, amount, AllowanceChangeCause::Permit);
+
+            self.env().emit_event(AuthorizationUsed {
+                authorizer: owner,
+                nonce,
+            });
+
+            Ok(())
         }
 
-        /// Get the owner of the contract
+        /// Register (or clear) the Ethereum address allowed to sign for the caller
         #[ink(message)]
-        pub fn get_owner(&self) -> AccountId {
-            self.owner
+        pub fn register_eth_signer(&mut self, eth_address: Option<[u8; 20]>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("register_eth_signer"))?;
+
+            let caller = self.env().caller();
+            if let Some(address) = eth_address {
+                self.eth_signers.insert(caller, &address);
+            } else {
+                self.eth_signers.remove(caller);
+            }
+
+            self.env().emit_event(EthSignerSet {
+                account: caller,
+                eth_address,
+            });
+
+            Ok(())
+        }
+
+        /// Get the Ethereum address registered for an account
+        #[ink(message)]
+        pub fn eth_signer_of(&self, account: AccountId) -> Option<[u8; 20]> {
+            self.eth_signers.get(account)
+        }
+
+        /// Get the hash every signed payload is bound to
+        #[ink(message)]
+        pub fn domain_separator(&self) -> Hash {
+            Hash::from(self.compute_domain_separator())
+        }
+
+        /// Get the values the domain separator is built from:
+        /// (name, version, chain genesis hash, contract address)
+        #[ink(message)]
+        pub fn chain_context(&self) -> (Vec<u8>, Vec<u8>, Option<Hash>, AccountId) {
+            (
+                DOMAIN_NAME.to_vec(),
+                DOMAIN_VERSION.to_vec(),
+                self.chain_genesis,
+                self.env().account_id(),
+            )
+        }
+
+        /// Record the chain genesis hash in the signing domain - only owner, only once
+        ///
+        /// Set once so previously issued signatures are not silently invalidated.
+        #[ink(message)]
+        pub fn set_chain_genesis(&mut self, genesis_hash: Hash) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_chain_genesis"))?;
+            self.ensure_privileged()?;
+
+            if self.chain_genesis.is_some() {
+                return Err(Error::ChainContextAlreadySet);
+            }
+
+            self.chain_genesis = Some(genesis_hash);
+            self.env().emit_event(ChainContextSet {
+                genesis_hash,
+                domain_separator: self.domain_separator(),
+            });
+
+            Ok(())
+        }
+
+        /// Get the next nonce an account must use for a kind of signed message
+        ///
+        /// Cheque nonces may be used in any order, so for `NonceKind::Cheque` this is
+        /// the number of cheques cashed or cancelled; check single cheques with
+        /// `is_cheque_used`.
+        #[ink(message)]
+        pub fn nonce_of(&self, account: AccountId, kind: NonceKind) -> u64 {
+            self.nonces.get((account, kind)).unwrap_or(0)
+        }
+
+        /// Burn (destroy) tokens from the caller's balance
+        #[ink(message)]
+        pub fn burn(&mut self, amount: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("burn"))?;
+
+            let caller = self.env().caller();
+            self._burn(caller, amount, Vec::new())?;
+            self.flush_balance_notifications();
+            Ok(())
+        }
+
+        /// Burn tokens from the caller's balance, recording why in the `Burned` event
+        #[ink(message)]
+        pub fn burn_with_reason(&mut self, amount: Balance, reason: Vec<u8>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("burn_with_reason"))?;
+
+            let caller = self.env().caller();
+            self._burn(caller, amount, reason)?;
+            self.flush_balance_notifications();
+            Ok(())
+        }
+
+        /// Burn tokens from another account using the caller's allowance
+        #[ink(message)]
+        pub fn burn_from(&mut self, from: AccountId, amount: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("burn_from"))?;
+
+            let caller = self.env().caller();
+            self.spend_allowance(from, caller, amount)?;
+            self._burn(from, amount, Vec::new())?;
+            self.flush_balance_notifications();
+            Ok(())
+        }
+
+        /// Burn tokens from another account using the caller's allowance, with a reason
+        #[ink(message)]
+        pub fn burn_from_with_reason(&mut self, from: AccountId, amount: Balance, reason: Vec<u8>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("burn_from_with_reason"))?;
+
+            let caller = self.env().caller();
+            self.spend_allowance(from, caller, amount)?;
+            self._burn(from, amount, reason)?;
+            self.flush_balance_notifications();
+            Ok(())
+        }
+
+        /// Burn from several accounts using the caller's allowances - only BURNER
+        ///
+        /// Each entry is burned like `burn_from`; if any fails, none are burned.
+        #[ink(message)]
+        pub fn burn_batch(&mut self, burns: Vec<(AccountId, Balance)>) -> Result<Balance> {
+            self.guard_selector(ink::selector_bytes!("burn_batch"))?;
+            self.ensure_role(BURNER)?;
+
+            // Validate: Keep batches bounded
+            if burns.is_empty() || burns.len() > MAX_BURN_BATCH {
+                return Err(Error::InvalidAmount);
+            }
+
+            let caller = self.env().caller();
+            let accounts = burns.len() as u32;
+            let mut total: Balance = 0;
+            for (from, amount) in burns {
+                self.spend_allowance(from, caller, amount)?;
+                self._burn(from, amount, Vec::new())?;
+                total = total.checked_add(amount).ok_or(Error::Overflow)?;
+            }
+
+            self.env().emit_event(BatchBurned {
+                burner: caller,
+                accounts,
+                total,
+            });
+            self.flush_balance_notifications();
+
+            Ok(total)
+        }
+
+        /// Get when an account last sent or received tokens (None = never)
+        #[ink(message)]
+        pub fn last_activity(&self, account: AccountId) -> Option<u64> {
+            self.last_activity.get(account)
+        }
+
+        /// Get the total amount of tokens ever minted
+        #[ink(message)]
+        pub fn total_minted(&self) -> Balance {
+            self.total_minted
+        }
+
+        /// Get the total amount of tokens ever burned
+        #[ink(message)]
+        pub fn total_burned(&self) -> Balance {
+            self.total_burned
+        }
+
+        /// Get the owner of the contract
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner
+        }
+
+        /// Get the token name
+        #[ink(message)]
+        pub fn token_name(&self) -> Option<String> {
+            self.name.clone()
+        }
+
+        /// Get the token symbol
+        #[ink(message)]
+        pub fn token_symbol(&self) -> Option<String> {
+            self.symbol.clone()
+        }
+
+        /// Get the number of decimals used for display
+        #[ink(message)]
+        pub fn token_decimals(&self) -> u8 {
+            self.decimals
+        }
+
+        /// Set or clear the metadata URI (JSON with logo and description) - only owner can do this
+        #[ink(message)]
+        pub fn set_token_uri(&mut self, uri: Option<String>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_token_uri"))?;
+            self.ensure_privileged()?;
+
+            // Validate: Keep the stored link bounded
+            if uri.as_ref().is_some_and(|uri| uri.len() > MAX_TOKEN_URI_LEN) {
+                return Err(Error::UriTooLong);
+            }
+
+            self.token_uri = uri.clone();
+            self.env().emit_event(TokenUriUpdated {
+                uri: uri.clone(),
+                by: self.env().caller(),
+            });
+            self.record_admin_action(AdminLogAction::SetTokenUri(uri));
+
+            Ok(())
+        }
+
+        /// Get the metadata URI
+        #[ink(message)]
+        pub fn token_uri(&self) -> Option<String> {
+            self.token_uri.clone()
+        }
+    }
+
+    // ========== PAUSE & BLACKLIST ==========
+    impl SimpleToken {
+        /// Set or remove the guardian account - only owner can do this
+        #[ink(message)]
+        pub fn set_guardian(&mut self, guardian: Option<AccountId>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_guardian"))?;
+            self.ensure_privileged()?;
+
+            // Validate: Guardian must be a separate key from the owner
+            if guardian == Some(self.owner) {
+                return Err(Error::InvalidGuardian);
+            }
+
+            self.guardian = guardian;
+            self.env().emit_event(GuardianChanged {
+                guardian,
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Get the guardian account, if any
+        #[ink(message)]
+        pub fn get_guardian(&self) -> Option<AccountId> {
+            self.guardian
+        }
+
+        /// Trigger the emergency stop - owner or guardian can do this
+        ///
+        /// Blocks transfers, mints and all privileged and role-gated operations
+        /// until lifted, which the owner can only do after `EMERGENCY_STOP_DELAY`
+        /// has passed. The guardian can lift its own stop early.
+        #[ink(message)]
+        pub fn emergency_stop(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("emergency_stop"))?;
+
+            let caller = self.env().caller();
+            let by_guardian = caller != self.owner && self.guardian == Some(caller);
+            if !by_guardian {
+                self.ensure_owner()?;
+            }
+
+            self.ensure_no_emergency()?;
+
+            let timestamp = self.env().block_timestamp();
+            self.emergency_stopped_at = Some(timestamp);
+            self.emergency_stopped_by_guardian = by_guardian;
+
+            self.env().emit_event(EmergencyStopped {
+                by: caller,
+                timestamp,
+                lift_after: timestamp.saturating_add(EMERGENCY_STOP_DELAY),
+            });
+
+            Ok(())
+        }
+
+        /// Lift the emergency stop - owner once the mandatory delay has passed
+        ///
+        /// The guardian can lift a stop it triggered straight away; the owner
+        /// always waits the full delay, since a compromised owner key is what the
+        /// guardian's stop protects against.
+        #[ink(message)]
+        pub fn lift_emergency_stop(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("lift_emergency_stop"))?;
+
+            let caller = self.env().caller();
+            let by_guardian = caller != self.owner && self.guardian == Some(caller);
+            if by_guardian && !self.emergency_stopped_by_guardian {
+                return Err(Error::Unauthorized);
+            }
+            if !by_guardian {
+                self.ensure_owner()?;
+            }
+
+            let stopped_at = self
+                .emergency_stopped_at
+                .ok_or(Error::EmergencyStopNotActive)?;
+            let timestamp = self.env().block_timestamp();
+
+            // Validate: Holders must get the full delay unless the guardian withdraws its own stop
+            let delay_elapsed = timestamp >= stopped_at.saturating_add(EMERGENCY_STOP_DELAY);
+            if !by_guardian && !delay_elapsed {
+                return Err(Error::EmergencyDelayNotElapsed);
+            }
+
+            self.emergency_stopped_at = None;
+            self.emergency_stopped_by_guardian = false;
+            self.env().emit_event(EmergencyStopLifted {
+                by: self.env().caller(),
+                timestamp,
+            });
+
+            Ok(())
+        }
+
+        /// Get when the emergency stop was triggered, if it is active
+        #[ink(message)]
+        pub fn emergency_stopped_at(&self) -> Option<u64> {
+            self.emergency_stopped_at
+        }
+
+        /// Pause or resume a single message by selector - only owner can do this
+        ///
+        /// Covers every state-changing message, so e.g. `mint` can be stopped while
+        /// `transfer` keeps working; the two selector guard setters themselves
+        /// cannot be paused.
+        /// `selector` is the dispatched one, so trait messages are keyed as
+        /// `Trait::method` (e.g. `PSP22::transfer`) separately from inherent ones.
+        #[ink(message)]
+        pub fn set_selector_paused(&mut self, selector: [u8; 4], paused: bool) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_selector_paused"))?;
+            self.ensure_privileged()?;
+            Self::ensure_unprotected_selector(selector)?;
+
+            if paused {
+                self.paused_selectors.insert(selector, &true);
+            } else {
+                self.paused_selectors.remove(selector);
+            }
+
+            self.env().emit_event(SelectorPauseChanged { selector, paused });
+
+            Ok(())
+        }
+
+        /// Restrict a message to members of `role`, or lift the restriction with `None` - only owner
+        ///
+        /// The restriction is enforced on top of the message's own checks.
+        #[ink(message)]
+        pub fn set_selector_access(&mut self, selector: [u8; 4], role: Option<RoleId>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_selector_access"))?;
+            self.ensure_privileged()?;
+            Self::ensure_unprotected_selector(selector)?;
+
+            if let Some(role) = role {
+                self.selector_access.insert(selector, &role);
+            } else {
+                self.selector_access.remove(selector);
+            }
+
+            self.env().emit_event(SelectorAccessChanged { selector, role });
+
+            Ok(())
+        }
+
+        /// Get the role a message is restricted to, if any
+        #[ink(message)]
+        pub fn selector_access(&self, selector: [u8; 4]) -> Option<RoleId> {
+            self.selector_access.get(selector)
+        }
+
+        /// Check whether a single message is paused
+        #[ink(message)]
+        pub fn is_selector_paused(&self, selector: [u8; 4]) -> bool {
+            self.paused_selectors.get(selector).unwrap_or(false)
+        }
+
+        /// Block an account from sending or receiving tokens - only owner can do this
+        ///
+        /// With a grace period set, the account is notified with `BlacklistScheduled`
+        /// and can still move funds until it runs out; `escalate_blacklist` makes it
+        /// take effect at once.
+        #[cfg(feature = "blacklist")]
+        #[ink(message)]
+        pub fn add_to_blacklist(&mut self, account: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("add_to_blacklist"))?;
+            self.ensure_privileged()?;
+
+            if self.blacklist_grace_period == 0 {
+                self.set_blacklisted(account, true);
+                return Ok(());
+            }
+
+            let effective_at = self
+                .env()
+                .block_timestamp()
+                .saturating_add(self.blacklist_grace_period);
+            self.pending_blacklist.insert(account, &effective_at);
+
+            self.env().emit_event(BlacklistScheduled {
+                account,
+                effective_at,
+            });
+            self.record_admin_action(AdminLogAction::ScheduleBlacklist { account, effective_at });
+
+            Ok(())
+        }
+
+        /// Move a scheduled blacklisting whose grace period has run out onto the
+        /// blacklist - anyone can call
+        ///
+        /// Transfers touching the account promote it too, but those transfers fail
+        /// and are reverted, so keepers should call this to index the account.
+        #[cfg(feature = "blacklist")]
+        #[ink(message)]
+        pub fn activate_blacklist(&mut self, account: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("activate_blacklist"))?;
+
+            let effective_at = self.pending_blacklist.get(account).ok_or(Error::NoPendingBlacklist)?;
+            if self.env().block_timestamp() < effective_at {
+                return Err(Error::BlacklistGracePeriodActive);
+            }
+
+            self.promote_pending_blacklist(account);
+            Ok(())
+        }
+
+        /// Blacklist an account immediately, skipping or cutting short its grace period - only owner
+        #[cfg(feature = "blacklist")]
+        #[ink(message)]
+        pub fn escalate_blacklist(&mut self, account: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("escalate_blacklist"))?;
+            self.ensure_privileged()?;
+            self.pending_blacklist.remove(account);
+            self.set_blacklisted(account, true);
+            Ok(())
+        }
+
+        /// Set the delay before new blacklistings take effect (0 = immediately) - only owner
+        #[cfg(feature = "blacklist")]
+        #[ink(message)]
+        pub fn set_blacklist_grace_period(&mut self, period: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_blacklist_grace_period"))?;
+            self.ensure_privileged()?;
+
+            self.blacklist_grace_period = period;
+            self.env().emit_event(BlacklistGracePeriodSet { period });
+
+            Ok(())
+        }
+
+        /// Get the delay (ms) before new blacklistings take effect
+        #[ink(message)]
+        pub fn blacklist_grace_period(&self) -> u64 {
+            self.blacklist_grace_period
+        }
+
+        /// Get when an account's scheduled blacklisting takes effect, if one is pending
+        #[ink(message)]
+        pub fn pending_blacklist_of(&self, account: AccountId) -> Option<u64> {
+            self.pending_blacklist.get(account)
+        }
+
+        /// Unblock a previously blacklisted account - only owner can do this
+        ///
+        /// Also cancels a blacklisting still in its grace period.
+        #[cfg(feature = "blacklist")]
+        #[ink(message)]
+        pub fn remove_from_blacklist(&mut self, account: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("remove_from_blacklist"))?;
+            self.ensure_privileged()?;
+            self.pending_blacklist.remove(account);
+            self.set_blacklisted(account, false);
+            Ok(())
+        }
+
+        /// Check whether an account is blacklisted
+        #[ink(message)]
+        pub fn is_blacklisted(&self, account: AccountId) -> bool {
+            self.check_blacklisted(account)
+        }
+
+        /// Get up to `limit` blacklisted accounts starting at `offset`
+        #[ink(message)]
+        pub fn blacklisted_accounts(&self, offset: u32, limit: u32) -> Vec<AccountId> {
+            let end = offset.saturating_add(limit).min(self.blacklist_count);
+            (offset..end)
+                .filter_map(|index| self.blacklist_members.get(index))
+                .collect()
+        }
+
+        /// Get the number of blacklisted accounts
+        #[ink(message)]
+        pub fn blacklist_count(&self) -> u32 {
+            self.blacklist_count
+        }
+
+        /// Recover other PSP22 tokens accidentally sent to this contract - only owner
+        #[ink(message)]
+        pub fn rescue_token(&mut self, token_address: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("rescue_token"))?;
+            self.ensure_privileged()?;
+
+            // Validate: Our own balances are not held by the contract account
+            if token_address == self.env().account_id() {
+                return Err(Error::Unauthorized);
+            }
+
+            // Validate: Cannot rescue zero tokens
+            if amount == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            self.call_psp22_transfer(token_address, to, amount)?;
+
+            self.env().emit_event(TokenRescued {
+                token: token_address,
+                to,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Recover native currency held by this contract - only owner
+        #[ink(message)]
+        pub fn withdraw_native(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("withdraw_native"))?;
+            self.ensure_privileged()?;
+
+            // Validate: Cannot withdraw zero
+            if amount == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            // Validate: Contract must hold enough native currency beyond unclaimed payouts
+            self.ensure_native_available(amount)?;
+
+            self.env()
+                .transfer(to, amount)
+                .map_err(|_| Error::NativeTransferFailed)?;
+
+            self.env().emit_event(NativeWithdrawn { to, amount });
+
+            Ok(())
+        }
+    }
+
+    // ========== ROLES ==========
+    impl SimpleToken {
+        /// Let members of `admin_role` grant and revoke `role` - only owner can do this
+        ///
+        /// Pass `None` to make the role owner-administered again.
+        #[ink(message)]
+        pub fn set_role_admin(&mut self, role: RoleId, admin_role: Option<RoleId>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_role_admin"))?;
+            self.ensure_privileged()?;
+
+            let previous_admin_role = self.role_admins.get(role);
+            if let Some(admin_role) = admin_role {
+                self.role_admins.insert(role, &admin_role);
+            } else {
+                self.role_admins.remove(role);
+            }
+
+            self.env().emit_event(RoleAdminChanged {
+                role,
+                previous_admin_role,
+                new_admin_role: admin_role,
+            });
+
+            Ok(())
+        }
+
+        /// Get every account holding a role
+        #[ink(message)]
+        pub fn role_members(&self, role: RoleId) -> Vec<AccountId> {
+            let count = self.role_member_count.get(role).unwrap_or(0);
+            (0..count)
+                .filter_map(|index| self.role_members.get((role, index)))
+                .collect()
+        }
+
+        /// Get the number of accounts holding a role
+        #[ink(message)]
+        pub fn role_member_count(&self, role: RoleId) -> u32 {
+            self.role_member_count.get(role).unwrap_or(0)
+        }
+    }
+
+    // ========== MINT REQUESTS ==========
+    impl SimpleToken {
+        /// Propose minting tokens; an APPROVER must confirm - only MINTER
+        ///
+        /// The amount must fit in the caller's minter allowance, which is only
+        /// charged once the request is approved.
+        #[ink(message)]
+        pub fn request_mint(&mut self, to: AccountId, amount: Balance) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("request_mint"))?;
+            self.ensure_role(MINTER)?;
+
+            // Validate: Cannot mint zero tokens
+            if amount == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            let caller = self.env().caller();
+            if self.minter_allowances.get(caller).unwrap_or(0) < amount {
+                return Err(Error::MinterAllowanceExceeded);
+            }
+            let id = self.next_mint_request_id;
+            self.next_mint_request_id = id.checked_add(1).ok_or(Error::Overflow)?;
+
+            let expires_at = self
+                .env()
+                .block_timestamp()
+                .saturating_add(self.mint_request_ttl);
+            self.mint_requests.insert(
+                id,
+                &MintRequest {
+                    to,
+                    amount,
+                    requested_by: caller,
+                    expires_at,
+                },
+            );
+
+            self.env().emit_event(MintRequested {
+                id,
+                to,
+                amount,
+                by: caller,
+                expires_at,
+            });
+
+            Ok(id)
+        }
+
+        /// Confirm a pending mint request and create the tokens - only APPROVER
+        #[ink(message)]
+        pub fn approve_mint(&mut self, request_id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("approve_mint"))?;
+            self.ensure_role(APPROVER)?;
+            self.ensure_no_emergency()?;
+
+            let caller = self.env().caller();
+            let request = self
+                .mint_requests
+                .get(request_id)
+                .ok_or(Error::MintRequestNotFound)?;
+
+            // Validate: Proposing and approving must be done by different keys
+            if request.requested_by == caller {
+                return Err(Error::Unauthorized);
+            }
+
+            // Validate: A minter whose role was revoked since the request can't get it through
+            if !self.check_role(MINTER, request.requested_by) {
+                return Err(Error::Unauthorized);
+            }
+
+            if self.env().block_timestamp() > request.expires_at {
+                return Err(Error::MintRequestExpired);
+            }
+
+            self.mint_requests.remove(request_id);
+            self.consume_mint_quota(request.requested_by, request.amount)?;
+            let allowance = self
+                .minter_allowances
+                .get(request.requested_by)
+                .unwrap_or(0)
+                .checked_sub(request.amount)
+                .ok_or(Error::MinterAllowanceExceeded)?;
+            self.minter_allowances.insert(request.requested_by, &allowance);
+            self._mint(request.to, request.amount)?;
+            self.record_admin_action(AdminLogAction::Mint {
+                to: request.to,
+                amount: request.amount,
+            });
+
+            self.env().emit_event(MintRequestApproved {
+                id: request_id,
+                by: caller,
+            });
+            self.flush_balance_notifications();
+
+            Ok(())
+        }
+
+        /// Drop a mint request - the requesting minter or any APPROVER can do this
+        #[ink(message)]
+        pub fn cancel_mint_request(&mut self, request_id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("cancel_mint_request"))?;
+
+            let caller = self.env().caller();
+            let request = self
+                .mint_requests
+                .get(request_id)
+                .ok_or(Error::MintRequestNotFound)?;
+
+            if request.requested_by != caller && !self.check_role(APPROVER, caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            self.mint_requests.remove(request_id);
+            self.env().emit_event(MintRequestCancelled {
+                id: request_id,
+                by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Set how long new mint requests stay approvable - only owner can do this
+        #[ink(message)]
+        pub fn set_mint_request_ttl(&mut self, ttl: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_mint_request_ttl"))?;
+            self.ensure_privileged()?;
+            self.mint_request_ttl = ttl;
+            Ok(())
+        }
+
+        /// Get a pending mint request by id
+        #[ink(message)]
+        pub fn get_mint_request(&self, request_id: u32) -> Option<MintRequest> {
+            self.mint_requests.get(request_id)
+        }
+
+        /// Set a minter's mint budget - only owner can do this
+        ///
+        /// Each approved request lowers the budget; once used up it has to be set
+        /// again before the minter's requests can be approved.
+        #[ink(message)]
+        pub fn set_minter_allowance(&mut self, minter: AccountId, amount: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_minter_allowance"))?;
+            self.ensure_privileged()?;
+
+            self.minter_allowances.insert(minter, &amount);
+            self.env().emit_event(MinterAllowanceSet {
+                minter,
+                allowance: amount,
+            });
+
+            Ok(())
+        }
+
+        /// Get a minter's remaining mint budget
+        #[ink(message)]
+        pub fn minter_allowance(&self, minter: AccountId) -> Balance {
+            self.minter_allowances.get(minter).unwrap_or(0)
+        }
+
+        /// Cap how much a minter's requests can mint per day - only owner can do this
+        ///
+        /// Pass `None` to remove the quota.
+        #[ink(message)]
+        pub fn set_minter_daily_quota(&mut self, minter: AccountId, quota: Option<Balance>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_minter_daily_quota"))?;
+            self.ensure_privileged()?;
+
+            if let Some(quota) = quota {
+                self.minter_daily_quota.insert(minter, &quota);
+            } else {
+                self.minter_daily_quota.remove(minter);
+            }
+
+            self.env().emit_event(MinterQuotaSet { minter, quota });
+
+            Ok(())
+        }
+
+        /// Get a minter's daily quota and how much of it is left in the current window
+        #[ink(message)]
+        pub fn minter_daily_quota(&self, minter: AccountId) -> (Option<Balance>, Balance) {
+            let Some(quota) = self.minter_daily_quota.get(minter) else {
+                return (None, Balance::MAX);
+            };
+            (Some(quota), quota.saturating_sub(self.minted_in_current_window(minter)))
+        }
+
+        /// Internal helper: amount a minter's requests minted in its current quota window
+        fn minted_in_current_window(&self, minter: AccountId) -> Balance {
+            match self.minted_in_window.get(minter) {
+                Some((start, minted)) if self.env().block_timestamp().saturating_sub(start) < MINT_QUOTA_WINDOW => minted,
+                _ => 0,
+            }
+        }
+
+        /// Internal helper that counts a mint against the requesting minter's daily quota
+        ///
+        /// The window starts with the first mint after the previous one ran out.
+        fn consume_mint_quota(&mut self, minter: AccountId, amount: Balance) -> Result<()> {
+            let Some(quota) = self.minter_daily_quota.get(minter) else {
+                return Ok(());
+            };
+
+            let now = self.env().block_timestamp();
+            let (start, minted) = match self.minted_in_window.get(minter) {
+                Some((start, minted)) if now.saturating_sub(start) < MINT_QUOTA_WINDOW => (start, minted),
+                _ => (now, 0),
+            };
+            let minted = minted.checked_add(amount).ok_or(Error::Overflow)?;
+            if minted > quota {
+                return Err(Error::MintQuotaExceeded);
+            }
+
+            self.minted_in_window.insert(minter, &(start, minted));
+            Ok(())
+        }
+    }
+
+    // ========== BURN REQUESTS ==========
+    impl SimpleToken {
+        /// Escrow tokens for a redemption burn that finalizes after the delay
+        ///
+        /// The tokens leave the caller's balance immediately but stay part of
+        /// the total supply until `finalize_burn`. COMPLIANCE can cancel in the
+        /// meantime, returning the tokens.
+        #[ink(message)]
+        pub fn request_burn(&mut self, amount: Balance) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("request_burn"))?;
+
+            let caller = self.env().caller();
+            self.debit_unlocked(caller, amount)?;
+
+            self.burn_escrow_total = self
+                .burn_escrow_total
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+
+            let id = self.next_burn_request_id;
+            self.next_burn_request_id = id.checked_add(1).ok_or(Error::Overflow)?;
+
+            let ready_at = self
+                .env()
+                .block_timestamp()
+                .saturating_add(self.burn_request_delay);
+            self.burn_requests.insert(
+                id,
+                &BurnRequest {
+                    from: caller,
+                    amount,
+                    ready_at,
+                },
+            );
+
+            self.env().emit_event(BurnRequested {
+                id,
+                from: caller,
+                amount,
+                ready_at,
+            });
+
+            Ok(id)
+        }
+
+        /// Burn escrowed tokens once the delay has passed - anyone can call
+        #[ink(message)]
+        pub fn finalize_burn(&mut self, request_id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("finalize_burn"))?;
+
+            let request = self
+                .burn_requests
+                .get(request_id)
+                .ok_or(Error::BurnRequestNotFound)?;
+
+            if self.env().block_timestamp() < request.ready_at {
+                return Err(Error::BurnRequestNotReady);
+            }
+
+            self.burn_requests.remove(request_id);
+            self.burn_escrow_total = self
+                .burn_escrow_total
+                .checked_sub(request.amount)
+                .ok_or(Error::Overflow)?;
+            self.total_supply = self
+                .total_supply
+                .checked_sub(request.amount)
+                .ok_or(Error::Overflow)?;
+            self.total_burned = self.total_burned.saturating_add(request.amount);
+
+            self.env().emit_event(Burned {
+                from: request.from,
+                amount: request.amount,
+                timestamp: self.env().block_timestamp(),
+                reason: b"redemption".to_vec(),
+                total_supply: self.total_supply,
+                ref_id: self.active_ref_id,
+            });
+            self.env().emit_event(BurnRequestFinalized { id: request_id });
+
+            Ok(())
+        }
+
+        /// Cancel a pending burn and return the tokens - only COMPLIANCE
+        #[ink(message)]
+        pub fn cancel_burn_request(&mut self, request_id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("cancel_burn_request"))?;
+            self.ensure_role(COMPLIANCE)?;
+
+            let request = self
+                .burn_requests
+                .get(request_id)
+                .ok_or(Error::BurnRequestNotFound)?;
+
+            self.burn_requests.remove(request_id);
+            self.burn_escrow_total = self
+                .burn_escrow_total
+                .checked_sub(request.amount)
+                .ok_or(Error::Overflow)?;
+
+            self.credit(request.from, request.amount)?;
+
+            self.env().emit_event(BurnRequestCancelled {
+                id: request_id,
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Set the delay applied to new burn requests - only owner can do this
+        #[ink(message)]
+        pub fn set_burn_request_delay(&mut self, delay: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_burn_request_delay"))?;
+            self.ensure_privileged()?;
+            self.burn_request_delay = delay;
+            Ok(())
+        }
+
+        /// Get a pending burn request by id
+        #[ink(message)]
+        pub fn get_burn_request(&self, request_id: u32) -> Option<BurnRequest> {
+            self.burn_requests.get(request_id)
+        }
+
+        /// Get the total amount currently held in burn escrow
+        #[ink(message)]
+        pub fn burn_escrow_total(&self) -> Balance {
+            self.burn_escrow_total
+        }
+    }
+
+    // ========== FEES ==========
+    impl SimpleToken {
+        /// Configure the transfer fee - only owner can do this
+        ///
+        /// `fee_bps` of every transfer is withheld and split by the routing table,
+        /// whose shares are in basis points of the fee and must add up to
+        /// `BPS_DENOMINATOR`, e.g. 50% treasury, 30% burn, 20% a charity account.
+        #[cfg(feature = "fees")]
+        #[ink(message)]
+        pub fn configure_fees(&mut self, fee_bps: u16, routes: Vec<(FeeDestination, u16)>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("configure_fees"))?;
+            self.ensure_privileged()?;
+            self.set_fees(fee_bps, routes)
+        }
+
+        /// Get the fee configuration: (fee_bps, routing table)
+        #[ink(message)]
+        pub fn fee_config(&self) -> (u16, Vec<(FeeDestination, u16)>) {
+            (self.fee_bps, self.fee_routes.clone())
+        }
+
+        /// Set the fee discount schedule - only owner can do this
+        ///
+        /// Each tier is (minimum vote-escrow weight, discount in bps of the fee).
+        /// Thresholds must be strictly ascending; a sender gets the discount of the
+        /// highest tier their `ve_balance_of` reaches at transfer time.
+        #[cfg(feature = "fees")]
+        #[ink(message)]
+        pub fn set_fee_discount_tiers(&mut self, tiers: Vec<(Balance, u16)>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_fee_discount_tiers"))?;
+            self.ensure_privileged()?;
+
+            if tiers.len() > MAX_FEE_DISCOUNT_TIERS {
+                return Err(Error::InvalidFeeConfig);
+            }
+            for (position, (threshold, discount_bps)) in tiers.iter().enumerate() {
+                if Balance::from(*discount_bps) > BPS_DENOMINATOR {
+                    return Err(Error::InvalidFeeConfig);
+                }
+                if position > 0 && *threshold <= tiers[position - 1].0 {
+                    return Err(Error::InvalidFeeConfig);
+                }
+            }
+
+            self.fee_discount_tiers = tiers.clone();
+
+            self.env().emit_event(FeeDiscountTiersSet { tiers });
+
+            Ok(())
+        }
+
+        /// Get the fee discount schedule
+        #[ink(message)]
+        pub fn fee_discount_tiers(&self) -> Vec<(Balance, u16)> {
+            self.fee_discount_tiers.clone()
+        }
+
+        /// Get the fee discount (bps of the fee) an account currently qualifies for
+        #[ink(message)]
+        pub fn fee_discount_bps(&self, account: AccountId) -> u16 {
+            if self.fee_discount_tiers.is_empty() {
+                return 0;
+            }
+            let weight = self.ve_balance_of(account);
+            self.fee_discount_tiers
+                .iter()
+                .rev()
+                .find(|(threshold, _)| weight >= *threshold)
+                .map_or(0, |(_, discount_bps)| *discount_bps)
+        }
+
+        /// Get the reflection an account has accrued but not yet received
+        ///
+        /// It is credited automatically the next time the account's balance changes.
+        #[ink(message)]
+        pub fn pending_reflection(&self, account: AccountId) -> Balance {
+            self.reflection_owed(account)
+        }
+    }
+
+    // ========== TREASURY ==========
+    impl SimpleToken {
+        /// Propose paying out of the treasury - only owner can do this
+        ///
+        /// The treasury is this contract's own token balance; fund it by minting
+        /// to, or routing fees into, the contract address. The spend can be
+        /// executed after `TREASURY_TIMELOCK`. With a multisig active, use a
+        /// `TreasurySpend` proposal instead.
+        #[ink(message)]
+        pub fn propose_treasury_spend(&mut self, to: AccountId, amount: Balance) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("propose_treasury_spend"))?;
+            self.ensure_privileged()?;
+
+            // Validate: Cannot spend zero tokens
+            if amount == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            let id = self.next_treasury_spend_id;
+            self.next_treasury_spend_id = id.checked_add(1).ok_or(Error::Overflow)?;
+
+            let executable_at = self
+                .env()
+                .block_timestamp()
+                .saturating_add(TREASURY_TIMELOCK);
+            self.treasury_spends.insert(
+                id,
+                &TreasurySpend {
+                    to,
+                    amount,
+                    executable_at,
+                },
+            );
+
+            self.env().emit_event(TreasurySpendProposed {
+                id,
+                to,
+                amount,
+                executable_at,
+            });
+
+            Ok(id)
+        }
+
+        /// Execute a treasury spend once its timelock has passed - anyone can call
+        #[ink(message)]
+        pub fn execute_treasury_spend(&mut self, spend_id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("execute_treasury_spend"))?;
+            self.ensure_no_emergency()?;
+
+            let spend = self
+                .treasury_spends
+                .get(spend_id)
+                .ok_or(Error::TreasurySpendNotFound)?;
+
+            if self.env().block_timestamp() < spend.executable_at {
+                return Err(Error::TreasurySpendNotReady);
+            }
+
+            self.treasury_spends.remove(spend_id);
+            self.pay_from_treasury(Some(spend_id), spend.to, spend.amount)?;
+            self.flush_balance_notifications();
+            Ok(())
+        }
+
+        /// Cancel a pending treasury spend - only owner can do this
+        #[ink(message)]
+        pub fn cancel_treasury_spend(&mut self, spend_id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("cancel_treasury_spend"))?;
+            self.ensure_privileged()?;
+
+            if self.treasury_spends.take(spend_id).is_none() {
+                return Err(Error::TreasurySpendNotFound);
+            }
+
+            self.env().emit_event(TreasurySpendCancelled { id: spend_id });
+
+            Ok(())
+        }
+
+        /// Get a pending treasury spend by id
+        #[ink(message)]
+        pub fn get_treasury_spend(&self, spend_id: u32) -> Option<TreasurySpend> {
+            self.treasury_spends.get(spend_id)
+        }
+
+        /// Get the treasury balance (tokens held by the contract itself)
+        #[ink(message)]
+        pub fn treasury_balance(&self) -> Balance {
+            self.balance_of(self.env().account_id())
+        }
+
+        /// Cap treasury payouts at `bps` of the treasury per `period` ms - only owner can do this
+        ///
+        /// Applies when a spend executes, whether it went through the timelock or
+        /// the multisig. Pass `None` to remove the cap.
+        #[ink(message)]
+        pub fn set_treasury_spend_limit(&mut self, limit: Option<(u16, u64)>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_treasury_spend_limit"))?;
+            self.ensure_privileged()?;
+
+            // Validate: A share of at most 100% over a non-empty period
+            if let Some((bps, period)) = limit {
+                if Balance::from(bps) > BPS_DENOMINATOR || period == 0 {
+                    return Err(Error::InvalidAmount);
+                }
+            }
+
+            self.treasury_spend_limit = limit;
+            self.treasury_period_start = self.env().block_timestamp();
+            self.treasury_period_base = self.balance_of(self.env().account_id());
+            self.treasury_spent_in_period = 0;
+
+            self.env().emit_event(TreasurySpendLimitSet { limit });
+
+            Ok(())
+        }
+
+        /// Get the treasury spending cap as (bps, period in ms)
+        #[ink(message)]
+        pub fn treasury_spend_limit(&self) -> Option<(u16, u64)> {
+            self.treasury_spend_limit
+        }
+
+        /// Get how much can still be paid out of the treasury in the current period (None = uncapped)
+        #[ink(message)]
+        pub fn treasury_spend_headroom(&self) -> Option<Balance> {
+            let (bps, period) = self.treasury_spend_limit?;
+            let (_, base, spent) = self.current_treasury_period(period);
+            let cap = Self::mul_div(base, Balance::from(bps), BPS_DENOMINATOR).unwrap_or(0);
+            Some(cap.saturating_sub(spent))
+        }
+    }
+
+    // ========== BUYBACK ==========
+    impl SimpleToken {
+        /// Set the DEX used for buybacks - only owner can do this
+        ///
+        /// `selector` must be a payable message on `router` taking
+        /// `(token: AccountId, min_out: Balance, to: AccountId)`, swapping the
+        /// transferred native value for `token` and returning the amount bought.
+        /// The router may not call back into this contract; instead it must hold
+        /// the bought tokens and approve this contract to pull them.
+        #[ink(message)]
+        pub fn set_buyback_router(&mut self, router: Option<(AccountId, [u8; 4])>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_buyback_router"))?;
+            self.ensure_privileged()?;
+
+            self.buyback_router = router;
+            self.env().emit_event(BuybackRouterSet { router });
+
+            Ok(())
+        }
+
+        /// Get the configured buyback router and swap selector
+        #[ink(message)]
+        pub fn buyback_router(&self) -> Option<(AccountId, [u8; 4])> {
+            self.buyback_router
+        }
+
+        /// Spend up to `max_spend` native currency held by the contract on buying
+        /// tokens through the DEX and burn everything bought - only owner
+        #[ink(message)]
+        pub fn buyback_and_burn(&mut self, max_spend: Balance, min_tokens_out: Balance) -> Result<Balance> {
+            self.guard_selector(ink::selector_bytes!("buyback_and_burn"))?;
+            self.ensure_privileged()?;
+
+            let (router, selector) = self.buyback_router.ok_or(Error::NoBuybackRouter)?;
+
+            // Validate: Contract must hold the native funds it spends, net of the
+            // dividend and ve-reward reserves
+            if max_spend == 0 {
+                return Err(Error::InvalidAmount);
+            }
+            let reserved = self.dividend_reserve.saturating_add(self.ve_rewards_outstanding.0);
+            if max_spend > self.env().balance().saturating_sub(reserved) {
+                return Err(Error::InsufficientBalance);
+            }
+
+            let this = self.env().account_id();
+            let native_before = self.env().balance();
+
+            // No re-entry: root fields are held in memory for the whole call
+            let result = build_call::<Environment>()
+                .call(router)
+                .transferred_value(max_spend)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(selector))
+                        .push_arg(this)
+                        .push_arg(min_tokens_out)
+                        .push_arg(this),
+                )
+                .returns::<Balance>()
+                .try_invoke();
+            let bought = match result {
+                Ok(Ok(bought)) => bought,
+                _ => return Err(Error::BuybackFailed),
+            };
+            if bought == 0 || bought < min_tokens_out {
+                return Err(Error::BuybackFailed);
+            }
+            let native_spent = native_before.saturating_sub(self.env().balance());
+
+            // Pull the bought tokens through the router's allowance; this fails
+            // unless the router really holds them
+            self.spend_allowance(router, this, bought)?;
+            self._burn(router, bought, b"buyback".to_vec())?;
+
+            self.env().emit_event(Buyback {
+                native_spent,
+                tokens_burned: bought,
+            });
+            self.flush_balance_notifications();
+
+            Ok(bought)
+        }
+    }
+
+    // ========== PRICE ORACLE ==========
+    impl SimpleToken {
+        /// Configure the price oracle - only owner can do this
+        ///
+        /// Oracle answers older than `max_age` ms are ignored in favour of
+        /// `fallback_price`. The oracle must be on the callback whitelist, or only
+        /// the fallback price is used.
+        #[ink(message)]
+        pub fn configure_oracle(
+            &mut self,
+            oracle: Option<AccountId>,
+            max_age: u64,
+            fallback_price: Option<Balance>,
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("configure_oracle"))?;
+            self.ensure_privileged()?;
+
+            self.price_oracle = oracle;
+            self.oracle_max_age = max_age;
+            self.fallback_price = fallback_price;
+
+            self.env().emit_event(OracleConfigured {
+                oracle,
+                max_age,
+                fallback_price,
+            });
+
+            Ok(())
+        }
+
+        /// Get the oracle configuration: (oracle, max_age, fallback_price)
+        #[ink(message)]
+        pub fn oracle_config(&self) -> (Option<AccountId>, u64, Option<Balance>) {
+            (self.price_oracle, self.oracle_max_age, self.fallback_price)
+        }
+
+        /// Get the token price from the oracle, or the fallback price if the
+        /// oracle is unset, not whitelisted, failing or stale
+        ///
+        /// This is the integration point for sale and collateral contracts, which
+        /// query it; this token has no sale or collateral logic of its own.
+        #[ink(message)]
+        pub fn token_price(&self) -> Result<Balance> {
+            self.oracle_price()
+                .or(self.fallback_price)
+                .ok_or(Error::PriceUnavailable)
+        }
+    }
+
+    // ========== SAVINGS VAULT ==========
+    impl SimpleToken {
+        /// Deposit tokens into the savings vault in exchange for shares
+        #[ink(message)]
+        pub fn deposit_to_vault(&mut self, amount: Balance) -> Result<Balance> {
+            self.guard_selector(ink::selector_bytes!("deposit_to_vault"))?;
+
+            let caller = self.env().caller();
+
+            // Assets left behind without shares (e.g. rounding dust) go to the
+            // treasury rather than the next depositor
+            if self.vault_total_shares == 0 && self.vault_assets > 0 {
+                let treasury = self.env().account_id();
+                let orphaned = core::mem::take(&mut self.vault_assets);
+                self.credit(treasury, orphaned)?;
+            }
+
+            // Price shares before the deposit changes the vault
+            let shares = if self.vault_total_shares == 0 || self.vault_assets == 0 {
+                amount
+            } else {
+                Self::mul_div(amount, self.vault_total_shares, self.vault_assets).ok_or(Error::Overflow)?
+            };
+            if shares == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            self.debit_unlocked(caller, amount)?;
+            self.vault_assets = self.vault_assets.checked_add(amount).ok_or(Error::Overflow)?;
+            self.vault_total_shares = self
+                .vault_total_shares
+                .checked_add(shares)
+                .ok_or(Error::Overflow)?;
+            let held = self.vault_shares.get(caller).unwrap_or(0);
+            self.vault_shares
+                .insert(caller, &held.checked_add(shares).ok_or(Error::Overflow)?);
+
+            self.env().emit_event(VaultDeposit {
+                account: caller,
+                amount,
+                shares,
+            });
+
+            Ok(shares)
+        }
+
+        /// Redeem vault shares for their current value in tokens
+        #[ink(message)]
+        pub fn withdraw_from_vault(&mut self, shares: Balance) -> Result<Balance> {
+            self.guard_selector(ink::selector_bytes!("withdraw_from_vault"))?;
+
+            let caller = self.env().caller();
+            let held = self.vault_shares.get(caller).unwrap_or(0);
+
+            // Validate: Cannot redeem more shares than owned
+            if shares == 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if held < shares {
+                return Err(Error::InsufficientShares);
+            }
+
+            let amount = Self::mul_div(shares, self.vault_assets, self.vault_total_shares)
+                .ok_or(Error::Overflow)?;
+
+            self.vault_shares.insert(caller, &(held - shares));
+            self.vault_total_shares -= shares;
+            self.vault_assets = self.vault_assets.saturating_sub(amount);
+            self.credit(caller, amount)?;
+
+            self.env().emit_event(VaultWithdraw {
+                account: caller,
+                amount,
+                shares,
+            });
+
+            Ok(amount)
+        }
+
+        /// Get the value of one vault share, scaled by `SHARE_PRICE_PRECISION`
+        #[ink(message)]
+        pub fn share_price(&self) -> Balance {
+            if self.vault_total_shares == 0 {
+                return SHARE_PRICE_PRECISION;
+            }
+            Self::mul_div(self.vault_assets, SHARE_PRICE_PRECISION, self.vault_total_shares).unwrap_or(Balance::MAX)
+        }
+
+        /// Get an account's vault shares
+        #[ink(message)]
+        pub fn vault_shares_of(&self, account: AccountId) -> Balance {
+            self.vault_shares.get(account).unwrap_or(0)
+        }
+
+        /// Get the tokens held by the vault and the total shares outstanding
+        #[ink(message)]
+        pub fn vault_totals(&self) -> (Balance, Balance) {
+            (self.vault_assets, self.vault_total_shares)
+        }
+    }
+
+    // ========== FEE LOTTERY ==========
+    impl SimpleToken {
+        /// Configure the fee lottery - only owner can do this
+        ///
+        /// The pot is funded by a `FeeDestination::Lottery` fee route and awarded to
+        /// one participant per `epoch_length` ms, picked with probability
+        /// proportional to balance.
+        #[ink(message)]
+        pub fn configure_lottery(&mut self, epoch_length: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("configure_lottery"))?;
+            self.ensure_privileged()?;
+
+            self.lottery_epoch_length = epoch_length;
+            self.lottery_last_draw = self.env().block_timestamp();
+
+            self.env().emit_event(LotteryConfigured { epoch_length });
+
+            Ok(())
+        }
+
+        /// Opt in to the fee lottery
+        #[ink(message)]
+        pub fn join_lottery(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("join_lottery"))?;
+
+            let caller = self.env().caller();
+            if self.lottery_epoch_length == 0 {
+                return Err(Error::LotteryDisabled);
+            }
+            if self.lottery_participant_index.contains(caller) {
+                return Err(Error::AlreadyParticipating);
+            }
+            if self.lottery_participant_count >= MAX_LOTTERY_PARTICIPANTS {
+                return Err(Error::TooManyParticipants);
+            }
+
+            let index = self.lottery_participant_count;
+            self.lottery_participants.insert(index, &caller);
+            self.lottery_participant_index.insert(caller, &index);
+            self.lottery_participant_count = index.saturating_add(1);
+
+            self.env().emit_event(LotteryParticipation {
+                account: caller,
+                participating: true,
+            });
+
+            Ok(())
+        }
+
+        /// Opt out of the fee lottery
+        #[ink(message)]
+        pub fn leave_lottery(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("leave_lottery"))?;
+
+            let caller = self.env().caller();
+            let index = self
+                .lottery_participant_index
+                .get(caller)
+                .ok_or(Error::NotParticipating)?;
+            let last_index = self.lottery_participant_count.saturating_sub(1);
+
+            // Move the last participant into the freed slot
+            if index != last_index {
+                if let Some(last) = self.lottery_participants.get(last_index) {
+                    self.lottery_participants.insert(index, &last);
+                    self.lottery_participant_index.insert(last, &index);
+                }
+            }
+            self.lottery_participants.remove(last_index);
+            self.lottery_participant_index.remove(caller);
+            self.lottery_participant_count = last_index;
+
+            self.env().emit_event(LotteryParticipation {
+                account: caller,
+                participating: false,
+            });
+
+            Ok(())
+        }
+
+        /// Award the pot for the finished epoch - anyone can call
+        ///
+        /// The winner is picked with the runtime's randomness, weighted by each
+        /// participant's current balance. If nobody holds tokens the pot rolls over.
+        #[ink(message)]
+        pub fn draw_lottery(&mut self) -> Result<Option<AccountId>> {
+            self.guard_selector(ink::selector_bytes!("draw_lottery"))?;
+            self.ensure_no_emergency()?;
+
+            if self.lottery_epoch_length == 0 {
+                return Err(Error::LotteryDisabled);
+            }
+            let now = self.env().block_timestamp();
+            if now < self.lottery_last_draw.saturating_add(self.lottery_epoch_length) {
+                return Err(Error::LotteryEpochNotOver);
+            }
+
+            let epoch = self.lottery_epoch;
+            let subject = self
+                .env()
+                .hash_encoded::<Blake2x256, _>(&(b"lottery", epoch, self.env().account_id()));
+            let seed = self
+                .env()
+                .extension()
+                .fetch_random(subject)
+                .map_err(|_| Error::RandomnessUnavailable)?;
+
+            // Weight participants by balance; blacklisted accounts cannot win
+            let weights: Vec<(AccountId, Balance)> = (0..self.lottery_participant_count)
+                .filter_map(|index| self.lottery_participants.get(index))
+                .filter(|account| !self.check_blacklisted(*account))
+                .map(|account| (account, self.balance_of(account)))
+                .filter(|(_, balance)| *balance > 0)
+                .collect();
+            let total_weight = weights
+                .iter()
+                .fold(0, |total: Balance, (_, balance)| total.saturating_add(*balance));
+
+            let mut winner = None;
+            let mut prize = 0;
+            if total_weight > 0 && self.lottery_pot > 0 {
+                let mut seed_bytes = [0u8; core::mem::size_of::<Balance>()];
+                seed_bytes.copy_from_slice(&seed[..seed_bytes.len()]);
+                let mut ticket = Balance::from_le_bytes(seed_bytes) % total_weight;
+                for (account, balance) in weights {
+                    if ticket < balance {
+                        winner = Some(account);
+                        break;
+                    }
+                    ticket -= balance;
+                }
+
+                if let Some(account) = winner {
+                    prize = self.lottery_pot;
+                    self.lottery_pot = 0;
+                    self.credit(account, prize)?;
+                }
+            }
+
+            self.lottery_epoch = epoch.checked_add(1).ok_or(Error::Overflow)?;
+            self.lottery_last_draw = now;
+
+            self.env().emit_event(LotteryDrawn {
+                epoch,
+                winner,
+                prize,
+                seed,
+            });
+
+            Ok(winner)
+        }
+
+        /// Get the lottery state: (pot, epoch, participant count, next draw time)
+        #[ink(message)]
+        pub fn lottery_info(&self) -> (Balance, u32, u32, u64) {
+            (
+                self.lottery_pot,
+                self.lottery_epoch,
+                self.lottery_participant_count,
+                self.lottery_last_draw.saturating_add(self.lottery_epoch_length),
+            )
+        }
+
+        /// Check whether an account takes part in the lottery
+        #[ink(message)]
+        pub fn is_lottery_participant(&self, account: AccountId) -> bool {
+            self.lottery_participant_index.contains(account)
+        }
+    }
+
+    // ========== LAUNCH PROTECTION ==========
+    impl SimpleToken {
+        /// Open trading to everyone - only owner can do this, and only once
+        ///
+        /// Until then, and for `launch_blocks` blocks after, a transfer needs the
+        /// owner, this contract or a whitelisted account on one side.
+        #[ink(message)]
+        pub fn enable_trading(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("enable_trading"))?;
+            self.ensure_privileged()?;
+
+            if self.trading_enabled_at.is_some() {
+                return Err(Error::TradingAlreadyEnabled);
+            }
+
+            let block = self.env().block_number();
+            self.trading_enabled_at = Some(block);
+
+            self.env().emit_event(TradingEnabled {
+                block,
+                launch_blocks: self.launch_blocks,
+            });
+
+            Ok(())
+        }
+
+        /// Set how many blocks after launch stay whitelist-only - only before launch
+        #[ink(message)]
+        pub fn set_launch_blocks(&mut self, launch_blocks: BlockNumber) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_launch_blocks"))?;
+            self.ensure_privileged()?;
+
+            if self.trading_enabled_at.is_some() {
+                return Err(Error::TradingAlreadyEnabled);
+            }
+            self.launch_blocks = launch_blocks;
+
+            Ok(())
+        }
+
+        /// Add or remove an account from the launch whitelist - only owner can do this
+        #[ink(message)]
+        pub fn set_launch_whitelisted(&mut self, account: AccountId, whitelisted: bool) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_launch_whitelisted"))?;
+            self.ensure_privileged()?;
+
+            if whitelisted {
+                self.launch_whitelist.insert(account, &true);
+            } else {
+                self.launch_whitelist.remove(account);
+            }
+
+            self.env().emit_event(LaunchWhitelistUpdated {
+                account,
+                whitelisted,
+            });
+
+            Ok(())
+        }
+
+        /// Check whether an account is on the launch whitelist
+        #[ink(message)]
+        pub fn is_launch_whitelisted(&self, account: AccountId) -> bool {
+            self.launch_whitelist.get(account).unwrap_or(false)
+        }
+
+        /// Get the launch state: (block trading was enabled at, launch blocks)
+        #[ink(message)]
+        pub fn trading_status(&self) -> (Option<BlockNumber>, BlockNumber) {
+            (self.trading_enabled_at, self.launch_blocks)
+        }
+    }
+
+    // ========== SELL LIMIT ==========
+    impl SimpleToken {
+        /// Limit how much an account can sell per window - only owner can do this
+        ///
+        /// Transfers to market accounts count as sells; `window_blocks` of 1 limits
+        /// per block. `None` lifts the limit.
+        #[ink(message)]
+        pub fn set_sell_limit(&mut self, limit: Option<Balance>, window_blocks: BlockNumber) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_sell_limit"))?;
+            self.ensure_privileged()?;
+
+            // Validate: A window must span at least one block
+            if window_blocks == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            self.sell_limit = limit;
+            self.sell_window_blocks = window_blocks;
+
+            self.env().emit_event(SellLimitSet {
+                limit,
+                window_blocks,
+            });
+
+            Ok(())
+        }
+
+        /// Mark or unmark an account as a market (e.g. DEX pair) - only owner can do this
+        #[ink(message)]
+        pub fn set_market_account(&mut self, account: AccountId, market: bool) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_market_account"))?;
+            self.ensure_privileged()?;
+
+            if market {
+                self.market_accounts.insert(account, &true);
+            } else {
+                self.market_accounts.remove(account);
+            }
+
+            self.env().emit_event(SellLimitAccountUpdated {
+                account,
+                market,
+                exempt: self.sell_limit_exempt.get(account).unwrap_or(false),
+            });
+
+            Ok(())
+        }
+
+        /// Exempt an account from the sell limit or remove the exemption - only owner
+        #[ink(message)]
+        pub fn set_sell_limit_exempt(&mut self, account: AccountId, exempt: bool) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_sell_limit_exempt"))?;
+            self.ensure_privileged()?;
+
+            if exempt {
+                self.sell_limit_exempt.insert(account, &true);
+            } else {
+                self.sell_limit_exempt.remove(account);
+            }
+
+            self.env().emit_event(SellLimitAccountUpdated {
+                account,
+                market: self.market_accounts.get(account).unwrap_or(false),
+                exempt,
+            });
+
+            Ok(())
+        }
+
+        /// Get the sell limit configuration: (limit, window length in blocks)
+        #[ink(message)]
+        pub fn sell_limit_config(&self) -> (Option<Balance>, BlockNumber) {
+            (self.sell_limit, self.sell_window_blocks)
+        }
+
+        /// Get whether an account is a market and whether it is exempt from the sell limit
+        #[ink(message)]
+        pub fn sell_limit_status(&self, account: AccountId) -> (bool, bool) {
+            (
+                self.market_accounts.get(account).unwrap_or(false),
+                self.sell_limit_exempt.get(account).unwrap_or(false),
+            )
+        }
+
+        /// Get how much an account can still sell in its current window
+        #[ink(message)]
+        pub fn remaining_sell_allowance(&self, account: AccountId) -> Option<Balance> {
+            let limit = self.sell_limit?;
+            let sold = match self.sells_in_window.get(account) {
+                Some((start, sold)) if self.env().block_number() < start.saturating_add(self.sell_window_blocks) => sold,
+                _ => 0,
+            };
+            Some(limit.saturating_sub(sold))
+        }
+    }
+
+    // ========== CONTRACT RECIPIENTS ==========
+    impl SimpleToken {
+        /// Set how transfers to contract accounts are treated - only owner can do this
+        #[ink(message)]
+        pub fn set_contract_recipient_policy(&mut self, policy: ContractRecipientPolicy) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_contract_recipient_policy"))?;
+            self.ensure_privileged()?;
+
+            self.contract_recipient_policy = policy;
+            self.env().emit_event(ContractRecipientPolicySet { policy });
+
+            Ok(())
+        }
+
+        /// Add or remove a contract code hash from the accept-list - only owner can do this
+        #[ink(message)]
+        pub fn set_code_hash_accepted(&mut self, code_hash: Hash, accepted: bool) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_code_hash_accepted"))?;
+            self.ensure_privileged()?;
+
+            if accepted {
+                self.accepted_code_hashes.insert(code_hash, &true);
+            } else {
+                self.accepted_code_hashes.remove(code_hash);
+            }
+
+            self.env().emit_event(CodeHashAcceptanceChanged {
+                code_hash,
+                accepted,
+            });
+
+            Ok(())
+        }
+
+        /// Get the contract-recipient policy
+        #[ink(message)]
+        pub fn contract_recipient_policy(&self) -> ContractRecipientPolicy {
+            self.contract_recipient_policy
+        }
+
+        /// Check whether contracts with a code hash are on the accept-list
+        #[ink(message)]
+        pub fn is_code_hash_accepted(&self, code_hash: Hash) -> bool {
+            self.accepted_code_hashes.get(code_hash).unwrap_or(false)
+        }
+    }
+
+    // ========== MULTICALL ==========
+    impl SimpleToken {
+        /// Run several operations for the caller in one transaction
+        ///
+        /// Each call goes through the same checks as the message it names; if any
+        /// fails, everything is rolled back.
+        #[ink(message)]
+        pub fn multicall(&mut self, calls: Vec<Call>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("multicall"))?;
+
+            // Validate: Keep batches bounded
+            if calls.is_empty() || calls.len() > MAX_MULTICALL_CALLS {
+                return Err(Error::InvalidAmount);
+            }
+
+            for call in calls {
+                self.dispatch_call(call)?;
+            }
+
+            Ok(())
+        }
+
+        /// Run one operation for the caller, tagging its events with `ref_id`
+        ///
+        /// `ref_id` is an opaque caller reference (e.g. an internal payment ID) echoed
+        /// in the `Transfer`, `Approval`, `Minted` and `Burned` events the call emits.
+        #[ink(message)]
+        pub fn call_with_ref(&mut self, ref_id: [u8; 32], call: Call) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("call_with_ref"))?;
+
+            self.active_ref_id = Some(ref_id);
+            let result = self.dispatch_call(call);
+            self.active_ref_id = None;
+
+            result
+        }
+
+        /// Internal helper that runs one `Call` through the message it names
+        fn dispatch_call(&mut self, call: Call) -> Result<()> {
+            match call {
+                Call::Transfer { to, amount } => self.transfer(to, amount)?,
+                Call::TransferFrom { from, to, amount } => self.transfer_from(from, to, amount)?,
+                Call::Approve { spender, amount } => self.approve(spender, amount)?,
+                Call::Burn { amount } => self.burn(amount)?,
+                Call::DepositToVault { amount } => {
+                    self.deposit_to_vault(amount)?;
+                }
+                Call::WithdrawFromVault { shares } => {
+                    self.withdraw_from_vault(shares)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    // ========== SESSION KEYS ==========
+    impl SimpleToken {
+        /// Let `key` spend up to `limit` of the caller's tokens until `expires_at`
+        ///
+        /// Registering the same key again replaces its budget.
+        #[ink(message)]
+        pub fn register_session_key(&mut self, key: AccountId, limit: Balance, expires_at: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("register_session_key"))?;
+
+            let caller = self.env().caller();
+
+            // Validate: A session key is a separate key with a budget and a future expiry
+            if key == caller || limit == 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if expires_at <= self.env().block_timestamp() {
+                return Err(Error::SessionKeyExpired);
+            }
+
+            self.session_keys.insert(
+                (caller, key),
+                &SessionKey {
+                    remaining: limit,
+                    expires_at,
+                },
+            );
+
+            self.env().emit_event(SessionKeyRegistered {
+                account: caller,
+                key,
+                limit,
+                expires_at,
+            });
+
+            Ok(())
+        }
+
+        /// Revoke one of the caller's session keys
+        #[ink(message)]
+        pub fn revoke_session_key(&mut self, key: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("revoke_session_key"))?;
+
+            let caller = self.env().caller();
+            self.session_keys
+                .take((caller, key))
+                .ok_or(Error::SessionKeyNotFound)?;
+
+            self.env().emit_event(SessionKeyRevoked { account: caller, key });
+
+            Ok(())
+        }
+
+        /// Transfer `account`'s tokens as its session key, drawing from the key's budget
+        #[ink(message)]
+        pub fn session_transfer(&mut self, account: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("session_transfer"))?;
+
+            let key = self.env().caller();
+            let mut session = self
+                .session_keys
+                .get((account, key))
+                .ok_or(Error::SessionKeyNotFound)?;
+
+            if self.env().block_timestamp() >= session.expires_at {
+                return Err(Error::SessionKeyExpired);
+            }
+            if amount > session.remaining {
+                return Err(Error::SessionBudgetExceeded);
+            }
+
+            session.remaining -= amount;
+            if session.remaining == 0 {
+                self.session_keys.remove((account, key));
+            } else {
+                self.session_keys.insert((account, key), &session);
+            }
+
+            self._transfer(account, to, amount)?;
+
+            self.env().emit_event(SessionKeyUsed {
+                account,
+                key,
+                amount,
+                remaining: session.remaining,
+            });
+            self.flush_balance_notifications();
+
+            Ok(())
+        }
+
+        /// Get a session key's remaining budget and expiry
+        #[ink(message)]
+        pub fn get_session_key(&self, account: AccountId, key: AccountId) -> Option<SessionKey> {
+            self.session_keys.get((account, key))
+        }
+    }
+
+    // ========== ACCOUNT GUARDIANS ==========
+    impl SimpleToken {
+        /// Register guardians who can jointly freeze the caller's balance
+        ///
+        /// Pass an empty list and a threshold of 0 to remove them. Cannot be
+        /// changed while the account is frozen.
+        #[ink(message)]
+        pub fn set_account_guardians(&mut self, guardians: Vec<AccountId>, threshold: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_account_guardians"))?;
+
+            let caller = self.env().caller();
+            self.ensure_not_frozen(caller)?;
+
+            // Validate: Bounded, distinct guardians other than the account itself
+            if guardians.len() > MAX_ACCOUNT_GUARDIANS {
+                return Err(Error::InvalidGuardian);
+            }
+            for (i, guardian) in guardians.iter().enumerate() {
+                if *guardian == caller || guardians[..i].contains(guardian) {
+                    return Err(Error::InvalidGuardian);
+                }
+            }
+
+            // Validate: Threshold must be reachable (and 0 only without guardians)
+            let count = guardians.len() as u32;
+            if threshold > count || (count > 0 && threshold == 0) {
+                return Err(Error::InvalidThreshold);
+            }
+
+            if guardians.is_empty() {
+                self.account_guardians.remove(caller);
+            } else {
+                self.account_guardians.insert(
+                    caller,
+                    &AccountGuardians {
+                        guardians: guardians.clone(),
+                        threshold,
+                    },
+                );
+            }
+            self.reset_freeze_votes(caller);
+
+            self.env().emit_event(AccountGuardiansSet {
+                account: caller,
+                guardians,
+                threshold,
+            });
+
+            Ok(())
+        }
+
+        /// Vote to freeze `account` (or unfreeze it if already frozen) - only its guardians
+        ///
+        /// The freeze state flips once `threshold` guardians have voted.
+        #[ink(message)]
+        pub fn vote_account_freeze(&mut self, account: AccountId) -> Result<bool> {
+            self.guard_selector(ink::selector_bytes!("vote_account_freeze"))?;
+
+            let caller = self.env().caller();
+            let config = self
+                .account_guardians
+                .get(account)
+                .ok_or(Error::Unauthorized)?;
+            if !config.guardians.contains(&caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            let round = self.freeze_rounds.get(account).unwrap_or(0);
+            if self.freeze_votes.get((account, round, caller)).unwrap_or(false) {
+                return Err(Error::AlreadyConfirmed);
+            }
+            self.freeze_votes.insert((account, round, caller), &true);
+            let votes = self
+                .freeze_vote_counts
+                .get((account, round))
+                .unwrap_or(0)
+                .saturating_add(1);
+            self.freeze_vote_counts.insert((account, round), &votes);
+
+            let frozen = self.frozen_accounts.get(account).unwrap_or(false);
+            self.env().emit_event(FreezeVoteCast {
+                account,
+                guardian: caller,
+                freeze: !frozen,
+                votes,
+            });
+
+            if votes < config.threshold {
+                return Ok(frozen);
+            }
+
+            if frozen {
+                self.frozen_accounts.remove(account);
+            } else {
+                self.frozen_accounts.insert(account, &true);
+            }
+            self.reset_freeze_votes(account);
+
+            self.env().emit_event(AccountFreezeChanged {
+                account,
+                frozen: !frozen,
+            });
+
+            Ok(!frozen)
+        }
+
+        /// Get an account's freeze guardians
+        #[ink(message)]
+        pub fn account_guardians(&self, account: AccountId) -> Option<AccountGuardians> {
+            self.account_guardians.get(account)
+        }
+
+        /// Check whether an account was frozen by its guardians
+        #[ink(message)]
+        pub fn is_frozen(&self, account: AccountId) -> bool {
+            self.frozen_accounts.get(account).unwrap_or(false)
+        }
+    }
+
+    // ========== TOKEN FACTORY ==========
+    impl SimpleToken {
+        /// Deploy a new token from this contract's own code and return its address
+        ///
+        /// The caller becomes owner unless `config.owner` says otherwise.
+        #[ink(message)]
+        pub fn create_token(&mut self, mut config: TokenConfig) -> Result<AccountId> {
+            self.guard_selector(ink::selector_bytes!("create_token"))?;
+
+            let caller = self.env().caller();
+            if config.owner.is_none() {
+                config.owner = Some(caller);
+            }
+
+            let code_hash = self
+                .env()
+                .own_code_hash()
+                .map_err(|_| Error::TokenCreationFailed)?;
+            let index = self.created_token_count;
+
+            let created = SimpleTokenRef::new_with_config(config)
+                .code_hash(code_hash)
+                .endowment(0)
+                .salt_bytes(index.to_le_bytes())
+                .try_instantiate();
+            let token = match created {
+                Ok(Ok(Ok(token))) => ink::ToAccountId::to_account_id(&token),
+                Ok(Ok(Err(error))) => return Err(error),
+                _ => return Err(Error::TokenCreationFailed),
+            };
+
+            self.created_tokens.insert(index, &token);
+            self.created_token_count = index.checked_add(1).ok_or(Error::Overflow)?;
+
+            self.env().emit_event(TokenCreated {
+                creator: caller,
+                token,
+                index,
+            });
+
+            Ok(token)
+        }
+
+        /// Get the token contract created at `index`
+        #[ink(message)]
+        pub fn created_token(&self, index: u32) -> Option<AccountId> {
+            self.created_tokens.get(index)
+        }
+
+        /// Get the number of token contracts created through this contract
+        #[ink(message)]
+        pub fn created_token_count(&self) -> u32 {
+            self.created_token_count
+        }
+    }
+
+    // ========== PAYMENT REQUESTS ==========
+    impl SimpleToken {
+        /// Ask `from` to pay the caller `amount`; returns the request ID
+        ///
+        /// The request stays payable for `PAYMENT_REQUEST_TTL`.
+        #[ink(message)]
+        pub fn request_payment(&mut self, from: AccountId, amount: Balance, memo: Vec<u8>) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("request_payment"))?;
+
+            let caller = self.env().caller();
+
+            // Validate: A real amount from someone else, with a short memo
+            if amount == 0 || from == caller {
+                return Err(Error::InvalidAmount);
+            }
+            if memo.len() > MAX_MEMO_LEN {
+                return Err(Error::ReasonTooLong);
+            }
+
+            let id = self.next_payment_request_id;
+            let expires_at = self.env().block_timestamp().saturating_add(PAYMENT_REQUEST_TTL);
+            self.payment_requests.insert(
+                id,
+                &PaymentRequest {
+                    payee: caller,
+                    payer: from,
+                    amount,
+                    memo: memo.clone(),
+                    expires_at,
+                },
+            );
+            self.next_payment_request_id = id.checked_add(1).ok_or(Error::Overflow)?;
+
+            self.env().emit_event(PaymentRequested {
+                id,
+                payee: caller,
+                payer: from,
+                amount,
+                memo,
+                expires_at,
+            });
+
+            Ok(id)
+        }
+
+        /// Pay a payment request addressed to the caller
+        #[ink(message)]
+        pub fn pay_request(&mut self, request_id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("pay_request"))?;
+
+            let caller = self.env().caller();
+            let request = self
+                .payment_requests
+                .get(request_id)
+                .ok_or(Error::PaymentRequestNotFound)?;
+
+            if request.payer != caller {
+                return Err(Error::Unauthorized);
+            }
+            if self.env().block_timestamp() >= request.expires_at {
+                return Err(Error::PaymentRequestExpired);
+            }
+
+            self.payment_requests.remove(request_id);
+            self._transfer(caller, request.payee, request.amount)?;
+
+            self.env().emit_event(PaymentRequestPaid { id: request_id });
+            self.flush_balance_notifications();
+
+            Ok(())
+        }
+
+        /// Drop a payment request - the payee can cancel it, the payer can decline it
+        #[ink(message)]
+        pub fn cancel_payment_request(&mut self, request_id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("cancel_payment_request"))?;
+
+            let caller = self.env().caller();
+            let request = self
+                .payment_requests
+                .get(request_id)
+                .ok_or(Error::PaymentRequestNotFound)?;
+
+            if request.payee != caller && request.payer != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            self.payment_requests.remove(request_id);
+            self.env().emit_event(PaymentRequestCancelled {
+                id: request_id,
+                by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Get a payment request by ID
+        #[ink(message)]
+        pub fn get_payment_request(&self, request_id: u32) -> Option<PaymentRequest> {
+            self.payment_requests.get(request_id)
+        }
+    }
+
+    // ========== HASHED TIMELOCKS ==========
+    impl SimpleToken {
+        /// Lock tokens for `recipient` against the SHA-256 `hashlock` until `timelock`
+        ///
+        /// The recipient claims by revealing the preimage before `timelock`;
+        /// afterwards only the sender can take the tokens back.
+        #[ink(message)]
+        pub fn lock_htlc(
+            &mut self,
+            recipient: AccountId,
+            amount: Balance,
+            hashlock: [u8; 32],
+            timelock: u64,
+        ) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("lock_htlc"))?;
+
+            // Validate: The lock must still be claimable
+            if timelock <= self.env().block_timestamp() {
+                return Err(Error::HtlcExpired);
+            }
+
+            let caller = self.env().caller();
+            self.debit_unlocked(caller, amount)?;
+
+            self.htlc_escrow_total = self
+                .htlc_escrow_total
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+
+            let id = self.next_htlc_id;
+            self.next_htlc_id = id.checked_add(1).ok_or(Error::Overflow)?;
+            self.htlcs.insert(
+                id,
+                &Htlc {
+                    sender: caller,
+                    recipient,
+                    amount,
+                    hashlock,
+                    timelock,
+                },
+            );
+
+            self.env().emit_event(HtlcLocked {
+                id,
+                sender: caller,
+                recipient,
+                amount,
+                hashlock,
+                timelock,
+            });
+
+            Ok(id)
+        }
+
+        /// Claim an HTLC by revealing its preimage - anyone can submit it before expiry
+        #[ink(message)]
+        pub fn claim_htlc(&mut self, id: u32, preimage: Vec<u8>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("claim_htlc"))?;
+
+            let htlc = self.htlcs.get(id).ok_or(Error::HtlcNotFound)?;
+
+            if self.env().block_timestamp() >= htlc.timelock {
+                return Err(Error::HtlcExpired);
+            }
+            if self.env().hash_bytes::<Sha2x256>(&preimage) != htlc.hashlock {
+                return Err(Error::InvalidPreimage);
+            }
+            if self.check_blacklisted(htlc.recipient) {
+                return Err(Error::AccountBlacklisted);
+            }
+
+            self.htlcs.remove(id);
+            self.htlc_escrow_total = self
+                .htlc_escrow_total
+                .checked_sub(htlc.amount)
+                .ok_or(Error::Overflow)?;
+            self.credit(htlc.recipient, htlc.amount)?;
+
+            self.env().emit_event(HtlcClaimed { id, preimage });
+
+            Ok(())
+        }
+
+        /// Return an expired HTLC to its sender - anyone can call
+        #[ink(message)]
+        pub fn refund_htlc(&mut self, id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("refund_htlc"))?;
+
+            let htlc = self.htlcs.get(id).ok_or(Error::HtlcNotFound)?;
+
+            if self.env().block_timestamp() < htlc.timelock {
+                return Err(Error::HtlcNotExpired);
+            }
+
+            self.htlcs.remove(id);
+            self.htlc_escrow_total = self
+                .htlc_escrow_total
+                .checked_sub(htlc.amount)
+                .ok_or(Error::Overflow)?;
+            self.credit(htlc.sender, htlc.amount)?;
+
+            self.env().emit_event(HtlcRefunded { id });
+
+            Ok(())
+        }
+
+        /// Get an HTLC by ID
+        #[ink(message)]
+        pub fn get_htlc(&self, id: u32) -> Option<Htlc> {
+            self.htlcs.get(id)
+        }
+    }
+
+    // ========== TOKEN SWAPS ==========
+    impl SimpleToken {
+        /// Escrow `amount` and offer it for `ask_amount` of another PSP22 token
+        #[ink(message)]
+        pub fn create_swap_offer(
+            &mut self,
+            amount: Balance,
+            ask_token: AccountId,
+            ask_amount: Balance,
+            taker: Option<AccountId>,
+            expires_at: u64,
+        ) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("create_swap_offer"))?;
+
+            // Validate: Something to ask for, from another token, before expiry
+            if ask_amount == 0 || ask_token == self.env().account_id() {
+                return Err(Error::InvalidAmount);
+            }
+            if expires_at <= self.env().block_timestamp() {
+                return Err(Error::SwapOfferExpired);
+            }
+
+            let caller = self.env().caller();
+            self.debit_unlocked(caller, amount)?;
+            self.swap_escrow_total = self
+                .swap_escrow_total
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+
+            let id = self.next_swap_offer_id;
+            self.next_swap_offer_id = id.checked_add(1).ok_or(Error::Overflow)?;
+            self.swap_offers.insert(
+                id,
+                &SwapOffer {
+                    maker: caller,
+                    amount,
+                    ask_token,
+                    ask_amount,
+                    taker,
+                    expires_at,
+                },
+            );
+
+            self.env().emit_event(SwapOfferCreated {
+                id,
+                maker: caller,
+                amount,
+                ask_token,
+                ask_amount,
+                taker,
+                expires_at,
+            });
+
+            Ok(id)
+        }
+
+        /// Accept a swap offer
+        ///
+        /// The caller must have approved this contract to spend `ask_amount` on
+        /// `ask_token`. That leg is paid straight to the maker; if it fails,
+        /// nothing moves.
+        #[ink(message)]
+        pub fn accept_swap_offer(&mut self, id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("accept_swap_offer"))?;
+
+            let caller = self.env().caller();
+            let offer = self.swap_offers.get(id).ok_or(Error::SwapOfferNotFound)?;
+
+            if offer.taker.is_some_and(|taker| taker != caller) {
+                return Err(Error::Unauthorized);
+            }
+            if self.env().block_timestamp() >= offer.expires_at {
+                return Err(Error::SwapOfferExpired);
+            }
+            if self.check_blacklisted(caller) {
+                return Err(Error::AccountBlacklisted);
+            }
+
+            // Other leg first: an error here reverts the whole swap
+            self.call_psp22_transfer_from(offer.ask_token, caller, offer.maker, offer.ask_amount)?;
+
+            self.swap_offers.remove(id);
+            self.swap_escrow_total = self
+                .swap_escrow_total
+                .checked_sub(offer.amount)
+                .ok_or(Error::Overflow)?;
+            self.credit(caller, offer.amount)?;
+
+            self.env().emit_event(SwapSettled { id, taker: caller });
+
+            Ok(())
+        }
+
+        /// Cancel a swap offer and take the escrow back - only the maker
+        #[ink(message)]
+        pub fn cancel_swap_offer(&mut self, id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("cancel_swap_offer"))?;
+
+            let offer = self.swap_offers.get(id).ok_or(Error::SwapOfferNotFound)?;
+            if offer.maker != self.env().caller() {
+                return Err(Error::Unauthorized);
+            }
+
+            self.close_swap_offer(id, offer)
+        }
+
+        /// Return an expired swap offer's escrow to its maker - anyone can call
+        #[ink(message)]
+        pub fn refund_swap_offer(&mut self, id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("refund_swap_offer"))?;
+
+            let offer = self.swap_offers.get(id).ok_or(Error::SwapOfferNotFound)?;
+            if self.env().block_timestamp() < offer.expires_at {
+                return Err(Error::SwapOfferNotExpired);
+            }
+
+            self.close_swap_offer(id, offer)
+        }
+
+        /// Get a swap offer by ID
+        #[ink(message)]
+        pub fn get_swap_offer(&self, id: u32) -> Option<SwapOffer> {
+            self.swap_offers.get(id)
+        }
+    }
+
+    // ========== STORAGE CLEANUP ==========
+    impl SimpleToken {
+        /// Remove an account's stored zero balance - anyone can call
+        ///
+        /// Frees the storage deposit. Returns whether there was anything to remove.
+        #[ink(message)]
+        pub fn clear_zero_balance(&mut self, account: AccountId) -> Result<bool> {
+            self.guard_selector(ink::selector_bytes!("clear_zero_balance"))?;
+
+            match self.balances.get(account) {
+                None => Ok(false),
+                Some(0) => {
+                    // Left over from before balance writes pruned empty accounts
+                    self.write_balance(account, 0);
+                    Ok(true)
+                }
+                Some(_) => Err(Error::InvalidAmount),
+            }
+        }
+
+        /// Get how many emptied balance entries have been removed from storage
+        ///
+        /// Each one returned its storage deposit.
+        #[ink(message)]
+        pub fn pruned_accounts(&self) -> u64 {
+            self.pruned_accounts
+        }
+
+        /// Remove a stored zero allowance - anyone can call
+        ///
+        /// Frees the storage deposit. Returns whether there was anything to remove.
+        #[ink(message)]
+        pub fn clear_zero_allowance(&mut self, owner: AccountId, spender: AccountId) -> Result<bool> {
+            self.guard_selector(ink::selector_bytes!("clear_zero_allowance"))?;
+
+            match self.allowances.get((owner, spender)) {
+                None => Ok(false),
+                Some(0) => {
+                    self.allowances.remove((owner, spender));
+                    Ok(true)
+                }
+                Some(_) => Err(Error::InvalidAmount),
+            }
+        }
+    }
+
+    // ========== HOLDER REPORT ==========
+    impl SimpleToken {
+        /// Rebuild the ranked holder report one page at a time - only owner can do this
+        ///
+        /// Merges up to `limit` holders starting at index `start` into the ranking
+        /// and keeps the top `MAX_HOLDER_REPORT`; `start == 0` begins a fresh
+        /// ranking. Returns the `start` for the next page, which equals the holder
+        /// count once the pass is complete.
+        #[ink(message)]
+        pub fn reindex_holders(&mut self, start: u32, limit: u32) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("reindex_holders"))?;
+            self.ensure_privileged()?;
+
+            let mut ranked: Vec<(AccountId, Balance)> = if start == 0 {
+                Vec::new()
+            } else {
+                (0..self.holder_ranking_len)
+                    .filter_map(|rank| self.holder_ranking.get(rank))
+                    .map(|account| (account, self.balance_of(account)))
+                    .collect()
+            };
+            let end = start.saturating_add(limit).min(self.holder_count);
+            for account in (start..end).filter_map(|index| self.holders.get(index)) {
+                if !ranked.iter().any(|(ranked_account, _)| *ranked_account == account) {
+                    ranked.push((account, self.balance_of(account)));
+                }
+            }
+            ranked.sort_by(|a, b| b.1.cmp(&a.1));
+            ranked.truncate(MAX_HOLDER_REPORT as usize);
+
+            let len = ranked.len() as u32;
+            for (rank, (account, _)) in ranked.into_iter().enumerate() {
+                self.holder_ranking.insert(rank as u32, &account);
+            }
+            // Drop ranks left over from a longer previous ranking
+            for rank in len..self.holder_ranking_len {
+                self.holder_ranking.remove(rank);
+            }
+            self.holder_ranking_len = len;
+            if end >= self.holder_count {
+                self.holder_ranking_updated_at = self.env().block_timestamp();
+            }
+
+            Ok(end)
+        }
+
+        /// Get up to `limit` holders starting at rank `offset`, largest first
+        ///
+        /// Order is as of the last `reindex_holders`; balances are current.
+        #[ink(message)]
+        pub fn holder_report(&self, offset: u32, limit: u32) -> Vec<(AccountId, Balance)> {
+            let end = offset.saturating_add(limit).min(self.holder_ranking_len);
+            (offset..end)
+                .filter_map(|rank| self.holder_ranking.get(rank))
+                .map(|account| (account, self.balance_of(account)))
+                .collect()
+        }
+
+        /// Get the number of holders and when the ranking was last rebuilt
+        #[ink(message)]
+        pub fn holder_stats(&self) -> (u32, u64) {
+            (self.holder_count, self.holder_ranking_updated_at)
+        }
+    }
+
+    // ========== CAPABILITIES ==========
+    impl SimpleToken {
+        /// Check whether this deployment supports and has enabled an interface
+        ///
+        /// Interface ids are the `INTERFACE_*` constants. Configurable modules
+        /// only report `true` while they are switched on.
+        #[ink(message)]
+        pub fn supports(&self, interface_id: InterfaceId) -> bool {
+            match interface_id {
+                INTERFACE_PSP22
+                | INTERFACE_METADATA
+                | INTERFACE_BURNABLE
+                | INTERFACE_MINTABLE
+                | INTERFACE_ACCESS_CONTROL
+                | INTERFACE_VESTING
+                | INTERFACE_PERMIT
+                | INTERFACE_VAULT
+                | INTERFACE_ESCROW => true,
+                INTERFACE_PAUSABLE => cfg!(feature = "pause"),
+                INTERFACE_BLACKLIST => cfg!(feature = "blacklist"),
+                INTERFACE_FEES => cfg!(feature = "fees") && self.fee_bps > 0,
+                INTERFACE_LOTTERY => self.lottery_epoch_length > 0,
+                INTERFACE_EMISSION => self.emission_distributor.is_some(),
+                INTERFACE_MULTISIG => self.multisig_threshold > 0,
+                _ => false,
+            }
+        }
+    }
+
+    // ========== ADMIN AUDIT LOG ==========
+    impl SimpleToken {
+        /// Get up to `limit` admin log entries, oldest retained entry first
+        ///
+        /// Only the last `ADMIN_LOG_CAPACITY` actions are kept; `offset` counts
+        /// from the oldest of those.
+        #[ink(message)]
+        pub fn admin_log(&self, offset: u32, limit: u32) -> Vec<AdminLogEntry> {
+            let first = self.admin_log_len.saturating_sub(ADMIN_LOG_CAPACITY);
+            let start = first.saturating_add(offset);
+            let end = start.saturating_add(limit).min(self.admin_log_len);
+            (start..end)
+                .filter_map(|sequence| self.admin_log.get(sequence % ADMIN_LOG_CAPACITY))
+                .collect()
+        }
+
+        /// Get the number of admin actions ever recorded (older ones may have been overwritten)
+        #[ink(message)]
+        pub fn admin_log_len(&self) -> u32 {
+            self.admin_log_len
+        }
+    }
+
+    // ========== INCIDENT MODE ==========
+    impl SimpleToken {
+        /// Turn incident mode on or off - owner or guardian can turn it on, only owner off
+        ///
+        /// While on, only the owner can move tokens freely; everyone else can only
+        /// send to the configured recovery address. The guardian can only switch
+        /// it on once the owner has set that address.
+        #[ink(message)]
+        pub fn set_incident_mode(&mut self, active: bool) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_incident_mode"))?;
+
+            // The guardian may raise the alarm on its own; everyone else needs owner rights
+            if active && self.guardian == Some(self.env().caller()) {
+                if self.incident_recovery_address.is_none() {
+                    return Err(Error::NoIncidentRecoveryAddress);
+                }
+            } else {
+                self.ensure_privileged()?;
+            }
+
+            self.incident_mode = active;
+
+            self.env().emit_event(IncidentModeChanged {
+                active,
+                recovery_address: self.incident_recovery_address,
+                by: self.env().caller(),
+            });
+            self.record_admin_action(AdminLogAction::IncidentMode { active });
+
+            Ok(())
+        }
+
+        /// Set the account that may still receive tokens during incident mode
+        /// (e.g. an exchange or recovery wallet) - only owner can do this
+        #[ink(message)]
+        pub fn set_incident_recovery_address(&mut self, recovery_address: Option<AccountId>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_incident_recovery_address"))?;
+            self.ensure_privileged()?;
+
+            self.incident_recovery_address = recovery_address;
+
+            self.env().emit_event(IncidentRecoveryAddressSet {
+                recovery_address,
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Get the incident mode state: (active, recovery address)
+        #[ink(message)]
+        pub fn incident_mode(&self) -> (bool, Option<AccountId>) {
+            (self.incident_mode, self.incident_recovery_address)
+        }
+    }
+
+    // ========== CIRCUIT BREAKER ==========
+    impl SimpleToken {
+        /// Pause automatically once more than `threshold` moves within `window` ms - only owner
+        ///
+        /// Pass `None` to switch the breaker off. Unpausing after a trip is manual.
+        #[cfg(feature = "pause")]
+        #[ink(message)]
+        pub fn set_circuit_breaker(&mut self, threshold: Option<Balance>, window: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_circuit_breaker"))?;
+            self.ensure_privileged()?;
+
+            // Validate: An active breaker needs a window to measure volume in
+            if threshold.is_some() && window == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            self.circuit_breaker_threshold = threshold;
+            self.circuit_breaker_window = window;
+            self.volume_window_start = self.env().block_timestamp();
+            self.volume_in_window = 0;
+
+            self.env().emit_event(CircuitBreakerConfigured { threshold, window });
+
+            Ok(())
+        }
+
+        /// Get the circuit breaker state: (threshold, window, volume in the current window)
+        #[ink(message)]
+        pub fn circuit_breaker(&self) -> (Option<Balance>, u64, Balance) {
+            (
+                self.circuit_breaker_threshold,
+                self.circuit_breaker_window,
+                self.volume_in_window,
+            )
+        }
+    }
+
+    // ========== VELOCITY CHECKS ==========
+    impl SimpleToken {
+        /// Set the per-sender limits used to flag suspicious activity - only RISK role
+        ///
+        /// Pass `None` to switch the checks off.
+        #[ink(message)]
+        pub fn set_velocity_limits(&mut self, limits: Option<VelocityLimits>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_velocity_limits"))?;
+            self.ensure_role(RISK)?;
+
+            // Validate: Limits are measured over a non-empty window
+            if limits.as_ref().is_some_and(|limits| limits.window == 0) {
+                return Err(Error::InvalidAmount);
+            }
+
+            self.velocity_limits = limits.clone();
+            self.env().emit_event(VelocityLimitsSet {
+                limits,
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Release an account frozen by the velocity check - only COMPLIANCE role
+        #[ink(message)]
+        pub fn release_risk_freeze(&mut self, account: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("release_risk_freeze"))?;
+            self.ensure_role(COMPLIANCE)?;
+
+            if !self.risk_frozen.get(account).unwrap_or(false) {
+                return Err(Error::InvalidAmount);
+            }
+            self.risk_frozen.remove(account);
+
+            self.env().emit_event(RiskFreezeReleased {
+                account,
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Get the velocity limits
+        #[ink(message)]
+        pub fn velocity_limits(&self) -> Option<VelocityLimits> {
+            self.velocity_limits.clone()
+        }
+
+        /// Get an account's activity in its current velocity window
+        #[ink(message)]
+        pub fn send_activity(&self, account: AccountId) -> SendActivity {
+            self.send_activity.get(account).unwrap_or_default()
+        }
+
+        /// Check whether an account is frozen pending compliance review
+        #[ink(message)]
+        pub fn is_risk_frozen(&self, account: AccountId) -> bool {
+            self.risk_frozen.get(account).unwrap_or(false)
+        }
+    }
+
+    // ========== BRIDGES ==========
+    impl SimpleToken {
+        /// Register a bridge or change its cap - only owner can do this
+        ///
+        /// Each bridge can only have `cap` of its own mints outstanding, so one
+        /// compromised bridge cannot mint against another bridge's collateral.
+        #[ink(message)]
+        pub fn set_bridge(&mut self, bridge: AccountId, cap: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_bridge"))?;
+            self.ensure_privileged()?;
+
+            let mut config = self.bridges.get(bridge).unwrap_or_default();
+            config.cap = cap;
+            self.bridges.insert(bridge, &config);
+
+            self.env().emit_event(BridgeConfigured { bridge, cap });
+
+            Ok(())
+        }
+
+        /// Unregister a bridge once nothing it minted is outstanding - only owner can do this
+        #[ink(message)]
+        pub fn remove_bridge(&mut self, bridge: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("remove_bridge"))?;
+            self.ensure_privileged()?;
+
+            let config = self.bridges.get(bridge).ok_or(Error::UnknownBridge)?;
+            if config.outstanding > 0 {
+                return Err(Error::BridgeOutstanding);
+            }
+            self.bridges.remove(bridge);
+
+            self.env().emit_event(BridgeRemoved { bridge });
+
+            Ok(())
+        }
+
+        /// Mint tokens that arrived from another chain - only registered bridges
+        #[ink(message)]
+        pub fn bridge_mint(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("bridge_mint"))?;
+            self.ensure_no_emergency()?;
+
+            let bridge = self.env().caller();
+            let mut config = self.bridges.get(bridge).ok_or(Error::UnknownBridge)?;
+
+            // Validate: The bridge may only mint up to its own cap and rate limit
+            config.outstanding = config.outstanding.checked_add(amount).ok_or(Error::Overflow)?;
+            if config.outstanding > config.cap {
+                return Err(Error::BridgeCapExceeded);
+            }
+            if let Some(rate) = config.mint_rate.as_mut() {
+                self.consume_rate_limit(rate, amount)?;
+            }
+
+            self._mint(to, amount)?;
+            self.bridges.insert(bridge, &config);
+
+            self.env().emit_event(BridgeMinted { bridge, to, amount });
+            self.flush_balance_notifications();
+
+            Ok(())
+        }
+
+        /// Burn tokens leaving for chain `chain_id` - only registered bridges
+        ///
+        /// Burning from another account uses the bridge's allowance. A bridge
+        /// cannot burn more than it has outstanding. The amount is added to
+        /// `bridged_supply(chain_id)`.
+        #[ink(message)]
+        pub fn bridge_burn(&mut self, from: AccountId, amount: Balance, chain_id: ChainId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("bridge_burn"))?;
+
+            let bridge = self.env().caller();
+            let mut config = self.bridges.get(bridge).ok_or(Error::UnknownBridge)?;
+            config.outstanding = config
+                .outstanding
+                .checked_sub(amount)
+                .ok_or(Error::BridgeOutstanding)?;
+            if let Some(rate) = config.burn_rate.as_mut() {
+                self.consume_rate_limit(rate, amount)?;
+            }
+
+            if from != bridge {
+                self.spend_allowance(from, bridge, amount)?;
+            }
+            self._burn(from, amount, b"bridge".to_vec())?;
+            self.bridges.insert(bridge, &config);
+
+            let bridged = self.bridged_supply.get(chain_id).unwrap_or(0);
+            self.bridged_supply
+                .insert(chain_id, &bridged.checked_add(amount).ok_or(Error::Overflow)?);
+            self.total_bridged_supply = self
+                .total_bridged_supply
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+
+            self.env().emit_event(BridgeBurned {
+                bridge,
+                from,
+                amount,
+                chain_id,
+            });
+            self.flush_balance_notifications();
+
+            Ok(())
+        }
+
+        /// Set a bridge's mint and burn rate limits as (limit, period in ms) - only owner
+        ///
+        /// Each limit refills linearly over its period and starts out full. Pass
+        /// `None` to lift a limit.
+        #[ink(message)]
+        pub fn set_bridge_rate_limits(
+            &mut self,
+            bridge: AccountId,
+            mint: Option<(Balance, u64)>,
+            burn: Option<(Balance, u64)>,
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_bridge_rate_limits"))?;
+            self.ensure_privileged()?;
+
+            let mut config = self.bridges.get(bridge).ok_or(Error::UnknownBridge)?;
+
+            // Validate: Every limit needs a refill period
+            if mint.is_some_and(|(_, period)| period == 0) || burn.is_some_and(|(_, period)| period == 0) {
+                return Err(Error::InvalidAmount);
+            }
+
+            let now = self.env().block_timestamp();
+            let bucket = |(limit, period): (Balance, u64)| RateLimit {
+                limit,
+                period,
+                available: limit,
+                updated_at: now,
+            };
+            config.mint_rate = mint.map(bucket);
+            config.burn_rate = burn.map(bucket);
+            self.bridges.insert(bridge, &config);
+
+            self.env().emit_event(BridgeRateLimitsSet { bridge, mint, burn });
+
+            Ok(())
+        }
+
+        /// Get a bridge's cap, outstanding amount and rate limits
+        #[ink(message)]
+        pub fn get_bridge(&self, bridge: AccountId) -> Option<Bridge> {
+            self.bridges.get(bridge)
+        }
+
+        /// Get how much a bridge can mint and burn right now under its rate limits
+        #[ink(message)]
+        pub fn bridge_rate_available(&self, bridge: AccountId) -> (Option<Balance>, Option<Balance>) {
+            let Some(config) = self.bridges.get(bridge) else {
+                return (Some(0), Some(0));
+            };
+            (
+                config.mint_rate.map(|rate| self.refilled(&rate)),
+                config.burn_rate.map(|rate| self.refilled(&rate)),
+            )
+        }
+
+        /// Internal helper: amount a rate limit bucket holds after refilling up to now
+        fn refilled(&self, rate: &RateLimit) -> Balance {
+            let elapsed = self.env().block_timestamp().saturating_sub(rate.updated_at);
+            let refill = Self::mul_div(rate.limit, Balance::from(elapsed), Balance::from(rate.period))
+                .unwrap_or(rate.limit);
+            rate.available.saturating_add(refill).min(rate.limit)
+        }
+
+        /// Internal helper that takes `amount` out of a rate limit bucket
+        fn consume_rate_limit(&self, rate: &mut RateLimit, amount: Balance) -> Result<()> {
+            let available = self.refilled(rate);
+            if amount > available {
+                return Err(Error::BridgeRateLimited);
+            }
+            rate.available = available - amount;
+            rate.updated_at = self.env().block_timestamp();
+            Ok(())
+        }
+
+        /// Get how much a bridge has minted and not yet burned
+        #[ink(message)]
+        pub fn bridge_outstanding(&self, bridge: AccountId) -> Balance {
+            self.bridges.get(bridge).map_or(0, |config| config.outstanding)
+        }
+
+        /// Get how much has been bridge-burned toward a destination chain
+        #[ink(message)]
+        pub fn bridged_supply(&self, chain_id: ChainId) -> Balance {
+            self.bridged_supply.get(chain_id).unwrap_or(0)
+        }
+
+        /// Get how much has been bridge-burned toward all destination chains
+        #[ink(message)]
+        pub fn total_bridged_supply(&self) -> Balance {
+            self.total_bridged_supply
+        }
+    }
+
+    // ========== BALANCE OBSERVER ==========
+    impl SimpleToken {
+        /// Register a staking/rewards contract to be told about balance changes - only owner
+        ///
+        /// The contract must expose `on_balance_change(account: AccountId, new_balance: Balance)`
+        /// and be on the callback whitelist. It is called once per changed account
+        /// after the message's state updates, and cannot call back into the token.
+        /// Pass `None` to stop notifying.
+        #[ink(message)]
+        pub fn set_balance_observer(&mut self, observer: Option<AccountId>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_balance_observer"))?;
+            self.ensure_privileged()?;
+
+            self.balance_observer = observer;
+            self.env().emit_event(BalanceObserverSet { observer });
+
+            Ok(())
+        }
+
+        /// Get the registered balance observer
+        #[ink(message)]
+        pub fn balance_observer(&self) -> Option<AccountId> {
+            self.balance_observer
+        }
+    }
+
+    // ========== CALLBACK WHITELIST ==========
+    impl SimpleToken {
+        /// Allow callbacks to a contract, capped at `gas_limit` ref-time weight - only owner
+        ///
+        /// Covers `transfer_and_call` recipients, the balance observer, the price
+        /// oracle and the ownership probe. Pass `None` to remove the contract from the whitelist.
+        #[ink(message)]
+        pub fn set_callback_gas_limit(&mut self, callee: AccountId, gas_limit: Option<u64>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_callback_gas_limit"))?;
+            self.ensure_privileged()?;
+
+            // Validate: A zero cap would make every callback fail
+            if gas_limit == Some(0) {
+                return Err(Error::InvalidAmount);
+            }
+
+            if let Some(limit) = gas_limit {
+                self.callback_gas_limits.insert(callee, &limit);
+            } else {
+                self.callback_gas_limits.remove(callee);
+            }
+
+            self.env().emit_event(CallbackWhitelistUpdated { callee, gas_limit });
+
+            Ok(())
+        }
+
+        /// Get the weight cap of a whitelisted callback contract (None = not whitelisted)
+        #[ink(message)]
+        pub fn callback_gas_limit_of(&self, callee: AccountId) -> Option<u64> {
+            self.callback_gas_limits.get(callee)
+        }
+    }
+
+    // ========== VOTE ESCROW ==========
+    impl SimpleToken {
+        /// Lock tokens until `unlock_time` for voting weight that decays towards it
+        ///
+        /// `unlock_time` must be in the future and at most `MAX_VE_LOCK_DURATION` away.
+        #[ink(message)]
+        pub fn create_lock(&mut self, amount: Balance, unlock_time: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("create_lock"))?;
+
+            let caller = self.env().caller();
+            if self.ve_locks.contains(caller) {
+                return Err(Error::LockAlreadyExists);
+            }
+            self.ensure_valid_unlock_time(unlock_time)?;
+
+            self.settle_ve_rewards(caller);
+            self.debit_unlocked(caller, amount)?;
+            self.ve_locked_total = self.ve_locked_total.checked_add(amount).ok_or(Error::Overflow)?;
+            self.ve_locks.insert(
+                caller,
+                &VeLock {
+                    amount,
+                    end: unlock_time,
+                },
+            );
+
+            self.env().emit_event(VeLockUpdated {
+                account: caller,
+                amount,
+                end: unlock_time,
+            });
+
+            Ok(())
+        }
+
+        /// Add tokens to the caller's running lock
+        #[ink(message)]
+        pub fn increase_amount(&mut self, amount: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("increase_amount"))?;
+
+            let caller = self.env().caller();
+            let mut lock = self.ve_locks.get(caller).ok_or(Error::NoLock)?;
+            if lock.end <= self.env().block_timestamp() {
+                return Err(Error::LockExpired);
+            }
+
+            self.settle_ve_rewards(caller);
+            self.debit_unlocked(caller, amount)?;
+            lock.amount = lock.amount.checked_add(amount).ok_or(Error::Overflow)?;
+            self.ve_locked_total = self.ve_locked_total.checked_add(amount).ok_or(Error::Overflow)?;
+            self.ve_locks.insert(caller, &lock);
+
+            self.env().emit_event(VeLockUpdated {
+                account: caller,
+                amount: lock.amount,
+                end: lock.end,
+            });
+
+            Ok(())
+        }
+
+        /// Push the caller's unlock time further out
+        #[ink(message)]
+        pub fn increase_unlock_time(&mut self, unlock_time: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("increase_unlock_time"))?;
+
+            let caller = self.env().caller();
+            let mut lock = self.ve_locks.get(caller).ok_or(Error::NoLock)?;
+            if lock.end <= self.env().block_timestamp() {
+                return Err(Error::LockExpired);
+            }
+            if unlock_time <= lock.end {
+                return Err(Error::InvalidSchedule);
+            }
+            self.ensure_valid_unlock_time(unlock_time)?;
+
+            lock.end = unlock_time;
+            self.ve_locks.insert(caller, &lock);
+
+            self.env().emit_event(VeLockUpdated {
+                account: caller,
+                amount: lock.amount,
+                end: lock.end,
+            });
+
+            Ok(())
+        }
+
+        /// Take the tokens back once the lock has expired
+        #[ink(message)]
+        pub fn withdraw(&mut self) -> Result<Balance> {
+            self.guard_selector(ink::selector_bytes!("withdraw"))?;
+
+            let caller = self.env().caller();
+            let lock = self.ve_locks.get(caller).ok_or(Error::NoLock)?;
+            if lock.end > self.env().block_timestamp() {
+                return Err(Error::LockNotExpired);
+            }
+
+            self.settle_ve_rewards(caller);
+            self.ve_locks.remove(caller);
+            self.ve_locked_total = self.ve_locked_total.saturating_sub(lock.amount);
+            self.credit(caller, lock.amount)?;
+
+            self.env().emit_event(VeWithdrawn {
+                account: caller,
+                amount: lock.amount,
+            });
+
+            Ok(lock.amount)
+        }
+
+        /// Get an account's current voting weight
+        ///
+        /// `amount * time_left / MAX_VE_LOCK_DURATION`, reaching zero at the unlock time.
+        #[ink(message)]
+        pub fn ve_balance_of(&self, account: AccountId) -> Balance {
+            let Some(lock) = self.ve_locks.get(account) else {
+                return 0;
+            };
+            let remaining = lock.end.saturating_sub(self.env().block_timestamp());
+            Self::mul_div(
+                lock.amount,
+                Balance::from(remaining),
+                Balance::from(MAX_VE_LOCK_DURATION),
+            )
+            .unwrap_or(0)
+        }
+
+        /// Get an account's vote-escrow lock
+        #[ink(message)]
+        pub fn get_ve_lock(&self, account: AccountId) -> Option<VeLock> {
+            self.ve_locks.get(account)
+        }
+
+        /// Get the total amount held in vote-escrow locks
+        #[ink(message)]
+        pub fn ve_locked_total(&self) -> Balance {
+            self.ve_locked_total
+        }
+
+        /// Internal helper that checks an unlock time lies within the allowed lock range
+        fn ensure_valid_unlock_time(&self, unlock_time: u64) -> Result<()> {
+            let now = self.env().block_timestamp();
+            if unlock_time <= now || unlock_time - now > MAX_VE_LOCK_DURATION {
+                return Err(Error::InvalidSchedule);
+            }
+            Ok(())
+        }
+    }
+
+    // ========== AIRDROPS ==========
+    impl SimpleToken {
+        /// Escrow `amount` for a merkle airdrop; returns its ID
+        ///
+        /// `hash` picks the function for leaves and nodes (see `merkle_leaf`); nodes
+        /// hash the sorted concatenation of their children, so keccak trees built by
+        /// EVM tooling can be reused as they are. Sponsored claims may keep up to
+        /// `max_tip_bps` of the allocation as a relayer tip.
+        #[ink(message)]
+        pub fn create_airdrop(
+            &mut self,
+            merkle_root: [u8; 32],
+            hash: MerkleHash,
+            amount: Balance,
+            max_tip_bps: u16,
+        ) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("create_airdrop"))?;
+
+            if Balance::from(max_tip_bps) > BPS_DENOMINATOR {
+                return Err(Error::TipTooHigh);
+            }
+
+            let caller = self.env().caller();
+            self.debit_unlocked(caller, amount)?;
+            self.airdrop_escrow_total = self
+                .airdrop_escrow_total
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+
+            let id = self.next_airdrop_id;
+            self.next_airdrop_id = id.checked_add(1).ok_or(Error::Overflow)?;
+            self.airdrops.insert(
+                id,
+                &Airdrop {
+                    creator: caller,
+                    merkle_root,
+                    hash,
+                    remaining: amount,
+                    max_tip_bps,
+                },
+            );
+
+            self.env().emit_event(AirdropCreated {
+                id,
+                creator: caller,
+                merkle_root,
+                hash,
+                amount,
+            });
+
+            Ok(id)
+        }
+
+        /// Claim the caller's allocation from an airdrop
+        #[ink(message)]
+        pub fn claim_airdrop(&mut self, id: u32, amount: Balance, proof: Vec<[u8; 32]>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("claim_airdrop"))?;
+
+            let caller = self.env().caller();
+            self.take_airdrop_allocation(id, caller, amount, &proof)?;
+            self.credit(caller, amount)?;
+
+            self.env().emit_event(AirdropClaimed {
+                id,
+                account: caller,
+                amount,
+                sponsor: None,
+                tip: 0,
+            });
+
+            Ok(())
+        }
+
+        /// Claim an airdrop allocation on behalf of `account` - only claim sponsors
+        ///
+        /// The sponsor pays the transaction fee and may keep `tip` out of the
+        /// allocation, at most the airdrop's `max_tip_bps`; the rest goes to `account`.
+        #[ink(message)]
+        pub fn claim_for(
+            &mut self,
+            id: u32,
+            account: AccountId,
+            amount: Balance,
+            proof: Vec<[u8; 32]>,
+            tip: Balance,
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("claim_for"))?;
+
+            let sponsor = self.env().caller();
+            if !self.claim_sponsors.contains(sponsor) {
+                return Err(Error::Unauthorized);
+            }
+
+            let airdrop = self.take_airdrop_allocation(id, account, amount, &proof)?;
+            let max_tip = Self::mul_div(amount, Balance::from(airdrop.max_tip_bps), BPS_DENOMINATOR)
+                .ok_or(Error::Overflow)?;
+            if tip > max_tip {
+                return Err(Error::TipTooHigh);
+            }
+
+            self.credit(account, amount.saturating_sub(tip))?;
+            if tip > 0 {
+                self.credit(sponsor, tip)?;
+            }
+
+            self.env().emit_event(AirdropClaimed {
+                id,
+                account,
+                amount,
+                sponsor: Some(sponsor),
+                tip,
+            });
+
+            Ok(())
+        }
+
+        /// Return the unclaimed remainder of an airdrop to its creator - only the creator
+        #[ink(message)]
+        pub fn close_airdrop(&mut self, id: u32) -> Result<Balance> {
+            self.guard_selector(ink::selector_bytes!("close_airdrop"))?;
+
+            let airdrop = self.airdrops.get(id).ok_or(Error::AirdropNotFound)?;
+            if self.env().caller() != airdrop.creator {
+                return Err(Error::Unauthorized);
+            }
+
+            self.airdrops.remove(id);
+            self.airdrop_escrow_total = self
+                .airdrop_escrow_total
+                .checked_sub(airdrop.remaining)
+                .ok_or(Error::Overflow)?;
+            if airdrop.remaining > 0 {
+                self.credit(airdrop.creator, airdrop.remaining)?;
+            }
+
+            self.env().emit_event(AirdropClosed {
+                id,
+                refunded: airdrop.remaining,
+            });
+
+            Ok(airdrop.remaining)
+        }
+
+        /// Allow or disallow a relayer to submit sponsored claims - only owner
+        #[ink(message)]
+        pub fn set_claim_sponsor(&mut self, sponsor: AccountId, allowed: bool) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_claim_sponsor"))?;
+            self.ensure_privileged()?;
+
+            if allowed {
+                self.claim_sponsors.insert(sponsor, &());
+            } else {
+                self.claim_sponsors.remove(sponsor);
+            }
+
+            self.env().emit_event(ClaimSponsorSet { sponsor, allowed });
+
+            Ok(())
+        }
+
+        /// Check whether an account may submit sponsored claims
+        #[ink(message)]
+        pub fn is_claim_sponsor(&self, account: AccountId) -> bool {
+            self.claim_sponsors.contains(account)
+        }
+
+        /// Get an airdrop by ID
+        #[ink(message)]
+        pub fn get_airdrop(&self, id: u32) -> Option<Airdrop> {
+            self.airdrops.get(id)
+        }
+
+        /// Check whether an account has claimed its allocation from an airdrop
+        #[ink(message)]
+        pub fn is_airdrop_claimed(&self, id: u32, account: AccountId) -> bool {
+            self.airdrop_claimed.contains((id, account))
+        }
+
+        /// Check a proof for an allocation against an airdrop's root without claiming
+        #[ink(message)]
+        pub fn verify_airdrop_proof(
+            &self,
+            id: u32,
+            account: AccountId,
+            amount: Balance,
+            proof: Vec<[u8; 32]>,
+        ) -> bool {
+            self.airdrops.get(id).is_some_and(|airdrop| {
+                let leaf = self.merkle_leaf(airdrop.hash, account, amount);
+                self.verify_merkle_proof(airdrop.hash, leaf, &proof, airdrop.merkle_root)
+            })
+        }
+
+        /// Internal helper that verifies an allocation and takes it out of the airdrop escrow
+        fn take_airdrop_allocation(
+            &mut self,
+            id: u32,
+            account: AccountId,
+            amount: Balance,
+            proof: &[[u8; 32]],
+        ) -> Result<Airdrop> {
+            let mut airdrop = self.airdrops.get(id).ok_or(Error::AirdropNotFound)?;
+
+            if self.airdrop_claimed.contains((id, account)) {
+                return Err(Error::AlreadyClaimed);
+            }
+            if self.check_blacklisted(account) {
+                return Err(Error::AccountBlacklisted);
+            }
+
+            let leaf = self.merkle_leaf(airdrop.hash, account, amount);
+            if !self.verify_merkle_proof(airdrop.hash, leaf, proof, airdrop.merkle_root) {
+                return Err(Error::InvalidProof);
+            }
+
+            airdrop.remaining = airdrop
+                .remaining
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientBalance)?;
+            self.airdrop_escrow_total = self
+                .airdrop_escrow_total
+                .checked_sub(amount)
+                .ok_or(Error::Overflow)?;
+            self.airdrops.insert(id, &airdrop);
+            self.airdrop_claimed.insert((id, account), &());
+
+            Ok(airdrop)
+        }
+
+        /// Internal helper: merkle leaf of an `(account, amount)` allocation
+        ///
+        /// Blake2 leaves hash the SCALE encoding; keccak leaves hash the 32-byte
+        /// account followed by the amount as a big-endian uint256. Leaves are
+        /// hashed twice so a 64-byte inner node can never pass as a leaf, matching
+        /// OpenZeppelin's `keccak256(bytes.concat(keccak256(abi.encode(bytes32, uint256))))`.
+        fn merkle_leaf(&self, hash: MerkleHash, account: AccountId, amount: Balance) -> [u8; 32] {
+            match hash {
+                MerkleHash::Blake2x256 => {
+                    let inner = self.env().hash_encoded::<Blake2x256, _>(&(account, amount));
+                    self.env().hash_bytes::<Blake2x256>(&inner)
+                }
+                MerkleHash::Keccak256 => {
+                    let mut packed = [0u8; 64];
+                    packed[..32].copy_from_slice(account.as_ref());
+                    packed[48..].copy_from_slice(&amount.to_be_bytes());
+                    let inner = self.env().hash_bytes::<Keccak256>(&packed);
+                    self.env().hash_bytes::<Keccak256>(&inner)
+                }
+            }
+        }
+
+        /// Internal helper that checks a merkle proof of `leaf` against `root`
+        fn verify_merkle_proof(
+            &self,
+            hash: MerkleHash,
+            leaf: [u8; 32],
+            proof: &[[u8; 32]],
+            root: [u8; 32],
+        ) -> bool {
+            let mut node = leaf;
+            for sibling in proof {
+                let mut pair = [0u8; 64];
+                let (first, second) = if node <= *sibling {
+                    (node, *sibling)
+                } else {
+                    (*sibling, node)
+                };
+                pair[..32].copy_from_slice(&first);
+                pair[32..].copy_from_slice(&second);
+                node = match hash {
+                    MerkleHash::Blake2x256 => self.env().hash_bytes::<Blake2x256>(&pair),
+                    MerkleHash::Keccak256 => self.env().hash_bytes::<Keccak256>(&pair),
+                };
+            }
+            node == root
+        }
+    }
+
+    // ========== CHEQUES ==========
+    impl SimpleToken {
+        /// Cash a cheque `payer` signed off-chain to the caller
+        ///
+        /// `payer` signs `(CHEQUE_TYPE, payer, beneficiary, amount, nonce, expiry)`
+        /// under the domain separator (see `verify_signature`). Cheque nonces are
+        /// single-use but need not be cashed in order.
+        #[ink(message)]
+        pub fn cash_cheque(
+            &mut self,
+            payer: AccountId,
+            amount: Balance,
+            nonce: u64,
+            expiry: u64,
+            signature: [u8; 64],
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("cash_cheque"))?;
+
+            if self.env().block_timestamp() >= expiry {
+                return Err(Error::AuthorizationExpired);
+            }
+
+            let beneficiary = self.env().caller();
+            if self.used_cheques.contains((payer, nonce)) {
+                return Err(Error::InvalidNonce);
+            }
+
+            let payload = (CHEQUE_TYPE, payer, beneficiary, amount, nonce, expiry);
+            self.verify_signature(payer, &payload, &signature)?;
+
+            // Mark the cheque used so it cannot be cashed twice
+            self.mark_cheque_used(payer, nonce)?;
+            self._transfer(payer, beneficiary, amount)?;
+
+            self.env().emit_event(ChequeCashed {
+                payer,
+                beneficiary,
+                amount,
+                nonce,
+            });
+            self.flush_balance_notifications();
+
+            Ok(())
+        }
+
+        /// Cancel a cheque the caller issued but that has not been cashed yet
+        #[ink(message)]
+        pub fn cancel_cheque(&mut self, nonce: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("cancel_cheque"))?;
+
+            let payer = self.env().caller();
+            if self.used_cheques.contains((payer, nonce)) {
+                return Err(Error::InvalidNonce);
+            }
+            self.mark_cheque_used(payer, nonce)?;
+
+            self.env().emit_event(ChequeCancelled { payer, nonce });
+
+            Ok(())
+        }
+
+        /// Check whether a payer's cheque nonce has been cashed or cancelled
+        #[ink(message)]
+        pub fn is_cheque_used(&self, payer: AccountId, nonce: u64) -> bool {
+            self.used_cheques.contains((payer, nonce))
+        }
+
+        /// Internal helper that retires a cheque nonce and counts it in the cheque nonce space
+        fn mark_cheque_used(&mut self, payer: AccountId, nonce: u64) -> Result<()> {
+            self.used_cheques.insert((payer, nonce), &());
+            let used = self.nonces.get((payer, NonceKind::Cheque)).unwrap_or(0);
+            self.nonces.insert(
+                (payer, NonceKind::Cheque),
+                &used.checked_add(1).ok_or(Error::Overflow)?,
+            );
+            Ok(())
+        }
+    }
+
+    // ========== DIVIDENDS ==========
+    impl SimpleToken {
+        /// Deposit the attached native currency as a dividend - only owner
+        ///
+        /// Takes a balance snapshot; holders claim `amount * balance / supply` of it
+        /// by their snapshot balance until `expires_at`, after which the owner can
+        /// sweep what is left. Tokens held by this contract do not share in it.
+        /// A `DepositDividend` proposal pays it out of native currency the contract
+        /// already holds instead.
+        #[ink(message, payable)]
+        pub fn deposit_dividend(&mut self, expires_at: u64) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("deposit_dividend"))?;
+            self.ensure_privileged()?;
+            self.start_dividend(self.env().transferred_value(), expires_at)
+        }
+
+        /// Internal helper that opens a dividend of `amount` already held by the contract
+        fn start_dividend(&mut self, amount: Balance, expires_at: u64) -> Result<u32> {
+            if amount == 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if expires_at <= self.env().block_timestamp() {
+                return Err(Error::InvalidSchedule);
+            }
+
+            let snapshot_id = self.take_snapshot()?;
+            let eligible_supply = self
+                .circulating_in_balances()
+                .saturating_sub(self.balances.get(self.env().account_id()).unwrap_or(0));
+
+            let id = self.next_dividend_id;
+            self.next_dividend_id = id.checked_add(1).ok_or(Error::Overflow)?;
+            self.dividends.insert(
+                id,
+                &Dividend {
+                    snapshot_id,
+                    amount,
+                    eligible_supply,
+                    claimed: 0,
+                    expires_at,
+                },
+            );
+            self.dividend_reserve = self.dividend_reserve.checked_add(amount).ok_or(Error::Overflow)?;
+
+            self.env().emit_event(DividendDeposited {
+                id,
+                snapshot_id,
+                amount,
+                expires_at,
+            });
+
+            Ok(id)
+        }
+
+        /// Claim the caller's share of a dividend in native currency
+        #[ink(message)]
+        pub fn claim_dividend(&mut self, id: u32) -> Result<Balance> {
+            self.guard_selector(ink::selector_bytes!("claim_dividend"))?;
+
+            let caller = self.env().caller();
+            let mut dividend = self.dividends.get(id).ok_or(Error::DividendNotFound)?;
+
+            if self.env().block_timestamp() >= dividend.expires_at {
+                return Err(Error::DividendExpired);
+            }
+            if self.dividend_claimed.contains((id, caller)) {
+                return Err(Error::AlreadyClaimed);
+            }
+
+            let share = self.dividend_share(&dividend, caller);
+            if share == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            self.dividend_claimed.insert((id, caller), &());
+            dividend.claimed = dividend.claimed.checked_add(share).ok_or(Error::Overflow)?;
+            self.dividends.insert(id, &dividend);
+            self.dividend_reserve = self.dividend_reserve.saturating_sub(share);
+
+            self.env()
+                .transfer(caller, share)
+                .map_err(|_| Error::NativeTransferFailed)?;
+
+            self.env().emit_event(DividendClaimed {
+                id,
+                account: caller,
+                amount: share,
+            });
+
+            Ok(share)
+        }
+
+        /// Send the unclaimed rest of an expired dividend to `to` - only owner
+        #[ink(message)]
+        pub fn sweep_dividend(&mut self, id: u32, to: AccountId) -> Result<Balance> {
+            self.guard_selector(ink::selector_bytes!("sweep_dividend"))?;
+            self.ensure_privileged()?;
+
+            let dividend = self.dividends.get(id).ok_or(Error::DividendNotFound)?;
+            if self.env().block_timestamp() < dividend.expires_at {
+                return Err(Error::DividendNotExpired);
+            }
+
+            let unclaimed = dividend.amount.saturating_sub(dividend.claimed);
+            self.dividends.remove(id);
+            self.dividend_reserve = self.dividend_reserve.saturating_sub(unclaimed);
+
+            if unclaimed > 0 {
+                self.env()
+                    .transfer(to, unclaimed)
+                    .map_err(|_| Error::NativeTransferFailed)?;
+            }
+
+            self.env().emit_event(DividendSwept {
+                id,
+                to,
+                amount: unclaimed,
+            });
+
+            Ok(unclaimed)
+        }
+
+        /// Get a dividend by ID
+        #[ink(message)]
+        pub fn get_dividend(&self, id: u32) -> Option<Dividend> {
+            self.dividends.get(id)
+        }
+
+        /// Get what an account can still claim from a dividend
+        #[ink(message)]
+        pub fn claimable_dividend(&self, id: u32, account: AccountId) -> Balance {
+            match self.dividends.get(id) {
+                Some(dividend)
+                    if !self.dividend_claimed.contains((id, account))
+                        && self.env().block_timestamp() < dividend.expires_at =>
+                {
+                    self.dividend_share(&dividend, account)
+                }
+                _ => 0,
+            }
+        }
+
+        /// Get an account's balance as of a snapshot
+        #[ink(message)]
+        pub fn balance_at(&self, snapshot_id: u32, account: AccountId) -> Balance {
+            if snapshot_id == 0 || snapshot_id > self.snapshot_id {
+                return 0;
+            }
+
+            // Walk back through the account's records to the earliest one at or after the snapshot
+            let mut recorded = self.last_snapshot_recorded.get(account).unwrap_or(0);
+            if recorded < snapshot_id {
+                return self.balances.get(account).unwrap_or(0);
+            }
+            loop {
+                let (balance, previous) = self
+                    .snapshot_balances
+                    .get((recorded, account))
+                    .unwrap_or_default();
+                if previous < snapshot_id {
+                    return balance;
+                }
+                recorded = previous;
+            }
+        }
+
+        /// Get the latest snapshot ID (0 = none taken yet)
+        #[ink(message)]
+        pub fn current_snapshot_id(&self) -> u32 {
+            self.snapshot_id
+        }
+
+        /// Internal helper that starts a new balance snapshot and returns its ID
+        fn take_snapshot(&mut self) -> Result<u32> {
+            self.snapshot_id = self.snapshot_id.checked_add(1).ok_or(Error::Overflow)?;
+            self.env().emit_event(SnapshotTaken {
+                id: self.snapshot_id,
+                total_supply: self.total_supply,
+            });
+            Ok(self.snapshot_id)
+        }
+
+        /// Internal helper that saves an account's balance for the latest snapshot before it changes
+        fn record_snapshot_balance(&mut self, account: AccountId) {
+            let id = self.snapshot_id;
+            if id == 0 {
+                return;
+            }
+            let previous = self.last_snapshot_recorded.get(account).unwrap_or(0);
+            if previous == id {
+                return;
+            }
+            let balance = self.balances.get(account).unwrap_or(0);
+            self.snapshot_balances.insert((id, account), &(balance, previous));
+            self.last_snapshot_recorded.insert(account, &id);
+        }
+
+        /// Internal helper: an account's pro-rata share of a dividend
+        fn dividend_share(&self, dividend: &Dividend, account: AccountId) -> Balance {
+            if account == self.env().account_id() {
+                return 0;
+            }
+            let balance = self.balance_at(dividend.snapshot_id, account);
+            Self::mul_div(dividend.amount, balance, dividend.eligible_supply).unwrap_or(0)
+        }
+    }
+
+    // ========== VOTE-ESCROW REWARDS ==========
+    impl SimpleToken {
+        /// Set the PSP22 token paid as a second locker reward and the account it is
+        /// pulled from - only owner
+        ///
+        /// The rewards account must approve this contract on `token`. The token can
+        /// only be changed once all rewards in the previous one have been claimed.
+        #[ink(message)]
+        pub fn set_reward_token(
+            &mut self,
+            token: Option<AccountId>,
+            rewards_account: Option<AccountId>,
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_reward_token"))?;
+            self.ensure_privileged()?;
+
+            if token != self.reward_token && self.ve_rewards_outstanding.1 > 0 {
+                return Err(Error::RewardsOutstanding);
+            }
+
+            self.reward_token = token;
+            self.rewards_account = rewards_account;
+
+            self.env().emit_event(RewardTokenSet {
+                token,
+                rewards_account,
+            });
+
+            Ok(())
+        }
+
+        /// Fund locker rewards with the attached native currency and/or `token_amount`
+        /// of the reward token - only owner
+        ///
+        /// Rewards are shared by locked amount among current lockers; the reward
+        /// token is pulled from the rewards account with `PSP22::transfer_from`.
+        /// A `FundVeRewards` proposal takes the native part from currency the
+        /// contract already holds instead.
+        #[ink(message, payable)]
+        pub fn fund_ve_rewards(&mut self, token_amount: Balance) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("fund_ve_rewards"))?;
+            self.ensure_privileged()?;
+            self.add_ve_rewards(self.env().transferred_value(), token_amount)
+        }
+
+        /// Internal helper that shares `native_amount` (already held by the contract)
+        /// and `token_amount` of the reward token among current lockers
+        fn add_ve_rewards(&mut self, native_amount: Balance, token_amount: Balance) -> Result<()> {
+            if (native_amount == 0 && token_amount == 0) || self.ve_locked_total == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            if token_amount > 0 {
+                let token = self.reward_token.ok_or(Error::NoRewardToken)?;
+                let source = self.rewards_account.ok_or(Error::NoRewardToken)?;
+                self.call_psp22_transfer_from(token, source, self.env().account_id(), token_amount)?;
+            }
+
+            let native_increment = Self::mul_div(native_amount, REFLECTION_PRECISION, self.ve_locked_total)
+                .ok_or(Error::Overflow)?;
+            let token_increment = Self::mul_div(token_amount, REFLECTION_PRECISION, self.ve_locked_total)
+                .ok_or(Error::Overflow)?;
+            let (native_index, token_index) = self.ve_reward_index;
+            self.ve_reward_index = (
+                native_index.checked_add(native_increment).ok_or(Error::Overflow)?,
+                token_index.checked_add(token_increment).ok_or(Error::Overflow)?,
+            );
+            let (native_outstanding, token_outstanding) = self.ve_rewards_outstanding;
+            self.ve_rewards_outstanding = (
+                native_outstanding.checked_add(native_amount).ok_or(Error::Overflow)?,
+                token_outstanding.checked_add(token_amount).ok_or(Error::Overflow)?,
+            );
+
+            self.env().emit_event(VeRewardsFunded {
+                native_amount,
+                token_amount,
+            });
+
+            Ok(())
+        }
+
+        /// Claim the caller's locker rewards; returns (native, reward token) paid
+        #[ink(message)]
+        pub fn claim_ve_rewards(&mut self) -> Result<(Balance, Balance)> {
+            self.guard_selector(ink::selector_bytes!("claim_ve_rewards"))?;
+
+            let caller = self.env().caller();
+            self.settle_ve_rewards(caller);
+            let (native_amount, token_amount) = self.ve_rewards_owed.get(caller).unwrap_or_default();
+            if native_amount == 0 && token_amount == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            self.ve_rewards_owed.remove(caller);
+            let (native_outstanding, token_outstanding) = self.ve_rewards_outstanding;
+            self.ve_rewards_outstanding = (
+                native_outstanding.saturating_sub(native_amount),
+                token_outstanding.saturating_sub(token_amount),
+            );
+
+            if native_amount > 0 {
+                self.env()
+                    .transfer(caller, native_amount)
+                    .map_err(|_| Error::NativeTransferFailed)?;
+            }
+            if token_amount > 0 {
+                let token = self.reward_token.ok_or(Error::NoRewardToken)?;
+                self.call_psp22_transfer(token, caller, token_amount)?;
+            }
+
+            self.env().emit_event(VeRewardsClaimed {
+                account: caller,
+                native_amount,
+                token_amount,
+            });
+
+            Ok((native_amount, token_amount))
+        }
+
+        /// Get a locker's unclaimed rewards as (native, reward token)
+        #[ink(message)]
+        pub fn pending_ve_rewards(&self, account: AccountId) -> (Balance, Balance) {
+            self.accrued_ve_rewards(account)
+        }
+
+        /// Get the reward token configuration: (token, rewards account)
+        #[ink(message)]
+        pub fn reward_token(&self) -> (Option<AccountId>, Option<AccountId>) {
+            (self.reward_token, self.rewards_account)
+        }
+
+        /// Internal helper: a locker's owed plus newly accrued rewards as (native, reward token)
+        fn accrued_ve_rewards(&self, account: AccountId) -> (Balance, Balance) {
+            let (owed_native, owed_token) = self.ve_rewards_owed.get(account).unwrap_or_default();
+            let Some(lock) = self.ve_locks.get(account) else {
+                return (owed_native, owed_token);
+            };
+            let (checkpoint_native, checkpoint_token) =
+                self.ve_reward_checkpoints.get(account).unwrap_or_default();
+            let (index_native, index_token) = self.ve_reward_index;
+
+            let native = Self::mul_div(
+                lock.amount,
+                index_native.saturating_sub(checkpoint_native),
+                REFLECTION_PRECISION,
+            )
+            .unwrap_or(0);
+            let token = Self::mul_div(
+                lock.amount,
+                index_token.saturating_sub(checkpoint_token),
+                REFLECTION_PRECISION,
+            )
+            .unwrap_or(0);
+            (owed_native.saturating_add(native), owed_token.saturating_add(token))
+        }
+
+        /// Internal helper that books a locker's accrued rewards before its lock changes
+        fn settle_ve_rewards(&mut self, account: AccountId) {
+            let owed = self.accrued_ve_rewards(account);
+            if owed != (0, 0) {
+                self.ve_rewards_owed.insert(account, &owed);
+            }
+            self.ve_reward_checkpoints.insert(account, &self.ve_reward_index);
+        }
+    }
+
+    // ========== SNAPSHOTS ==========
+    impl SimpleToken {
+        /// Take a balance snapshot for governance or airdrop tooling - only owner
+        #[ink(message)]
+        pub fn snapshot(&mut self) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("snapshot"))?;
+            self.ensure_privileged()?;
+            self.take_snapshot()
+        }
+
+        /// Get one page of holder balances at a snapshot
+        ///
+        /// Pages run over current holders, then accounts that have left since the
+        /// first snapshot; `offset` and `limit` count those entries, and accounts with
+        /// nothing at the snapshot are left out, so a page can be shorter than
+        /// `limit`. Export all pages at the same block.
+        #[ink(message)]
+        pub fn export_snapshot(&self, snapshot_id: u32, offset: u32, limit: u32) -> Vec<(AccountId, Balance)> {
+            if snapshot_id == 0 || snapshot_id > self.snapshot_id {
+                return Vec::new();
+            }
+
+            let end = offset
+                .saturating_add(limit)
+                .min(self.holder_count.saturating_add(self.former_holder_count));
+            (offset..end)
+                .filter_map(|position| {
+                    if position < self.holder_count {
+                        self.holders.get(position)
+                    } else {
+                        self.former_holders
+                            .get(position - self.holder_count)
+                            .filter(|account| !self.holder_index.contains(*account))
+                    }
+                })
+                .map(|account| (account, self.balance_at(snapshot_id, account)))
+                .filter(|(_, balance)| *balance > 0)
+                .collect()
+        }
+
+        /// Get the number of entries `export_snapshot` pages over
+        #[ink(message)]
+        pub fn snapshot_export_len(&self) -> u32 {
+            self.holder_count.saturating_add(self.former_holder_count)
+        }
+    }
+
+    // ========== ACCOUNT LABELS ==========
+    impl SimpleToken {
+        /// Tag an account with a short label, or clear it with `None` - only LABELER role
+        #[ink(message)]
+        pub fn set_account_label(&mut self, account: AccountId, label: Option<Vec<u8>>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_account_label"))?;
+            self.ensure_role(LABELER)?;
+
+            // Validate: Labels are short tags, not free text
+            if label.as_ref().is_some_and(|label| label.is_empty() || label.len() > MAX_LABEL_LEN) {
+                return Err(Error::InvalidLabel);
+            }
+
+            if let Some(label) = &label {
+                self.account_labels.insert(account, label);
+            } else {
+                self.account_labels.remove(account);
+            }
+
+            self.env().emit_event(AccountLabelSet {
+                account,
+                label,
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Get an account's label
+        #[ink(message)]
+        pub fn label_of(&self, account: AccountId) -> Option<Vec<u8>> {
+            self.account_labels.get(account)
+        }
+
+        /// Check whether an account carries a given label
+        #[ink(message)]
+        pub fn has_label(&self, account: AccountId, label: Vec<u8>) -> bool {
+            self.account_labels.get(account).is_some_and(|stored| stored == label)
+        }
+    }
+
+    // ========== SEIZURES ==========
+    impl SimpleToken {
+        /// Seize tokens from an account into escrow - only COMPLIANCE
+        ///
+        /// Ignores freezes, blacklisting and vesting. The tokens can only be burned
+        /// or redistributed after `SEIZURE_DISPUTE_WINDOW`; until then APPEALS can
+        /// reverse the seizure.
+        #[ink(message)]
+        pub fn seize(&mut self, account: AccountId, amount: Balance, reason: Vec<u8>) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("seize"))?;
+            self.ensure_role(COMPLIANCE)?;
+
+            if amount == 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if reason.len() > MAX_BURN_REASON_LEN {
+                return Err(Error::ReasonTooLong);
+            }
+
+            self.settle_reflection(account)?;
+            let balance = self.balances.get(account).unwrap_or(0);
+            if balance < amount {
+                return Err(Error::InsufficientBalance);
+            }
+            self.write_balance(account, balance - amount);
+            // The escrow is held by this contract until the case is resolved
+            self.emit_transfer(account, self.env().account_id(), amount);
+            self.seized_escrow_total = self
+                .seized_escrow_total
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+
+            let now = self.env().block_timestamp();
+            let dispute_ends = now.saturating_add(SEIZURE_DISPUTE_WINDOW);
+            let id = self.next_seizure_id;
+            self.next_seizure_id = id.checked_add(1).ok_or(Error::Overflow)?;
+            self.seizures.insert(
+                id,
+                &SeizureCase {
+                    account,
+                    amount,
+                    reason: reason.clone(),
+                    seized_at: now,
+                    dispute_ends,
+                    status: SeizureStatus::Open,
+                },
+            );
+
+            self.env().emit_event(TokensSeized {
+                id,
+                account,
+                amount,
+                reason,
+                dispute_ends,
+            });
+            self.notify_balance_change(account);
+            self.flush_balance_notifications();
+
+            Ok(id)
+        }
+
+        /// Reverse a seizure during its dispute window, returning the tokens - only APPEALS
+        #[ink(message)]
+        pub fn reverse_seizure(&mut self, id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("reverse_seizure"))?;
+            self.ensure_role(APPEALS)?;
+
+            let case = self.open_seizure(id)?;
+            if self.env().block_timestamp() >= case.dispute_ends {
+                return Err(Error::DisputeWindowClosed);
+            }
+
+            self.close_seizure(id, case.clone(), SeizureStatus::Reversed)?;
+            self.credit(case.account, case.amount)?;
+            self.emit_transfer(self.env().account_id(), case.account, case.amount);
+
+            Ok(())
+        }
+
+        /// Burn seized tokens once the dispute window is over - only COMPLIANCE
+        #[ink(message)]
+        pub fn burn_seized(&mut self, id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("burn_seized"))?;
+            self.ensure_role(COMPLIANCE)?;
+
+            let case = self.open_seizure(id)?;
+            if self.env().block_timestamp() < case.dispute_ends {
+                return Err(Error::DisputeWindowOpen);
+            }
+
+            self.close_seizure(id, case.clone(), SeizureStatus::Burned)?;
+            self.total_supply = self
+                .total_supply
+                .checked_sub(case.amount)
+                .ok_or(Error::Overflow)?;
+            self.total_burned = self.total_burned.saturating_add(case.amount);
+
+            self.env().emit_event(Burned {
+                from: self.env().account_id(),
+                amount: case.amount,
+                timestamp: self.env().block_timestamp(),
+                reason: b"seizure".to_vec(),
+                total_supply: self.total_supply,
+                ref_id: self.active_ref_id,
+            });
+
+            Ok(())
+        }
+
+        /// Send seized tokens to `to` once the dispute window is over - only COMPLIANCE
+        #[ink(message)]
+        pub fn redistribute_seized(&mut self, id: u32, to: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("redistribute_seized"))?;
+            self.ensure_role(COMPLIANCE)?;
+
+            let case = self.open_seizure(id)?;
+            if self.env().block_timestamp() < case.dispute_ends {
+                return Err(Error::DisputeWindowOpen);
+            }
+
+            self.close_seizure(id, case.clone(), SeizureStatus::Redistributed { to })?;
+            self.credit(to, case.amount)?;
+            self.emit_transfer(self.env().account_id(), to, case.amount);
+
+            Ok(())
+        }
+
+        /// Get a seizure case by ID
+        #[ink(message)]
+        pub fn get_seizure(&self, id: u32) -> Option<SeizureCase> {
+            self.seizures.get(id)
+        }
+
+        /// Get the number of seizure cases ever opened
+        #[ink(message)]
+        pub fn seizure_count(&self) -> u32 {
+            self.next_seizure_id
+        }
+
+        /// Get the total amount of seized tokens still in escrow
+        #[ink(message)]
+        pub fn seized_escrow_total(&self) -> Balance {
+            self.seized_escrow_total
+        }
+    }
+
+    // ========== SUPPLY ATTESTATION ==========
+    impl SimpleToken {
+        /// Emit a `SupplyAttested` checkpoint of the supply breakdown - owner or KEEPER role
+        ///
+        /// Staked covers the savings vault and vote-escrow locks; locked covers
+        /// burn, HTLC, swap, airdrop and seizure escrows.
+        #[ink(message)]
+        pub fn attest_supply(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("attest_supply"))?;
+
+            let caller = self.env().caller();
+            if caller != self.owner && !self.check_role(KEEPER, caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            let total_staked = self.vault_assets.saturating_add(self.ve_locked_total);
+            let total_locked = self
+                .burn_escrow_total
+                .saturating_add(self.htlc_escrow_total)
+                .saturating_add(self.swap_escrow_total)
+                .saturating_add(self.airdrop_escrow_total)
+                .saturating_add(self.seized_escrow_total);
+
+            self.env().emit_event(SupplyAttested {
+                by: caller,
+                total_supply: self.total_supply,
+                total_staked,
+                total_locked,
+                treasury: self.treasury_balance(),
+                block: self.env().block_number(),
+                timestamp: self.env().block_timestamp(),
+            });
+
+            Ok(())
+        }
+    }
+
+    // ========== MONITORING ==========
+    impl SimpleToken {
+        /// Set the amount from which transfers also emit `LargeTransfer` - only owner
+        #[ink(message)]
+        pub fn set_large_transfer_threshold(&mut self, threshold: Option<Balance>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_large_transfer_threshold"))?;
+            self.ensure_privileged()?;
+
+            self.large_transfer_threshold = threshold;
+            self.env().emit_event(LargeTransferThresholdSet { threshold });
+
+            Ok(())
+        }
+
+        /// Get the large-transfer alert threshold
+        #[ink(message)]
+        pub fn large_transfer_threshold(&self) -> Option<Balance> {
+            self.large_transfer_threshold
+        }
+    }
+
+    // ========== OWNERSHIP HANDOVER ==========
+    impl SimpleToken {
+        /// Get the scheduled ownership handover, if any
+        #[ink(message)]
+        pub fn pending_ownership_transfer(&self) -> Option<PendingOwnershipTransfer> {
+            self.pending_ownership_transfer.clone()
+        }
+
+        /// Require contract owners to answer `accepts_ownership()` - only owner can do this
+        ///
+        /// Stops handovers to DAOs or timelocks that could not exercise ownership.
+        #[ink(message)]
+        pub fn set_ownership_probe_required(&mut self, required: bool) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_ownership_probe_required"))?;
+            self.ensure_privileged()?;
+
+            self.ownership_probe_required = required;
+
+            Ok(())
+        }
+
+        /// Check whether contract owners must confirm they accept ownership
+        #[ink(message)]
+        pub fn ownership_probe_required(&self) -> bool {
+            self.ownership_probe_required
+        }
+    }
+
+    // ========== SOCIAL RECOVERY ==========
+    impl SimpleToken {
+        /// Register recovery guardians - only owner can do this
+        ///
+        /// `threshold` guardians must approve the same new owner within `window`
+        /// ms; ownership then moves `delay` ms later unless the owner cancels.
+        #[ink(message)]
+        pub fn set_recovery_guardians(
+            &mut self,
+            guardians: Vec<AccountId>,
+            threshold: u32,
+            window: u64,
+            delay: u64,
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("set_recovery_guardians"))?;
+            self.ensure_privileged()?;
+
+            // Validate: threshold 0 disables recovery, otherwise it must be reachable
+            if threshold as usize > guardians.len() {
+                return Err(Error::InvalidThreshold);
+            }
+
+            // A new guardian set invalidates any recovery in progress
+            self.recovery = None;
+            self.recovery_guardians = guardians.clone();
+            self.recovery_threshold = threshold;
+            self.recovery_window = window;
+            self.recovery_delay = delay;
+
+            self.env().emit_event(RecoveryGuardiansSet {
+                guardians,
+                threshold,
+                window,
+                delay,
+            });
+
+            Ok(())
+        }
+
+        /// Approve moving ownership to `new_owner` - only recovery guardians
+        #[ink(message)]
+        pub fn recover_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("recover_ownership"))?;
+
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+
+            // Validate: Only recovery guardians can approve
+            if self.recovery_threshold == 0 || !self.recovery_guardians.contains(&caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            // Continue the current round, or start a new one if none is live
+            let mut request = match self.recovery.clone() {
+                Some(request)
+                    if request.approved_at.is_some()
+                        || now <= request.started_at.saturating_add(self.recovery_window) =>
+                {
+                    if request.new_owner != new_owner {
+                        return Err(Error::RecoveryInProgress);
+                    }
+                    request
+                }
+                _ => {
+                    let round = self.recovery_round.checked_add(1).ok_or(Error::Overflow)?;
+                    self.recovery_round = round;
+                    self.env().emit_event(RecoveryStarted {
+                        round,
+                        new_owner,
+                        by: caller,
+                    });
+                    RecoveryRequest {
+                        round,
+                        new_owner,
+                        started_at: now,
+                        approvals: 0,
+                        approved_at: None,
+                    }
+                }
+            };
+
+            if self
+                .recovery_approvals
+                .get((request.round, caller))
+                .unwrap_or(false)
+            {
+                return Err(Error::AlreadyConfirmed);
+            }
+
+            self.recovery_approvals.insert((request.round, caller), &true);
+            request.approvals = request.approvals.checked_add(1).ok_or(Error::Overflow)?;
+
+            self.env().emit_event(RecoveryApproval {
+                round: request.round,
+                by: caller,
+                approvals: request.approvals,
+            });
+
+            // Start the delay once enough guardians agreed
+            if request.approved_at.is_none() && request.approvals >= self.recovery_threshold {
+                request.approved_at = Some(now);
+                self.env().emit_event(RecoveryApproved {
+                    round: request.round,
+                    new_owner,
+                    executable_at: now.saturating_add(self.recovery_delay),
+                });
+            }
+
+            self.recovery = Some(request);
+
+            Ok(())
+        }
+
+        /// Move ownership once an approved recovery's delay has passed - anyone can call
+        #[ink(message)]
+        pub fn finalize_recovery(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("finalize_recovery"))?;
+
+            let request = self.recovery.clone().ok_or(Error::NoActiveRecovery)?;
+            let approved_at = request.approved_at.ok_or(Error::RecoveryNotReady)?;
+
+            // Validate: The owner must get the full delay to cancel
+            if self.env().block_timestamp() < approved_at.saturating_add(self.recovery_delay) {
+                return Err(Error::RecoveryNotReady);
+            }
+
+            let previous_owner = self.owner;
+            self.set_owner(request.new_owner);
+
+            self.env().emit_event(OwnershipRecovered {
+                previous_owner,
+                new_owner: request.new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Cancel a recovery in progress - only owner can do this
+        #[ink(message)]
+        pub fn cancel_recovery(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("cancel_recovery"))?;
+            self.ensure_privileged()?;
+
+            let request = self.recovery.take().ok_or(Error::NoActiveRecovery)?;
+            self.env().emit_event(RecoveryCancelled {
+                round: request.round,
+            });
+
+            Ok(())
+        }
+
+        /// Get the recovery guardians
+        #[ink(message)]
+        pub fn recovery_guardians(&self) -> Vec<AccountId> {
+            self.recovery_guardians.clone()
+        }
+
+        /// Get the recovery currently in progress, if any
+        #[ink(message)]
+        pub fn pending_recovery(&self) -> Option<RecoveryRequest> {
+            self.recovery.clone()
+        }
+    }
+
+    // ========== DEAD-MAN SWITCH ==========
+    impl SimpleToken {
+        /// Configure the dead-man switch - only owner can do this
+        ///
+        /// If the owner goes `interval` ms without calling `heartbeat`, the
+        /// successor can claim ownership. Configuring counts as a heartbeat.
+        #[ink(message)]
+        pub fn configure_dead_man_switch(
+            &mut self,
+            successor: Option<AccountId>,
+            interval: u64,
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("configure_dead_man_switch"))?;
+            self.ensure_privileged()?;
+
+            self.successor = successor;
+            self.heartbeat_interval = interval;
+            self.last_heartbeat = self.env().block_timestamp();
+
+            self.env().emit_event(DeadManSwitchConfigured { successor, interval });
+
+            Ok(())
+        }
+
+        /// Prove the owner is still active - only owner can do this
+        #[ink(message)]
+        pub fn heartbeat(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("heartbeat"))?;
+            self.ensure_privileged()?;
+
+            let timestamp = self.env().block_timestamp();
+            self.last_heartbeat = timestamp;
+
+            self.env().emit_event(Heartbeat {
+                owner: self.owner,
+                timestamp,
+            });
+
+            Ok(())
+        }
+
+        /// Take over ownership after the owner missed their heartbeat - only successor
+        #[ink(message)]
+        pub fn claim_ownership(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("claim_ownership"))?;
+
+            let caller = self.env().caller();
+
+            // Validate: Only the designated successor of an enabled switch can claim
+            if self.heartbeat_interval == 0 || self.successor != Some(caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            // Validate: Owner must have been silent for the whole interval
+            let deadline = self.last_heartbeat.saturating_add(self.heartbeat_interval);
+            if self.env().block_timestamp() <= deadline {
+                return Err(Error::OwnerStillActive);
+            }
+
+            let previous_owner = self.owner;
+            // The switch is one-shot; the new owner can configure a fresh one
+            self.set_owner(caller);
+
+            self.env().emit_event(OwnershipClaimed {
+                previous_owner,
+                new_owner: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Get the successor, heartbeat interval and last heartbeat time
+        #[ink(message)]
+        pub fn dead_man_switch(&self) -> (Option<AccountId>, u64, u64) {
+            (self.successor, self.heartbeat_interval, self.last_heartbeat)
+        }
+    }
+
+    // ========== EMISSION SCHEDULE ==========
+    impl SimpleToken {
+        /// Configure the emission schedule - only owner can do this
+        ///
+        /// Emission accrues from the current block; pass `None` as distributor
+        /// to switch it off. With a non-zero `halving_interval` the per-era
+        /// amount halves every `halving_interval` blocks.
+        #[ink(message)]
+        pub fn configure_emission(
+            &mut self,
+            distributor: Option<AccountId>,
+            amount_per_era: Balance,
+            era_length: BlockNumber,
+            halving_interval: BlockNumber,
+        ) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("configure_emission"))?;
+            self.ensure_privileged()?;
+
+            // Validate: An active schedule needs a non-empty era
+            if distributor.is_some() && era_length == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            self.emission_distributor = distributor;
+            self.emission_per_era = amount_per_era;
+            self.emission_era_length = era_length;
+            self.emission_halving_interval = halving_interval;
+            self.emission_start = self.env().block_number();
+            self.emission_eras_dripped = 0;
+
+            self.env().emit_event(EmissionConfigured {
+                distributor,
+                amount_per_era,
+                era_length,
+                halving_interval,
+            });
+
+            Ok(())
+        }
+
+        /// Mint all emission accrued since the last drip to the distributor - anyone can call
+        #[ink(message)]
+        pub fn drip(&mut self) -> Result<Balance> {
+            self.guard_selector(ink::selector_bytes!("drip"))?;
+            self.ensure_no_emergency()?;
+
+            let distributor = self.emission_distributor.ok_or(Error::EmissionDisabled)?;
+            let completed = self.completed_eras_at(self.env().block_number());
+            let eras = completed.saturating_sub(self.emission_eras_dripped);
+            if eras == 0 {
+                return Err(Error::NothingToDrip);
+            }
+
+            let amount = self.emission_for_eras(self.emission_eras_dripped, completed);
+
+            // Only consume whole eras so partial progress carries over
+            self.emission_eras_dripped = completed;
+
+            // Once emission has fully decayed there is nothing left to mint
+            if amount == 0 {
+                return Err(Error::NothingToDrip);
+            }
+
+            self._mint(distributor, amount)?;
+
+            self.env().emit_event(EmissionDripped {
+                to: distributor,
+                amount,
+                eras,
+            });
+            self.flush_balance_notifications();
+
+            Ok(amount)
+        }
+
+        /// Get the amount `drip` would mint right now
+        #[ink(message)]
+        pub fn pending_emission(&self) -> Balance {
+            if self.emission_distributor.is_none() {
+                return 0;
+            }
+            let completed = self.completed_eras_at(self.env().block_number());
+            self.emission_for_eras(self.emission_eras_dripped, completed)
+        }
+
+        /// Get the per-era emission that applies at the current block
+        #[ink(message)]
+        pub fn current_emission_rate(&self) -> Balance {
+            if self.emission_distributor.is_none() {
+                return 0;
+            }
+            self.era_emission(self.completed_eras_at(self.env().block_number()))
+        }
+
+        /// Get the total supply once all emission up to `block` has been dripped
+        ///
+        /// Only accounts for the emission schedule, not for other mints or burns.
+        #[ink(message)]
+        pub fn projected_supply(&self, block: BlockNumber) -> Balance {
+            if self.emission_distributor.is_none() {
+                return self.total_supply;
+            }
+            let completed = self.completed_eras_at(block);
+            self.total_supply
+                .saturating_add(self.emission_for_eras(self.emission_eras_dripped, completed))
+        }
+    }
+
+    // ========== SUPPLY CAP ==========
+    impl SimpleToken {
+        /// Queue a new supply cap - only owner can do this
+        ///
+        /// The change can only be applied after `CAP_CHANGE_DELAY`, giving
+        /// holders time to react. Queuing replaces any earlier pending change.
+        #[ink(message)]
+        pub fn queue_cap_change(&mut self, new_cap: Option<Balance>) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("queue_cap_change"))?;
+            self.ensure_privileged()?;
+
+            // Validate: Cap cannot be below what already exists
+            if let Some(cap) = new_cap {
+                if cap < self.total_supply {
+                    return Err(Error::CapExceeded);
+                }
+            }
+
+            let effective_at = self
+                .env()
+                .block_timestamp()
+                .saturating_add(CAP_CHANGE_DELAY);
+            self.pending_cap_change = Some(PendingCapChange {
+                new_cap,
+                effective_at,
+            });
+
+            self.env().emit_event(CapChangeQueued {
+                new_cap,
+                effective_at,
+            });
+
+            Ok(())
+        }
+
+        /// Apply the queued cap change once its delay has passed - anyone can call
+        #[ink(message)]
+        pub fn apply_cap_change(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("apply_cap_change"))?;
+
+            let pending = self
+                .pending_cap_change
+                .clone()
+                .ok_or(Error::NoPendingCapChange)?;
+
+            if self.env().block_timestamp() < pending.effective_at {
+                return Err(Error::CapChangeNotReady);
+            }
+
+            // Validate: Supply may have grown since the change was queued
+            if let Some(cap) = pending.new_cap {
+                if cap < self.total_supply {
+                    return Err(Error::CapExceeded);
+                }
+            }
+
+            let old_cap = self.max_supply;
+            self.max_supply = pending.new_cap;
+            self.pending_cap_change = None;
+
+            self.env().emit_event(CapChanged {
+                old_cap,
+                new_cap: pending.new_cap,
+            });
+
+            Ok(())
+        }
+
+        /// Drop the queued cap change - only owner can do this
+        #[ink(message)]
+        pub fn cancel_cap_change(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("cancel_cap_change"))?;
+            self.ensure_privileged()?;
+
+            let pending = self
+                .pending_cap_change
+                .take()
+                .ok_or(Error::NoPendingCapChange)?;
+
+            self.env().emit_event(CapChangeCancelled {
+                new_cap: pending.new_cap,
+            });
+
+            Ok(())
+        }
+
+        /// Get the maximum supply (None = uncapped)
+        #[ink(message)]
+        pub fn max_supply(&self) -> Option<Balance> {
+            self.max_supply
+        }
+
+        /// Get the queued cap change, if any
+        #[ink(message)]
+        pub fn pending_cap_change(&self) -> Option<PendingCapChange> {
+            self.pending_cap_change.clone()
+        }
+    }
+
+    // ========== MULTISIG ==========
+    impl SimpleToken {
+        /// Hand privileged operations over to a multisig - only owner, and only
+        /// while no multisig is active (afterwards use a `SetMultisig` proposal)
+        #[ink(message)]
+        pub fn configure_multisig(&mut self, admins: Vec<AccountId>, threshold: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("configure_multisig"))?;
+            self.ensure_privileged()?;
+            self.set_multisig(admins, threshold)
+        }
+
+        /// Propose a privileged action; the proposer's confirmation is counted
+        #[ink(message)]
+        pub fn propose(&mut self, action: AdminAction) -> Result<u32> {
+            self.guard_selector(ink::selector_bytes!("propose"))?;
+
+            let caller = self.env().caller();
+
+            // Validate: Only multisig admins can propose
+            if self.multisig_threshold == 0 || !self.is_multisig_admin(caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            let id = self.next_proposal_id;
+            self.next_proposal_id = id.checked_add(1).ok_or(Error::Overflow)?;
+
+            self.proposals.insert(
+                id,
+                &Proposal {
+                    action: action.clone(),
+                    proposer: caller,
+                    confirmations: 0,
+                    executed: false,
+                    epoch: self.multisig_epoch,
+                },
+            );
+
+            self.env().emit_event(ProposalCreated {
+                id,
+                proposer: caller,
+                action,
+            });
+
+            // Proposing counts as the first confirmation
+            self.confirm(id)?;
+
+            Ok(id)
+        }
+
+        /// Confirm a pending proposal; it executes as soon as the threshold is met
+        #[ink(message)]
+        pub fn confirm(&mut self, proposal_id: u32) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("confirm"))?;
+
+            let caller = self.env().caller();
+
+            // Validate: Only multisig admins can confirm
+            if self.multisig_threshold == 0 || !self.is_multisig_admin(caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut proposal = self
+                .proposals
+                .get(proposal_id)
+                .ok_or(Error::ProposalNotFound)?;
+
+            if proposal.executed {
+                return Err(Error::ProposalAlreadyExecuted);
+            }
+            // Confirmations from admins that were since removed must not count
+            if proposal.epoch != self.multisig_epoch {
+                return Err(Error::ProposalStale);
+            }
+
+            if self
+                .proposal_confirmations
+                .get((proposal_id, caller))
+                .unwrap_or(false)
+            {
+                return Err(Error::AlreadyConfirmed);
+            }
+
+            self.proposal_confirmations.insert((proposal_id, caller), &true);
+            proposal.confirmations = proposal
+                .confirmations
+                .checked_add(1)
+                .ok_or(Error::Overflow)?;
+
+            self.env().emit_event(ProposalConfirmed {
+                id: proposal_id,
+                by: caller,
+                confirmations: proposal.confirmations,
+            });
+
+            // Execute once enough admins agreed
+            if proposal.confirmations >= self.multisig_threshold {
+                proposal.executed = true;
+                self.proposals.insert(proposal_id, &proposal);
+                self.execute_action(proposal.action)?;
+                self.env().emit_event(ProposalExecuted { id: proposal_id });
+            } else {
+                self.proposals.insert(proposal_id, &proposal);
+            }
+            self.flush_balance_notifications();
+
+            Ok(())
+        }
+
+        /// Get a proposal by id
+        #[ink(message)]
+        pub fn get_proposal(&self, proposal_id: u32) -> Option<Proposal> {
+            self.proposals.get(proposal_id)
+        }
+
+        /// Get the current multisig admins
+        #[ink(message)]
+        pub fn multisig_admins(&self) -> Vec<AccountId> {
+            self.multisig_admins.clone()
+        }
+
+        /// Get the current multisig threshold (0 = disabled)
+        #[ink(message)]
+        pub fn multisig_threshold(&self) -> u32 {
+            self.multisig_threshold
+        }
+    }
+
+    impl PSP22 for SimpleToken {
+        /// `PSP22::total_supply`
+        #[ink(message)]
+        fn total_supply(&self) -> Balance {
+            SimpleToken::total_supply(self)
+        }
+
+        /// `PSP22::balance_of`
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> Balance {
+            SimpleToken::balance_of(self, owner)
+        }
+
+        /// `PSP22::allowance`
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            SimpleToken::allowance(self, owner, spender)
+        }
+
+        /// `PSP22::transfer` - `_data` is accepted for compatibility and ignored
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            to: AccountId,
+            value: Balance,
+            _data: Vec<u8>,
+        ) -> core::result::Result<(), PSP22Error> {
+            SimpleToken::transfer(self, to, value).map_err(PSP22Error::from)
+        }
+
+        /// `PSP22::transfer_from` - `_data` is accepted for compatibility and ignored
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+            _data: Vec<u8>,
+        ) -> core::result::Result<(), PSP22Error> {
+            SimpleToken::transfer_from(self, from, to, value).map_err(PSP22Error::from)
+        }
+
+        /// `PSP22::approve`
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: Balance) -> core::result::Result<(), PSP22Error> {
+            SimpleToken::approve(self, spender, value).map_err(PSP22Error::from)
+        }
+
+        /// `PSP22::increase_allowance`
+        #[ink(message)]
+        fn increase_allowance(
+            &mut self,
+            spender: AccountId,
+            delta_value: Balance,
+        ) -> core::result::Result<(), PSP22Error> {
+            self.guard_selector(ink::selector_bytes!("PSP22::increase_allowance"))?;
+
+            let caller = self.env().caller();
+            let allowance = self
+                .allowances
+                .get((caller, spender))
+                .unwrap_or(0)
+                .checked_add(delta_value)
+                .ok_or(Error::Overflow)?;
+            self.set_allowance(caller, spender, allowance, AllowanceChangeCause::Increase);
+            Ok(())
+        }
+
+        /// `PSP22::decrease_allowance`
+        #[ink(message)]
+        fn decrease_allowance(
+            &mut self,
+            spender: AccountId,
+            delta_value: Balance,
+        ) -> core::result::Result<(), PSP22Error> {
+            self.guard_selector(ink::selector_bytes!("PSP22::decrease_allowance"))?;
+
+            let caller = self.env().caller();
+            let allowance = self
+                .allowances
+                .get((caller, spender))
+                .unwrap_or(0)
+                .checked_sub(delta_value)
+                .ok_or(PSP22Error::InsufficientAllowance)?;
+            self.set_allowance(caller, spender, allowance, AllowanceChangeCause::Decrease);
+            Ok(())
+        }
+    }
+
+    impl Ownable for SimpleToken {
+        /// Get the current owner
+        #[ink(message)]
+        fn owner(&self) -> AccountId {
+            self.owner
+        }
+
+        /// Schedule handing ownership to `new_owner` at `effective_at` - only owner
+        ///
+        /// Replaces any handover already scheduled. Until `effective_at` the owner
+        /// can still cancel it.
+        #[ink(message)]
+        fn schedule_ownership_transfer(&mut self, new_owner: AccountId, effective_at: u64) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("Ownable::schedule_ownership_transfer"))?;
+            self.ensure_privileged()?;
+
+            // Validate: The handover must leave time to review it
+            if new_owner == self.owner || effective_at <= self.env().block_timestamp() {
+                return Err(Error::InvalidAmount);
+            }
+
+            self.pending_ownership_transfer = Some(PendingOwnershipTransfer {
+                new_owner,
+                effective_at,
+            });
+
+            self.env().emit_event(OwnershipTransferScheduled {
+                new_owner,
+                effective_at,
+            });
+
+            Ok(())
+        }
+
+        /// Cancel the scheduled ownership handover - only owner, before it takes effect
+        #[ink(message)]
+        fn cancel_ownership_transfer(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("Ownable::cancel_ownership_transfer"))?;
+            self.ensure_privileged()?;
+
+            let pending = self
+                .pending_ownership_transfer
+                .take()
+                .ok_or(Error::NoPendingOwnershipTransfer)?;
+
+            self.env().emit_event(OwnershipTransferCancelled {
+                new_owner: pending.new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Complete the scheduled handover once it is effective - anyone can call
+        #[ink(message)]
+        fn finalize_ownership_transfer(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("Ownable::finalize_ownership_transfer"))?;
+
+            let pending = self
+                .pending_ownership_transfer
+                .clone()
+                .ok_or(Error::NoPendingOwnershipTransfer)?;
+
+            if self.env().block_timestamp() < pending.effective_at {
+                return Err(Error::OwnershipTransferNotReady);
+            }
+            if self.ownership_probe_required {
+                self.ensure_accepts_ownership(pending.new_owner)?;
+            }
+
+            let previous_owner = self.owner;
+            self.set_owner(pending.new_owner);
+
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: pending.new_owner,
+            });
+
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "pause")]
+    impl Pausable for SimpleToken {
+        /// Pause all transfers - owner or guardian can do this
+        #[ink(message)]
+        fn pause(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("Pausable::pause"))?;
+
+            // The guardian may pause on its own; everyone else needs owner rights
+            if self.guardian != Some(self.env().caller()) {
+                self.ensure_privileged()?;
+            }
+            self.set_paused(true);
+            Ok(())
+        }
+
+        /// Resume transfers - only owner can do this
+        #[ink(message)]
+        fn unpause(&mut self) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("Pausable::unpause"))?;
+            self.ensure_privileged()?;
+            self.set_paused(false);
+            Ok(())
+        }
+
+        /// Check whether the contract is paused
+        #[ink(message)]
+        fn is_paused(&self) -> bool {
+            self.is_paused
+        }
+    }
+
+    impl AccessControl for SimpleToken {
+        /// Check whether an account holds a role
+        #[ink(message)]
+        fn has_role(&self, role: RoleId, account: AccountId) -> bool {
+            self.check_role(role, account)
+        }
+
+        /// Get the admin role of a role (None = owner only)
+        #[ink(message)]
+        fn get_role_admin(&self, role: RoleId) -> Option<RoleId> {
+            self.role_admins.get(role)
+        }
+
+        /// Give an account a role - owner or members of the role's admin role
+        #[ink(message)]
+        fn grant_role(&mut self, role: RoleId, account: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("AccessControl::grant_role"))?;
+            self.ensure_role_admin(role)?;
+            self.add_role_member(role, account)
+        }
+
+        /// Take a role away from an account - owner or members of the role's admin role
+        #[ink(message)]
+        fn revoke_role(&mut self, role: RoleId, account: AccountId) -> Result<()> {
+            self.guard_selector(ink::selector_bytes!("AccessControl::revoke_role"))?;
+            self.ensure_role_admin(role)?;
+            self.remove_role_member(role, account);
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::CustomEnvironment;
+
+        fn accounts() -> ink::env::test::DefaultAccounts<CustomEnvironment> {
+            ink::env::test::default_accounts::<CustomEnvironment>()
+        }
+
+        #[ink::test]
+        fn reentrant_transfer_is_rejected() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+
+            // A recipient callback is running
+            assert_eq!(token.enter_non_reentrant(), Ok(()));
+
+            assert_eq!(token.transfer(accounts.bob, 100), Err(Error::ReentrancyDetected));
+            assert_eq!(token.balance_of(accounts.alice), 1_000);
+            assert_eq!(token.balance_of(accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn reentrant_transfer_from_is_rejected() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+            assert_eq!(token.approve(accounts.bob, 500), Ok(()));
+
+            assert_eq!(token.enter_non_reentrant(), Ok(()));
+
+            // The malicious callee tries to pull the approved tokens mid-update
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(
+                token.transfer_from(accounts.alice, accounts.charlie, 500),
+                Err(Error::ReentrancyDetected)
+            );
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 500);
+            assert_eq!(token.balance_of(accounts.charlie), 0);
+        }
+
+        #[ink::test]
+        fn lock_cannot_be_taken_twice() {
+            let mut token = SimpleToken::new();
+
+            assert_eq!(token.enter_non_reentrant(), Ok(()));
+            assert_eq!(token.enter_non_reentrant(), Err(Error::ReentrancyDetected));
+        }
+
+        #[ink::test]
+        fn transfers_resume_after_callback() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+
+            assert_eq!(token.enter_non_reentrant(), Ok(()));
+            token.exit_non_reentrant();
+
+            assert_eq!(token.transfer(accounts.bob, 100), Ok(()));
+            assert_eq!(token.balance_of(accounts.bob), 100);
+        }
+
+        #[ink::test]
+        fn psp22_messages_report_standard_errors() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+
+            assert_eq!(
+                PSP22::transfer(&mut token, accounts.bob, 2_000, Vec::new()),
+                Err(PSP22Error::InsufficientBalance)
+            );
+            assert_eq!(
+                token.decrease_allowance(accounts.bob, 1),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+            assert_eq!(
+                PSP22Error::from(Error::ContractPaused),
+                PSP22Error::Custom(String::from("ContractPaused"))
+            );
+        }
+
+        #[ink::test]
+        fn multisig_mint_executes_at_threshold() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let admins = vec![accounts.alice, accounts.bob, accounts.charlie];
+            assert_eq!(token.configure_multisig(admins, 2), Ok(()));
+
+            // The owner alone can no longer mint
+            assert_eq!(token.mint(accounts.django, 500), Err(Error::MultisigRequired));
+
+            let action = AdminAction::Mint {
+                to: accounts.django,
+                amount: 500,
+            };
+            assert_eq!(token.propose(action), Ok(0));
+            assert_eq!(token.get_proposal(0).map(|proposal| proposal.confirmations), Some(1));
+            assert_eq!(token.balance_of(accounts.django), 0);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.confirm(0), Ok(()));
+            assert_eq!(token.balance_of(accounts.django), 500);
+            assert_eq!(token.confirm(0), Err(Error::ProposalAlreadyExecuted));
+        }
+
+        #[ink::test]
+        fn multisig_rejects_outsiders_and_double_confirmations() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let admins = vec![accounts.alice, accounts.bob, accounts.charlie];
+            assert_eq!(token.configure_multisig(admins, 3), Ok(()));
+
+            let action = AdminAction::Mint {
+                to: accounts.django,
+                amount: 500,
+            };
+            assert_eq!(token.propose(action.clone()), Ok(0));
+            assert_eq!(token.confirm(0), Err(Error::AlreadyConfirmed));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.eve);
+            assert_eq!(token.propose(action), Err(Error::Unauthorized));
+            assert_eq!(token.confirm(0), Err(Error::Unauthorized));
+            assert_eq!(token.balance_of(accounts.django), 0);
+        }
+
+        #[ink::test]
+        fn multisig_threshold_counts_unique_admins() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+
+            // Duplicates collapse to two admins, so three confirmations are unreachable
+            let admins = vec![accounts.bob, accounts.bob, accounts.charlie];
+            assert_eq!(token.configure_multisig(admins.clone(), 3), Err(Error::InvalidThreshold));
+            assert_eq!(token.configure_multisig(admins.clone(), 0), Err(Error::InvalidThreshold));
+
+            assert_eq!(token.configure_multisig(admins, 2), Ok(()));
+            assert_eq!(token.multisig_admins(), vec![accounts.bob, accounts.charlie]);
+        }
+
+        #[ink::test]
+        fn multisig_change_invalidates_open_proposals() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.configure_multisig(vec![accounts.alice, accounts.bob], 2), Ok(()));
+
+            let mint = AdminAction::Mint {
+                to: accounts.django,
+                amount: 500,
+            };
+            assert_eq!(token.propose(mint), Ok(0));
+            let rotate = AdminAction::SetMultisig {
+                admins: vec![accounts.alice, accounts.charlie],
+                threshold: 2,
+            };
+            assert_eq!(token.propose(rotate), Ok(1));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.confirm(1), Ok(()));
+
+            // Alice's confirmation of the mint predates the rotation
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.confirm(0), Err(Error::ProposalStale));
+            assert_eq!(token.balance_of(accounts.django), 0);
+        }
+
+        #[ink::test]
+        fn social_recovery_moves_ownership_after_delay() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let guardians = vec![accounts.bob, accounts.charlie, accounts.django];
+            assert_eq!(token.set_recovery_guardians(guardians, 2, 1_000, 500), Ok(()));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(100);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.recover_ownership(accounts.eve), Ok(()));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.recover_ownership(accounts.eve), Ok(()));
+
+            // The owner gets the full delay to cancel
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(599);
+            assert_eq!(token.finalize_recovery(), Err(Error::RecoveryNotReady));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(600);
+            assert_eq!(token.finalize_recovery(), Ok(()));
+            assert_eq!(token.get_owner(), accounts.eve);
+            assert_eq!(token.pending_recovery(), None);
+        }
+
+        #[ink::test]
+        fn social_recovery_rejects_outsiders_and_can_be_cancelled() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let guardians = vec![accounts.bob, accounts.charlie];
+            assert_eq!(token.set_recovery_guardians(guardians, 2, 1_000, 500), Ok(()));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.eve);
+            assert_eq!(token.recover_ownership(accounts.eve), Err(Error::Unauthorized));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.recover_ownership(accounts.eve), Ok(()));
+            assert_eq!(token.recover_ownership(accounts.eve), Err(Error::AlreadyConfirmed));
+
+            // A live round is locked to its new owner
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.recover_ownership(accounts.frank), Err(Error::RecoveryInProgress));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.cancel_recovery(), Ok(()));
+            assert_eq!(token.finalize_recovery(), Err(Error::NoActiveRecovery));
+            assert_eq!(token.get_owner(), accounts.alice);
+        }
+
+        #[ink::test]
+        fn dead_man_switch_hands_over_after_silence() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.configure_dead_man_switch(Some(accounts.bob), 1_000), Ok(()));
+
+            // A recovery in progress must not survive the handover
+            let guardians = vec![accounts.charlie, accounts.django];
+            assert_eq!(token.set_recovery_guardians(guardians, 2, 10_000, 0), Ok(()));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.recover_ownership(accounts.eve), Ok(()));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_000);
+            assert_eq!(token.claim_ownership(), Err(Error::OwnerStillActive));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_001);
+            assert_eq!(token.claim_ownership(), Ok(()));
+            assert_eq!(token.get_owner(), accounts.bob);
+            assert_eq!(token.dead_man_switch(), (None, 0, 1_001));
+            assert_eq!(token.pending_recovery(), None);
+        }
+
+        #[ink::test]
+        fn dead_man_switch_rejects_others_and_heartbeat_resets_it() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.configure_dead_man_switch(Some(accounts.bob), 1_000), Ok(()));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(900);
+            assert_eq!(token.heartbeat(), Ok(()));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_500);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.claim_ownership(), Err(Error::Unauthorized));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.claim_ownership(), Err(Error::OwnerStillActive));
+            assert_eq!(token.get_owner(), accounts.alice);
+        }
+
+        #[ink::test]
+        fn emission_drips_completed_eras_to_distributor() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.configure_emission(Some(accounts.bob), 100, 10, 0), Ok(()));
+            assert_eq!(token.drip(), Err(Error::NothingToDrip));
+
+            for _ in 0..25 {
+                ink::env::test::advance_block::<CustomEnvironment>();
+            }
+
+            // Only whole eras are paid; the half era carries over
+            assert_eq!(token.pending_emission(), 200);
+            assert_eq!(token.drip(), Ok(200));
+            assert_eq!(token.balance_of(accounts.bob), 200);
+            assert_eq!(token.drip(), Err(Error::NothingToDrip));
+        }
+
+        #[ink::test]
+        fn emission_rejects_empty_eras_and_disabled_schedule() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.configure_emission(Some(accounts.bob), 100, 0, 0), Err(Error::InvalidAmount));
+            assert_eq!(token.drip(), Err(Error::EmissionDisabled));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.configure_emission(Some(accounts.bob), 100, 10, 0), Err(Error::Unauthorized));
+        }
+
+        #[ink::test]
+        fn emission_halves_on_era_aligned_boundaries() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.configure_emission(Some(accounts.bob), 1_000, 10, 20), Ok(()));
+
+            // Two eras per halving: 1000, 1000, 500, 500, 250
+            assert_eq!(token.era_emission(4), 250);
+            assert_eq!(token.emission_for_eras(0, 5), 3_250);
+            assert_eq!(token.emission_for_eras(2, 5), 1_250);
+
+            for _ in 0..50 {
+                ink::env::test::advance_block::<CustomEnvironment>();
+            }
+            assert_eq!(token.current_emission_rate(), 250);
+            assert_eq!(token.drip(), Ok(3_250));
+        }
+
+        #[ink::test]
+        fn emission_halves_by_era_start_block_and_decays_to_zero() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+
+            // Eras start at blocks 0, 3, 6, ...; halvings every 10 blocks
+            assert_eq!(token.configure_emission(Some(accounts.bob), 800, 3, 10), Ok(()));
+            assert_eq!(token.emission_for_eras(0, 8), 4 * 800 + 3 * 400 + 200);
+
+            assert_eq!(token.configure_emission(Some(accounts.bob), 1, 10, 10), Ok(()));
+            assert_eq!(token.emission_for_eras(0, 1_000), 1);
+        }
+
+        #[ink::test]
+        fn cap_change_applies_after_delay() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+            assert_eq!(token.queue_cap_change(Some(2_000)), Ok(()));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(CAP_CHANGE_DELAY - 1);
+            assert_eq!(token.apply_cap_change(), Err(Error::CapChangeNotReady));
+
+            // Anyone can apply once the delay is over
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(CAP_CHANGE_DELAY);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.apply_cap_change(), Ok(()));
+            assert_eq!(token.max_supply(), Some(2_000));
+            assert_eq!(token.pending_cap_change(), None);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.mint(accounts.alice, 1_001), Err(Error::CapExceeded));
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+        }
+
+        #[ink::test]
+        fn cap_change_rejects_low_caps_outsiders_and_cancelled_changes() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+            assert_eq!(token.queue_cap_change(Some(999)), Err(Error::CapExceeded));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.queue_cap_change(Some(2_000)), Err(Error::Unauthorized));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.queue_cap_change(Some(2_000)), Ok(()));
+            assert_eq!(token.cancel_cap_change(), Ok(()));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(CAP_CHANGE_DELAY);
+            assert_eq!(token.apply_cap_change(), Err(Error::NoPendingCapChange));
+            assert_eq!(token.max_supply(), None);
+        }
+
+        /// Sign `payload` under the token's domain separator with the dev `alice` sr25519 key
+        fn sign_sr25519<T: scale::Encode>(token: &SimpleToken, payload: &T) -> [u8; 64] {
+            let mut message = [0u8; 32];
+            let input = (token.compute_domain_separator(), payload);
+            ink::env::hash_encoded::<Blake2x256, _>(&input, &mut message);
+            ink_e2e::alice().sign(&message).0
+        }
+
+        #[ink::test]
+        fn transfer_with_authorization_moves_signed_amount_once() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let signer = AccountId::from(ink_e2e::alice().public_key().0);
+            assert_eq!(token.mint(signer, 1_000), Ok(()));
+
+            let payload = (TRANSFER_AUTHORIZATION_TYPE, signer, accounts.bob, 300u128, 0u64, 1_000u64, 0u64);
+            let signature = sign_sr25519(&token, &payload);
+
+            // Any relayer can submit
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(100);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(
+                token.transfer_with_authorization(signer, accounts.bob, 300, 0, 1_000, 0, signature),
+                Ok(())
+            );
+            assert_eq!(token.balance_of(accounts.bob), 300);
+            assert_eq!(token.nonce_of(signer, NonceKind::MetaTx), 1);
+
+            assert_eq!(
+                token.transfer_with_authorization(signer, accounts.bob, 300, 0, 1_000, 0, signature),
+                Err(Error::InvalidNonce)
+            );
+            assert_eq!(token.balance_of(accounts.bob), 300);
+        }
+
+        #[ink::test]
+        fn transfer_with_authorization_rejects_window_and_tampering() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let signer = AccountId::from(ink_e2e::alice().public_key().0);
+            assert_eq!(token.mint(signer, 1_000), Ok(()));
+
+            let payload = (TRANSFER_AUTHORIZATION_TYPE, signer, accounts.bob, 300u128, 100u64, 200u64, 0u64);
+            let signature = sign_sr25519(&token, &payload);
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(100);
+            assert_eq!(
+                token.transfer_with_authorization(signer, accounts.bob, 300, 100, 200, 0, signature),
+                Err(Error::AuthorizationNotYetValid)
+            );
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(200);
+            assert_eq!(
+                token.transfer_with_authorization(signer, accounts.bob, 300, 100, 200, 0, signature),
+                Err(Error::AuthorizationExpired)
+            );
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(150);
+            assert_eq!(
+                token.transfer_with_authorization(signer, accounts.bob, 301, 100, 200, 0, signature),
+                Err(Error::InvalidSignature)
+            );
+            assert_eq!(token.balance_of(accounts.bob), 0);
+            assert_eq!(token.nonce_of(signer, NonceKind::MetaTx), 0);
+        }
+
+        /// Sign `payload` the way an Ethereum wallet's `personal_sign` would; returns the
+        /// signature and the signer's address
+        fn sign_ecdsa<T: scale::Encode>(
+            token: &SimpleToken,
+            key: [u8; 32],
+            payload: &T,
+        ) -> ([u8; 65], [u8; 20]) {
+            let mut digest = [0u8; 32];
+            let input = (token.compute_domain_separator(), payload);
+            ink::env::hash_encoded::<Keccak256, _>(&input, &mut digest);
+            let mut prefixed = [0u8; 60];
+            prefixed[..28].copy_from_slice(ETH_SIGNED_MESSAGE_PREFIX);
+            prefixed[28..].copy_from_slice(&digest);
+            let mut message = [0u8; 32];
+            ink::env::hash_bytes::<Keccak256>(&prefixed, &mut message);
+
+            let secp = secp256k1::Secp256k1::new();
+            let secret = secp256k1::SecretKey::from_slice(&key).unwrap();
+            let message = secp256k1::Message::from_digest_slice(&message).unwrap();
+            let (recovery_id, compact) = secp
+                .sign_ecdsa_recoverable(&message, &secret)
+                .serialize_compact();
+            let mut signature = [0u8; 65];
+            signature[..64].copy_from_slice(&compact);
+            signature[64] = recovery_id.to_i32() as u8;
+
+            let mut address = [0u8; 20];
+            let public_key = secret.public_key(&secp).serialize();
+            ink::env::ecdsa_to_eth_address(&public_key, &mut address).unwrap();
+            (signature, address)
+        }
+
+        #[ink::test]
+        fn permit_ecdsa_sets_allowance_once() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+
+            let payload = (ECDSA_PERMIT_TYPE, accounts.alice, accounts.bob, 500u128, 1_000u64, 0u64);
+            let (signature, address) = sign_ecdsa(&token, [0x11; 32], &payload);
+            assert_eq!(token.register_eth_signer(Some(address)), Ok(()));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(
+                token.permit_ecdsa(accounts.alice, accounts.bob, 500, 1_000, 0, signature),
+                Ok(())
+            );
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 500);
+            assert_eq!(token.nonce_of(accounts.alice, NonceKind::Permit), 1);
+            assert_eq!(
+                token.permit_ecdsa(accounts.alice, accounts.bob, 500, 1_000, 0, signature),
+                Err(Error::InvalidNonce)
+            );
+        }
+
+        #[ink::test]
+        fn permit_ecdsa_rejects_unregistered_foreign_and_expired_signatures() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+
+            let payload = (ECDSA_PERMIT_TYPE, accounts.alice, accounts.bob, 500u128, 1_000u64, 0u64);
+            let (signature, _) = sign_ecdsa(&token, [0x11; 32], &payload);
+            assert_eq!(
+                token.permit_ecdsa(accounts.alice, accounts.bob, 500, 1_000, 0, signature),
+                Err(Error::NoEthSigner)
+            );
+
+            // Alice registered a different wallet
+            let (_, other) = sign_ecdsa(&token, [0x22; 32], &payload);
+            assert_eq!(token.register_eth_signer(Some(other)), Ok(()));
+            assert_eq!(
+                token.permit_ecdsa(accounts.alice, accounts.bob, 500, 1_000, 0, signature),
+                Err(Error::InvalidSignature)
+            );
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_000);
+            assert_eq!(
+                token.permit_ecdsa(accounts.alice, accounts.bob, 500, 1_000, 0, signature),
+                Err(Error::AuthorizationExpired)
+            );
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn nonces_are_scoped_per_operation_kind() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+
+            assert_eq!(token.use_nonce(accounts.alice, NonceKind::Permit, 0), Ok(()));
+            assert_eq!(token.use_nonce(accounts.alice, NonceKind::Permit, 0), Err(Error::InvalidNonce));
+            assert_eq!(token.use_nonce(accounts.alice, NonceKind::Permit, 2), Err(Error::InvalidNonce));
+            assert_eq!(token.nonce_of(accounts.alice, NonceKind::Permit), 1);
+
+            // A used permit nonce does not advance meta-transactions or other accounts
+            assert_eq!(token.nonce_of(accounts.alice, NonceKind::MetaTx), 0);
+            assert_eq!(token.nonce_of(accounts.bob, NonceKind::Permit), 0);
+            assert_eq!(token.use_nonce(accounts.alice, NonceKind::MetaTx, 0), Ok(()));
+        }
+
+        /// Build a two-leaf airdrop tree for `(bob, 300)` and `(charlie, 200)`; returns
+        /// the root and both leaves
+        fn two_leaf_tree(token: &SimpleToken, hash: MerkleHash) -> ([u8; 32], [u8; 32], [u8; 32]) {
+            let accounts = accounts();
+            let bob_leaf = token.merkle_leaf(hash, accounts.bob, 300);
+            let charlie_leaf = token.merkle_leaf(hash, accounts.charlie, 200);
+            let (first, second) = if bob_leaf <= charlie_leaf {
+                (bob_leaf, charlie_leaf)
+            } else {
+                (charlie_leaf, bob_leaf)
+            };
+            let mut pair = [0u8; 64];
+            pair[..32].copy_from_slice(&first);
+            pair[32..].copy_from_slice(&second);
+            let mut root = [0u8; 32];
+            match hash {
+                MerkleHash::Blake2x256 => ink::env::hash_bytes::<Blake2x256>(&pair, &mut root),
+                MerkleHash::Keccak256 => ink::env::hash_bytes::<Keccak256>(&pair, &mut root),
+            }
+            (root, bob_leaf, charlie_leaf)
+        }
+
+        #[ink::test]
+        fn sponsored_claim_pays_allocation_minus_tip() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+            let (root, _, charlie_leaf) = two_leaf_tree(&token, MerkleHash::Blake2x256);
+            assert_eq!(token.create_airdrop(root, MerkleHash::Blake2x256, 500, 1_000), Ok(0));
+            assert_eq!(token.set_claim_sponsor(accounts.django, true), Ok(()));
+
+            // The tip is capped at 10% of the allocation
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.django);
+            assert_eq!(
+                token.claim_for(0, accounts.bob, 300, vec![charlie_leaf], 31),
+                Err(Error::TipTooHigh)
+            );
+            assert_eq!(token.claim_for(0, accounts.bob, 300, vec![charlie_leaf], 30), Ok(()));
+            assert_eq!(token.balance_of(accounts.bob), 270);
+            assert_eq!(token.balance_of(accounts.django), 30);
+            assert!(token.is_airdrop_claimed(0, accounts.bob));
+        }
+
+        #[ink::test]
+        fn sponsored_claim_requires_a_registered_sponsor() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+            let (root, _, charlie_leaf) = two_leaf_tree(&token, MerkleHash::Blake2x256);
+            assert_eq!(token.create_airdrop(root, MerkleHash::Blake2x256, 500, 1_000), Ok(0));
+            assert_eq!(
+                token.create_airdrop(root, MerkleHash::Blake2x256, 500, 10_001),
+                Err(Error::TipTooHigh)
+            );
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.eve);
+            assert_eq!(
+                token.claim_for(0, accounts.bob, 300, vec![charlie_leaf], 0),
+                Err(Error::Unauthorized)
+            );
+            assert_eq!(token.balance_of(accounts.bob), 0);
+            assert!(!token.is_airdrop_claimed(0, accounts.bob));
+        }
+
+        #[ink::test]
+        fn airdrop_claims_verify_with_either_hash() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+
+            for (id, hash) in [(0, MerkleHash::Blake2x256), (1, MerkleHash::Keccak256)] {
+                let (root, bob_leaf, charlie_leaf) = two_leaf_tree(&token, hash);
+                ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+                assert_eq!(token.create_airdrop(root, hash, 500, 0), Ok(id));
+                assert!(token.verify_airdrop_proof(id, accounts.charlie, 200, vec![bob_leaf]));
+
+                ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+                assert_eq!(token.claim_airdrop(id, 300, vec![charlie_leaf]), Ok(()));
+                assert_eq!(token.claim_airdrop(id, 300, vec![charlie_leaf]), Err(Error::AlreadyClaimed));
+            }
+            assert_eq!(token.balance_of(accounts.bob), 600);
+        }
+
+        #[ink::test]
+        fn airdrop_rejects_wrong_allocations_and_closes_to_creator() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+            let (root, bob_leaf, charlie_leaf) = two_leaf_tree(&token, MerkleHash::Keccak256);
+            assert_eq!(token.create_airdrop(root, MerkleHash::Keccak256, 500, 0), Ok(0));
+
+            // Leaves are hashed twice, so they can never be mistaken for a 64-byte inner node
+            let mut packed = [0u8; 64];
+            packed[..32].copy_from_slice(accounts.bob.as_ref());
+            packed[48..].copy_from_slice(&300u128.to_be_bytes());
+            let mut inner = [0u8; 32];
+            ink::env::hash_bytes::<Keccak256>(&packed, &mut inner);
+            let mut leaf = [0u8; 32];
+            ink::env::hash_bytes::<Keccak256>(&inner, &mut leaf);
+            assert_eq!(bob_leaf, leaf);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.claim_airdrop(0, 300, vec![bob_leaf]), Err(Error::InvalidProof));
+            assert_eq!(token.claim_airdrop(0, 200, vec![charlie_leaf]), Err(Error::InvalidProof));
+
+            assert_eq!(token.close_airdrop(0), Err(Error::Unauthorized));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.close_airdrop(0), Ok(500));
+            assert_eq!(token.balance_of(accounts.alice), 1_000);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.claim_airdrop(0, 200, vec![bob_leaf]), Err(Error::AirdropNotFound));
+        }
+
+        fn sha256(preimage: &[u8]) -> [u8; 32] {
+            let mut hash = [0u8; 32];
+            ink::env::hash_bytes::<Sha2x256>(preimage, &mut hash);
+            hash
+        }
+
+        #[ink::test]
+        fn htlc_pays_recipient_on_preimage() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+            assert_eq!(token.lock_htlc(accounts.bob, 300, sha256(b"secret"), 1_000), Ok(0));
+            assert_eq!(token.balance_of(accounts.alice), 700);
+
+            // Anyone holding the preimage can complete it for the recipient
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.claim_htlc(0, b"secret".to_vec()), Ok(()));
+            assert_eq!(token.balance_of(accounts.bob), 300);
+            assert_eq!(token.claim_htlc(0, b"secret".to_vec()), Err(Error::HtlcNotFound));
+        }
+
+        #[ink::test]
+        fn htlc_rejects_bad_preimages_and_refunds_after_expiry() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(500);
+            assert_eq!(token.lock_htlc(accounts.bob, 300, sha256(b"secret"), 500), Err(Error::HtlcExpired));
+            assert_eq!(token.lock_htlc(accounts.bob, 300, sha256(b"secret"), 1_000), Ok(0));
+
+            assert_eq!(token.claim_htlc(0, b"guess".to_vec()), Err(Error::InvalidPreimage));
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(999);
+            assert_eq!(token.refund_htlc(0), Err(Error::HtlcNotExpired));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_000);
+            assert_eq!(token.claim_htlc(0, b"secret".to_vec()), Err(Error::HtlcExpired));
+            assert_eq!(token.refund_htlc(0), Ok(()));
+            assert_eq!(token.balance_of(accounts.alice), 1_000);
+            assert_eq!(token.balance_of(accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn swap_offer_escrows_and_returns_to_maker() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+
+            // Settling needs a second token contract, which the off-chain env lacks;
+            // escrow and refund paths are covered here
+            assert_eq!(token.create_swap_offer(400, accounts.eve, 50, None, 1_000), Ok(0));
+            assert_eq!(token.balance_of(accounts.alice), 600);
+            assert_eq!(token.get_swap_offer(0).map(|offer| offer.amount), Some(400));
+
+            assert_eq!(token.cancel_swap_offer(0), Ok(()));
+            assert_eq!(token.balance_of(accounts.alice), 1_000);
+            assert_eq!(token.get_swap_offer(0), None);
+
+            assert_eq!(token.create_swap_offer(400, accounts.eve, 50, None, 1_000), Ok(1));
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_000);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.refund_swap_offer(1), Ok(()));
+            assert_eq!(token.balance_of(accounts.alice), 1_000);
+        }
+
+        #[ink::test]
+        fn swap_offer_rejects_bad_terms_wrong_takers_and_expiry() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let this = ink::env::account_id::<CustomEnvironment>();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+
+            assert_eq!(token.create_swap_offer(400, this, 50, None, 1_000), Err(Error::InvalidAmount));
+            assert_eq!(token.create_swap_offer(400, accounts.eve, 0, None, 1_000), Err(Error::InvalidAmount));
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(500);
+            assert_eq!(
+                token.create_swap_offer(400, accounts.eve, 50, None, 500),
+                Err(Error::SwapOfferExpired)
+            );
+
+            let taker = Some(accounts.bob);
+            assert_eq!(token.create_swap_offer(400, accounts.eve, 50, taker, 1_000), Ok(0));
+            assert_eq!(token.refund_swap_offer(0), Err(Error::SwapOfferNotExpired));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.accept_swap_offer(0), Err(Error::Unauthorized));
+            assert_eq!(token.cancel_swap_offer(0), Err(Error::Unauthorized));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_000);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.accept_swap_offer(0), Err(Error::SwapOfferExpired));
+            assert_eq!(token.balance_of(accounts.bob), 0);
+        }
+
+        #[cfg(feature = "fees")]
+        #[ink::test]
+        fn reflection_fee_accrues_to_existing_holders() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.configure_fees(1_000, vec![(FeeDestination::Reflection, 10_000)]), Ok(()));
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+            assert_eq!(token.mint(accounts.bob, 1_000), Ok(()));
+
+            // 10 tokens of fee spread over the 1,990 tokens still held
+            assert_eq!(token.transfer(accounts.charlie, 100), Ok(()));
+            assert_eq!(token.balance_of(accounts.charlie), 90);
+            assert_eq!(token.pending_reflection(accounts.bob), 5);
+            assert_eq!(token.pending_reflection(accounts.alice), 4);
+            assert_eq!(token.pending_reflection(accounts.charlie), 0);
+
+            // Settled on the next balance change
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.transfer(accounts.django, 1), Ok(()));
+            assert_eq!(token.balance_of(accounts.bob), 1_004);
+            assert_eq!(token.pending_reflection(accounts.bob), 0);
+            assert_eq!(token.total_supply(), 2_000);
+        }
+
+        #[cfg(feature = "fees")]
+        #[ink::test]
+        fn fee_config_rejects_excess_and_unrouted_fees() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let routes = vec![(FeeDestination::Reflection, 10_000)];
+            assert_eq!(token.configure_fees(MAX_FEE_BPS + 1, routes.clone()), Err(Error::InvalidFeeConfig));
+            assert_eq!(
+                token.configure_fees(100, vec![(FeeDestination::Reflection, 9_999)]),
+                Err(Error::InvalidFeeConfig)
+            );
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.configure_fees(100, routes), Err(Error::Unauthorized));
+        }
+
+        #[cfg(feature = "fees")]
+        #[ink::test]
+        fn vault_fees_go_to_treasury_until_shares_exist() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let treasury = ink::env::account_id::<CustomEnvironment>();
+            assert_eq!(token.configure_fees(1_000, vec![(FeeDestination::Vault, 10_000)]), Ok(()));
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+
+            // Nobody holds shares, so the first depositor must not collect this fee
+            assert_eq!(token.transfer(accounts.bob, 100), Ok(()));
+            assert_eq!(token.balance_of(treasury), 10);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.deposit_to_vault(45), Ok(45));
+
+            // With shares outstanding the fee raises the share price
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.transfer(accounts.charlie, 100), Ok(()));
+            assert_eq!(token.balance_of(treasury), 10);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.withdraw_from_vault(45), Ok(55));
+            assert_eq!(token.balance_of(accounts.bob), 100);
+        }
+
+        #[ink::test]
+        fn vault_sweeps_orphaned_assets_and_rejects_bad_withdrawals() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let treasury = ink::env::account_id::<CustomEnvironment>();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+
+            // Rounding dust left without shares
+            token.vault_assets = 7;
+            assert_eq!(token.deposit_to_vault(100), Ok(100));
+            assert_eq!(token.balance_of(treasury), 7);
+            assert_eq!(token.share_price(), SHARE_PRICE_PRECISION);
+
+            assert_eq!(token.withdraw_from_vault(0), Err(Error::InvalidAmount));
+            assert_eq!(token.withdraw_from_vault(101), Err(Error::InsufficientShares));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.withdraw_from_vault(1), Err(Error::InsufficientShares));
+        }
+
+        #[ink::test]
+        fn dividend_pays_by_snapshot_balance() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let contract = ink::env::account_id::<CustomEnvironment>();
+            ink::env::test::set_account_balance::<CustomEnvironment>(contract, 1_000);
+            assert_eq!(token.mint(accounts.alice, 300), Ok(()));
+            assert_eq!(token.mint(accounts.bob, 100), Ok(()));
+
+            ink::env::test::set_value_transferred::<CustomEnvironment>(400);
+            assert_eq!(token.deposit_dividend(100), Ok(0));
+            ink::env::test::set_value_transferred::<CustomEnvironment>(0);
+            let snapshot = token.current_snapshot_id();
+
+            // Moving tokens after the snapshot does not change the shares
+            assert_eq!(token.transfer(accounts.bob, 300), Ok(()));
+            assert_eq!(token.balance_at(snapshot, accounts.alice), 300);
+            assert_eq!(token.balance_at(snapshot, accounts.bob), 100);
+            assert_eq!(token.balance_at(snapshot + 1, accounts.bob), 0);
+            assert_eq!(token.claimable_dividend(0, accounts.alice), 300);
+            assert_eq!(token.claimable_dividend(0, accounts.bob), 100);
+
+            assert_eq!(token.claim_dividend(0), Ok(300));
+            assert_eq!(token.claim_dividend(0), Err(Error::AlreadyClaimed));
+            assert_eq!(token.claimable_dividend(0, accounts.alice), 0);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.claim_dividend(0), Err(Error::InvalidAmount));
+            assert_eq!(token.claim_dividend(1), Err(Error::DividendNotFound));
+        }
+
+        #[ink::test]
+        fn dividend_rejects_bad_deposits_and_sweeps_after_expiry() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let contract = ink::env::account_id::<CustomEnvironment>();
+            ink::env::test::set_account_balance::<CustomEnvironment>(contract, 1_000);
+            assert_eq!(token.mint(accounts.bob, 100), Ok(()));
+
+            assert_eq!(token.deposit_dividend(100), Err(Error::InvalidAmount));
+            ink::env::test::set_value_transferred::<CustomEnvironment>(200);
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(50);
+            assert_eq!(token.deposit_dividend(50), Err(Error::InvalidSchedule));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.deposit_dividend(100), Err(Error::Unauthorized));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.deposit_dividend(100), Ok(0));
+            ink::env::test::set_value_transferred::<CustomEnvironment>(0);
+
+            assert_eq!(token.sweep_dividend(0, accounts.alice), Err(Error::DividendNotExpired));
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(100);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.claim_dividend(0), Err(Error::DividendExpired));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.sweep_dividend(0, accounts.alice), Ok(200));
+            assert_eq!(token.get_dividend(0), None);
+        }
+
+        #[ink::test]
+        fn seizure_can_be_reversed_during_dispute_window() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.bob, 500), Ok(()));
+            assert_eq!(token.seize(accounts.bob, 200, b"order".to_vec()), Err(Error::MissingRole));
+            assert_eq!(token.grant_role(COMPLIANCE, accounts.alice), Ok(()));
+            assert_eq!(token.grant_role(APPEALS, accounts.charlie), Ok(()));
+
+            assert_eq!(token.seize(accounts.bob, 0, Vec::new()), Err(Error::InvalidAmount));
+            assert_eq!(token.seize(accounts.bob, 501, Vec::new()), Err(Error::InsufficientBalance));
+            assert_eq!(token.seize(accounts.bob, 200, b"order".to_vec()), Ok(0));
+            assert_eq!(token.balance_of(accounts.bob), 300);
+            assert_eq!(token.seized_escrow_total(), 200);
+            assert_eq!(token.burn_seized(0), Err(Error::DisputeWindowOpen));
+            assert_eq!(token.redistribute_seized(0, accounts.django), Err(Error::DisputeWindowOpen));
+            assert_eq!(token.reverse_seizure(0), Err(Error::MissingRole));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.reverse_seizure(0), Ok(()));
+            assert_eq!(token.reverse_seizure(0), Err(Error::SeizureClosed));
+            assert_eq!(token.balance_of(accounts.bob), 500);
+            assert_eq!(token.seized_escrow_total(), 0);
+            assert_eq!(token.get_seizure(0).map(|case| case.status), Some(SeizureStatus::Reversed));
+        }
+
+        #[ink::test]
+        fn seizure_resolves_after_dispute_window() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.bob, 500), Ok(()));
+            assert_eq!(token.grant_role(COMPLIANCE, accounts.alice), Ok(()));
+            assert_eq!(token.grant_role(APPEALS, accounts.charlie), Ok(()));
+            assert_eq!(token.seize(accounts.bob, 200, Vec::new()), Ok(0));
+            assert_eq!(token.seize(accounts.bob, 100, Vec::new()), Ok(1));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(SEIZURE_DISPUTE_WINDOW);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.reverse_seizure(0), Err(Error::DisputeWindowClosed));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.burn_seized(0), Ok(()));
+            assert_eq!(token.total_supply(), 300);
+            assert_eq!(token.redistribute_seized(1, accounts.django), Ok(()));
+            assert_eq!(token.balance_of(accounts.django), 100);
+            assert_eq!(token.seized_escrow_total(), 0);
+            assert_eq!(token.burn_seized(1), Err(Error::SeizureClosed));
+            assert_eq!(token.burn_seized(2), Err(Error::SeizureNotFound));
+            assert_eq!(token.seizure_count(), 2);
+        }
+
+        #[cfg(feature = "blacklist")]
+        #[ink::test]
+        fn blacklist_grace_period_delays_blocking() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.bob, 500), Ok(()));
+            assert_eq!(token.set_blacklist_grace_period(1_000), Ok(()));
+            assert_eq!(token.add_to_blacklist(accounts.bob), Ok(()));
+            assert_eq!(token.pending_blacklist_of(accounts.bob), Some(1_000));
+            assert!(!token.is_blacklisted(accounts.bob));
+
+            // Funds can still move during the grace period
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.transfer(accounts.charlie, 100), Ok(()));
+            assert_eq!(token.activate_blacklist(accounts.bob), Err(Error::BlacklistGracePeriodActive));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_000);
+            assert_eq!(token.activate_blacklist(accounts.bob), Ok(()));
+            assert!(token.is_blacklisted(accounts.bob));
+            assert_eq!(token.pending_blacklist_of(accounts.bob), None);
+            assert_eq!(token.blacklist_count(), 1);
+            assert_eq!(token.transfer(accounts.charlie, 100), Err(Error::AccountBlacklisted));
+            assert_eq!(token.activate_blacklist(accounts.bob), Err(Error::NoPendingBlacklist));
+        }
+
+        #[cfg(feature = "blacklist")]
+        #[ink::test]
+        fn blacklist_can_be_escalated_or_cancelled() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.set_blacklist_grace_period(1_000), Ok(()));
+            assert_eq!(token.add_to_blacklist(accounts.bob), Ok(()));
+            assert_eq!(token.add_to_blacklist(accounts.charlie), Ok(()));
+
+            assert_eq!(token.escalate_blacklist(accounts.bob), Ok(()));
+            assert!(token.is_blacklisted(accounts.bob));
+            assert_eq!(token.pending_blacklist_of(accounts.bob), None);
+
+            assert_eq!(token.remove_from_blacklist(accounts.charlie), Ok(()));
+            assert_eq!(token.pending_blacklist_of(accounts.charlie), None);
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_000);
+            assert_eq!(token.activate_blacklist(accounts.charlie), Err(Error::NoPendingBlacklist));
+            assert!(!token.is_blacklisted(accounts.charlie));
+            assert_eq!(token.blacklist_count(), 1);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.set_blacklist_grace_period(0), Err(Error::Unauthorized));
+        }
+
+        #[ink::test]
+        fn selector_guards_require_multisig_and_protect_themselves() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let transfer = ink::selector_bytes!("PSP22::transfer");
+            let guard = ink::selector_bytes!("set_selector_paused");
+            assert_eq!(token.set_selector_paused(guard, true), Err(Error::ProtectedSelector));
+            assert_eq!(token.set_selector_access(guard, Some(MINTER)), Err(Error::ProtectedSelector));
+
+            let admins = vec![accounts.alice, accounts.bob];
+            assert_eq!(token.configure_multisig(admins, 2), Ok(()));
+            assert_eq!(token.set_selector_paused(transfer, true), Err(Error::MultisigRequired));
+            assert_eq!(token.set_selector_access(transfer, Some(MINTER)), Err(Error::MultisigRequired));
+
+            let action = AdminAction::SetSelectorPaused {
+                selector: transfer,
+                paused: true,
+            };
+            assert_eq!(token.propose(action), Ok(0));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.confirm(0), Ok(()));
+            assert!(token.is_selector_paused(transfer));
+        }
+
+        #[ink::test]
+        fn multisig_gates_owner_role_grants_and_withdrawals() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let contract = ink::env::account_id::<CustomEnvironment>();
+            ink::env::test::set_account_balance::<CustomEnvironment>(contract, 1_000);
+            let admins = vec![accounts.alice, accounts.bob];
+            assert_eq!(token.configure_multisig(admins, 2), Ok(()));
+
+            // The owner can no longer hand itself a role or move funds alone
+            assert_eq!(token.grant_role(COMPLIANCE, accounts.alice), Err(Error::MultisigRequired));
+            assert_eq!(token.withdraw_native(accounts.alice, 100), Err(Error::MultisigRequired));
+            assert_eq!(token.set_guardian(Some(accounts.eve)), Err(Error::MultisigRequired));
+            assert_eq!(token.heartbeat(), Err(Error::MultisigRequired));
+
+            let action = AdminAction::GrantRole {
+                role: COMPLIANCE,
+                account: accounts.charlie,
+            };
+            assert_eq!(token.propose(action), Ok(0));
+            let action = AdminAction::DepositDividend {
+                amount: 400,
+                expires_at: 100,
+            };
+            assert_eq!(token.propose(action), Ok(1));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.confirm(0), Ok(()));
+            assert!(token.has_role(COMPLIANCE, accounts.charlie));
+            assert_eq!(token.confirm(1), Ok(()));
+            assert_eq!(token.get_dividend(0).map(|dividend| dividend.amount), Some(400));
+
+            // Funds owed to dividend claimants cannot be paid out again
+            let action = AdminAction::DepositDividend {
+                amount: 700,
+                expires_at: 100,
+            };
+            assert_eq!(token.propose(action), Ok(2));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.confirm(2), Err(Error::InsufficientBalance));
+        }
+
+        #[ink::test]
+        fn guardian_stop_cannot_be_lifted_early_by_owner() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.set_guardian(Some(accounts.eve)), Ok(()));
+            assert_eq!(token.mint(accounts.bob, 500), Ok(()));
+            assert_eq!(token.grant_role(COMPLIANCE, accounts.charlie), Ok(()));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.eve);
+            assert_eq!(token.emergency_stop(), Ok(()));
+
+            // Mints, role holders and owner setters are all frozen
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.seize(accounts.bob, 100, Vec::new()), Err(Error::EmergencyStopActive));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.mint(accounts.alice, 100), Err(Error::EmergencyStopActive));
+            assert_eq!(token.grant_role(MINTER, accounts.alice), Err(Error::EmergencyStopActive));
+            assert_eq!(token.set_guardian(None), Err(Error::EmergencyStopActive));
+            assert_eq!(token.lift_emergency_stop(), Err(Error::EmergencyDelayNotElapsed));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.eve);
+            assert_eq!(token.lift_emergency_stop(), Ok(()));
+            assert_eq!(token.emergency_stopped_at(), None);
+        }
+
+        #[ink::test]
+        fn owner_stop_can_only_be_lifted_by_owner_after_delay() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.set_guardian(Some(accounts.eve)), Ok(()));
+            assert_eq!(token.emergency_stop(), Ok(()));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.eve);
+            assert_eq!(token.lift_emergency_stop(), Err(Error::Unauthorized));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.lift_emergency_stop(), Err(Error::EmergencyDelayNotElapsed));
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(EMERGENCY_STOP_DELAY);
+            assert_eq!(token.lift_emergency_stop(), Ok(()));
+        }
+
+        #[ink::test]
+        fn guardian_incident_mode_uses_owner_recovery_address() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.set_guardian(Some(accounts.eve)), Ok(()));
+            assert_eq!(token.mint(accounts.bob, 500), Ok(()));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.eve);
+            assert_eq!(token.set_incident_mode(true), Err(Error::NoIncidentRecoveryAddress));
+            assert_eq!(token.set_incident_recovery_address(Some(accounts.eve)), Err(Error::Unauthorized));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.set_incident_recovery_address(Some(accounts.django)), Ok(()));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.eve);
+            assert_eq!(token.set_incident_mode(true), Ok(()));
+            assert_eq!(token.set_incident_mode(false), Err(Error::Unauthorized));
+            assert_eq!(token.incident_mode(), (true, Some(accounts.django)));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.transfer(accounts.charlie, 100), Err(Error::IncidentModeActive));
+            assert_eq!(token.transfer(accounts.django, 100), Ok(()));
+        }
+
+        #[ink::test]
+        fn balance_observer_is_queued_once_per_account() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 500), Ok(()));
+            assert!(token.pending_balance_notifications.is_empty());
+
+            // Queued while the message runs; the observer is only called at its end
+            token.balance_observer = Some(accounts.eve);
+            assert_eq!(token._transfer(accounts.alice, accounts.bob, 100), Ok(()));
+            assert_eq!(token._transfer(accounts.alice, accounts.bob, 100), Ok(()));
+            assert_eq!(token.pending_balance_notifications, vec![accounts.alice, accounts.bob]);
+        }
+
+        /// Events of type `E` recorded so far, in emission order
+        fn recorded<E: ink::env::Event + scale::Decode>() -> Vec<E> {
+            let topic = E::SIGNATURE_TOPIC.expect("events are not anonymous");
+            ink::env::test::recorded_events()
+                .filter(|event| event.topics.first().is_some_and(|first| first[..] == topic[..]))
+                .map(|event| E::decode(&mut &event.data[..]).expect("event decodes"))
+                .collect()
+        }
+
+        #[cfg(feature = "fees")]
+        #[ink::test]
+        fn fee_shares_emit_transfer_and_burned_events() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let routes = vec![
+                (FeeDestination::Account(accounts.django), 5_000),
+                (FeeDestination::Burn, 5_000),
+            ];
+            assert_eq!(token.configure_fees(1_000, routes), Ok(()));
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+            assert_eq!(token.transfer(accounts.bob, 100), Ok(()));
+
+            let transfers: Vec<(AccountId, AccountId, Balance)> = recorded::<Transfer>()
+                .into_iter()
+                .map(|event| (event.from, event.to, event.amount))
+                .collect();
+            assert_eq!(
+                transfers,
+                vec![(accounts.alice, accounts.django, 5), (accounts.alice, accounts.bob, 90)]
+            );
+            let burns: Vec<(AccountId, Balance)> = recorded::<Burned>()
+                .into_iter()
+                .map(|event| (event.from, event.amount))
+                .collect();
+            assert_eq!(burns, vec![(accounts.alice, 5)]);
+        }
+
+        #[ink::test]
+        fn seizure_moves_emit_transfer_events() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let contract = ink::env::account_id::<CustomEnvironment>();
+            assert_eq!(token.mint(accounts.bob, 500), Ok(()));
+            assert_eq!(token.grant_role(COMPLIANCE, accounts.alice), Ok(()));
+            assert_eq!(token.grant_role(APPEALS, accounts.alice), Ok(()));
+            assert_eq!(token.seize(accounts.bob, 200, Vec::new()), Ok(0));
+            assert_eq!(token.reverse_seizure(0), Ok(()));
+
+            let transfers: Vec<(AccountId, AccountId, Balance)> = recorded::<Transfer>()
+                .into_iter()
+                .map(|event| (event.from, event.to, event.amount))
+                .collect();
+            assert_eq!(transfers, vec![(accounts.bob, contract, 200), (contract, accounts.bob, 200)]);
+        }
+
+        #[ink::test]
+        fn unwhitelisted_oracle_falls_back_without_calling_out() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.token_price(), Err(Error::PriceUnavailable));
+
+            assert_eq!(token.configure_oracle(Some(accounts.eve), 1_000, Some(42)), Ok(()));
+            assert_eq!(token.token_price(), Ok(42));
+            assert_eq!(token.configure_oracle(Some(accounts.eve), 1_000, None), Ok(()));
+            assert_eq!(token.token_price(), Err(Error::PriceUnavailable));
+        }
+
+        #[ink::test]
+        fn bridge_mints_within_cap_and_rate_limits() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.bridge_mint(accounts.charlie, 100), Err(Error::UnknownBridge));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.set_bridge(accounts.bob, 1_000), Ok(()));
+            assert_eq!(
+                token.set_bridge_rate_limits(accounts.bob, Some((100, 0)), None),
+                Err(Error::InvalidAmount)
+            );
+            assert_eq!(
+                token.set_bridge_rate_limits(accounts.django, Some((100, 1_000)), None),
+                Err(Error::UnknownBridge)
+            );
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.bridge_mint(accounts.charlie, 600), Ok(()));
+            assert_eq!(token.bridge_mint(accounts.charlie, 401), Err(Error::BridgeCapExceeded));
+            assert_eq!(token.bridge_outstanding(accounts.bob), 600);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.remove_bridge(accounts.bob), Err(Error::BridgeOutstanding));
+            assert_eq!(
+                token.set_bridge_rate_limits(accounts.bob, Some((100, 1_000)), Some((50, 1_000))),
+                Ok(())
+            );
+            assert_eq!(token.bridge_rate_available(accounts.bob), (Some(100), Some(50)));
+            assert_eq!(token.bridge_rate_available(accounts.django), (Some(0), Some(0)));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.bridge_mint(accounts.charlie, 101), Err(Error::BridgeRateLimited));
+            assert_eq!(token.bridge_mint(accounts.charlie, 100), Ok(()));
+            assert_eq!(token.bridge_rate_available(accounts.bob), (Some(0), Some(50)));
+
+            // The bucket refills linearly over its period
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(500);
+            assert_eq!(token.bridge_rate_available(accounts.bob), (Some(50), Some(50)));
+            assert_eq!(token.bridge_mint(accounts.charlie, 51), Err(Error::BridgeRateLimited));
+            assert_eq!(token.bridge_mint(accounts.charlie, 50), Ok(()));
+            assert_eq!(token.bridge_outstanding(accounts.bob), 750);
+            assert_eq!(token.total_supply(), 750);
+        }
+
+        #[ink::test]
+        fn bridge_burns_release_outstanding_and_count_bridged_supply() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.set_bridge(accounts.bob, 1_000), Ok(()));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.bridge_mint(accounts.charlie, 300), Ok(()));
+            assert_eq!(token.bridge_burn(accounts.charlie, 100, 7), Err(Error::InsufficientAllowance));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.approve(accounts.bob, 200), Ok(()));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.bridge_burn(accounts.charlie, 100, 7), Ok(()));
+            assert_eq!(token.bridge_outstanding(accounts.bob), 200);
+            assert_eq!(token.bridged_supply(7), 100);
+            assert_eq!(token.bridged_supply(8), 0);
+            assert_eq!(token.total_bridged_supply(), 100);
+            assert_eq!(token.total_supply(), 200);
+            assert_eq!(token.balance_of(accounts.charlie), 200);
+
+            // Bridged supply is accounting only; burning frees the cap for later mints
+            assert_eq!(token.bridge_mint(accounts.charlie, 800), Ok(()));
+            assert_eq!(token.bridge_outstanding(accounts.bob), 1_000);
+            assert_eq!(token.bridge_burn(accounts.bob, 1_001, 8), Err(Error::BridgeOutstanding));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.set_bridge_rate_limits(accounts.bob, None, Some((50, 1_000))), Ok(()));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.bridge_burn(accounts.charlie, 60, 8), Err(Error::BridgeRateLimited));
+            assert_eq!(token.bridge_burn(accounts.charlie, 50, 8), Ok(()));
+            assert_eq!(token.bridged_supply(8), 50);
+            assert_eq!(token.total_bridged_supply(), 150);
+            assert_eq!(token.bridge_outstanding(accounts.bob), 950);
+        }
+
+        #[ink::test]
+        fn treasury_spends_are_capped_per_period() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let treasury = ink::env::account_id::<CustomEnvironment>();
+            let period = 2 * TREASURY_TIMELOCK;
+            assert_eq!(token.mint(treasury, 1_000), Ok(()));
+            assert_eq!(token.treasury_spend_headroom(), None);
+            assert_eq!(token.set_treasury_spend_limit(Some((10_001, period))), Err(Error::InvalidAmount));
+            assert_eq!(token.set_treasury_spend_limit(Some((1_000, 0))), Err(Error::InvalidAmount));
+            assert_eq!(token.set_treasury_spend_limit(Some((1_000, period))), Ok(()));
+            assert_eq!(token.treasury_spend_headroom(), Some(100));
+
+            assert_eq!(token.propose_treasury_spend(accounts.bob, 0), Err(Error::InvalidAmount));
+            assert_eq!(token.propose_treasury_spend(accounts.bob, 60), Ok(0));
+            assert_eq!(token.propose_treasury_spend(accounts.bob, 50), Ok(1));
+            assert_eq!(token.execute_treasury_spend(0), Err(Error::TreasurySpendNotReady));
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(TREASURY_TIMELOCK);
+            assert_eq!(token.execute_treasury_spend(0), Ok(()));
+            assert_eq!(token.treasury_spend_headroom(), Some(40));
+            assert_eq!(token.execute_treasury_spend(1), Err(Error::TreasuryLimitExceeded));
+
+            // The next period's cap is measured against the treasury at that point
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(period);
+            assert_eq!(token.treasury_spend_headroom(), Some(94));
+            assert_eq!(token.propose_treasury_spend(accounts.bob, 90), Ok(2));
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(period + TREASURY_TIMELOCK);
+            assert_eq!(token.execute_treasury_spend(2), Ok(()));
+            assert_eq!(token.balance_of(accounts.bob), 150);
+            assert_eq!(token.treasury_spend_headroom(), Some(4));
+
+            assert_eq!(token.set_treasury_spend_limit(None), Ok(()));
+            assert_eq!(token.treasury_spend_headroom(), None);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.set_treasury_spend_limit(Some((1_000, period))), Err(Error::Unauthorized));
+        }
+
+        #[cfg(feature = "pause")]
+        #[ink::test]
+        fn circuit_breaker_pauses_after_window_volume() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 1_000), Ok(()));
+            assert_eq!(token.set_circuit_breaker(Some(100), 0), Err(Error::InvalidAmount));
+            assert_eq!(token.set_circuit_breaker(Some(100), 1_000), Ok(()));
+
+            assert_eq!(token.transfer(accounts.bob, 60), Ok(()));
+            assert_eq!(token.circuit_breaker(), (Some(100), 1_000, 60));
+            // The transfer that crosses the threshold still goes through
+            assert_eq!(token.transfer(accounts.bob, 50), Ok(()));
+            assert!(token.is_paused());
+            assert_eq!(token.transfer(accounts.bob, 1), Err(Error::ContractPaused));
+
+            // Unpausing is manual; volume is counted afresh in the next window
+            assert_eq!(token.unpause(), Ok(()));
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_000);
+            assert_eq!(token.transfer(accounts.bob, 90), Ok(()));
+            assert!(!token.is_paused());
+            assert_eq!(token.circuit_breaker(), (Some(100), 1_000, 90));
+
+            assert_eq!(token.set_circuit_breaker(None, 0), Ok(()));
+            assert_eq!(token.transfer(accounts.bob, 500), Ok(()));
+            assert!(!token.is_paused());
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.set_circuit_breaker(Some(1), 1), Err(Error::Unauthorized));
+        }
+
+        #[ink::test]
+        fn velocity_checks_flag_and_freeze_senders() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.bob, 1_000), Ok(()));
+            let limits = VelocityLimits {
+                window: 1_000,
+                max_sends: 2,
+                max_volume: 500,
+                auto_freeze: false,
+            };
+            assert_eq!(token.set_velocity_limits(Some(limits.clone())), Err(Error::MissingRole));
+            assert_eq!(token.grant_role(RISK, accounts.alice), Ok(()));
+            assert_eq!(token.grant_role(COMPLIANCE, accounts.alice), Ok(()));
+            assert_eq!(
+                token.set_velocity_limits(Some(VelocityLimits { window: 0, ..limits.clone() })),
+                Err(Error::InvalidAmount)
+            );
+            assert_eq!(token.set_velocity_limits(Some(limits.clone())), Ok(()));
+
+            // Flagging alone does not stop the sender
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            for _ in 0..3 {
+                assert_eq!(token.transfer(accounts.charlie, 10), Ok(()));
+            }
+            assert_eq!(
+                token.send_activity(accounts.bob),
+                SendActivity {
+                    window_start: 0,
+                    sends: 3,
+                    volume: 30,
+                    flagged: true,
+                }
+            );
+            assert!(!token.is_risk_frozen(accounts.bob));
+            assert_eq!(recorded::<SuspiciousActivity>().len(), 1);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(
+                token.set_velocity_limits(Some(VelocityLimits {
+                    auto_freeze: true,
+                    ..limits
+                })),
+                Ok(())
+            );
+
+            // In a new window, going over the volume freezes the sender after the move
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_000);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.transfer(accounts.charlie, 600), Ok(()));
+            assert!(token.is_risk_frozen(accounts.bob));
+            assert_eq!(token.transfer(accounts.charlie, 1), Err(Error::AccountFrozen));
+            assert_eq!(token.release_risk_freeze(accounts.bob), Err(Error::MissingRole));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.release_risk_freeze(accounts.bob), Ok(()));
+            assert_eq!(token.release_risk_freeze(accounts.bob), Err(Error::InvalidAmount));
+            assert!(!token.is_risk_frozen(accounts.bob));
+        }
+
+        #[ink::test]
+        fn minter_allowance_and_daily_quota_limit_approved_mints() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.grant_role(MINTER, accounts.bob), Ok(()));
+            assert_eq!(token.grant_role(APPROVER, accounts.charlie), Ok(()));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.request_mint(accounts.django, 100), Err(Error::MinterAllowanceExceeded));
+            assert_eq!(token.set_minter_allowance(accounts.bob, 300), Err(Error::Unauthorized));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.set_minter_allowance(accounts.bob, 300), Ok(()));
+            assert_eq!(token.set_minter_daily_quota(accounts.bob, Some(150)), Ok(()));
+            assert_eq!(token.minter_daily_quota(accounts.bob), (Some(150), 150));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.request_mint(accounts.django, 0), Err(Error::InvalidAmount));
+            assert_eq!(token.request_mint(accounts.django, 301), Err(Error::MinterAllowanceExceeded));
+            assert_eq!(token.request_mint(accounts.django, 100), Ok(0));
+            assert_eq!(token.request_mint(accounts.django, 100), Ok(1));
+            assert_eq!(token.approve_mint(0), Err(Error::MissingRole));
+
+            // The allowance is only charged once a request is approved
+            assert_eq!(token.minter_allowance(accounts.bob), 300);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.approve_mint(0), Ok(()));
+            assert_eq!(token.minter_allowance(accounts.bob), 200);
+            assert_eq!(token.minter_daily_quota(accounts.bob), (Some(150), 50));
+            assert_eq!(token.approve_mint(1), Err(Error::MintQuotaExceeded));
+
+            // The quota refills once its window has run out
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(MINT_QUOTA_WINDOW);
+            assert_eq!(token.minter_daily_quota(accounts.bob), (Some(150), 150));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.request_mint(accounts.django, 150), Ok(2));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.approve_mint(2), Ok(()));
+            assert_eq!(token.minter_allowance(accounts.bob), 50);
+            assert_eq!(token.balance_of(accounts.django), 250);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.request_mint(accounts.django, 51), Err(Error::MinterAllowanceExceeded));
+        }
+
+        #[ink::test]
+        fn session_key_spends_within_budget_until_expiry() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            assert_eq!(token.mint(accounts.alice, 500), Ok(()));
+            assert_eq!(token.register_session_key(accounts.alice, 100, 1_000), Err(Error::InvalidAmount));
+            assert_eq!(token.register_session_key(accounts.bob, 0, 1_000), Err(Error::InvalidAmount));
+            assert_eq!(token.register_session_key(accounts.bob, 100, 0), Err(Error::SessionKeyExpired));
+            assert_eq!(token.register_session_key(accounts.bob, 100, 1_000), Ok(()));
+            assert_eq!(token.register_session_key(accounts.django, 30, 1_000), Ok(()));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(
+                token.session_transfer(accounts.alice, accounts.charlie, 101),
+                Err(Error::SessionBudgetExceeded)
+            );
+            assert_eq!(token.session_transfer(accounts.alice, accounts.charlie, 60), Ok(()));
+            assert_eq!(token.get_session_key(accounts.alice, accounts.bob).map(|key| key.remaining), Some(40));
+            assert_eq!(
+                token.session_transfer(accounts.django, accounts.charlie, 1),
+                Err(Error::SessionKeyNotFound)
+            );
+
+            // Spending the whole budget retires the key
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.django);
+            assert_eq!(token.session_transfer(accounts.alice, accounts.charlie, 30), Ok(()));
+            assert_eq!(token.get_session_key(accounts.alice, accounts.django), None);
+
+            ink::env::test::set_block_timestamp::<CustomEnvironment>(1_000);
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(
+                token.session_transfer(accounts.alice, accounts.charlie, 10),
+                Err(Error::SessionKeyExpired)
+            );
+            assert_eq!(token.balance_of(accounts.charlie), 90);
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.revoke_session_key(accounts.bob), Ok(()));
+            assert_eq!(token.revoke_session_key(accounts.bob), Err(Error::SessionKeyNotFound));
+        }
+
+        #[ink::test]
+        fn transfer_and_call_rejects_before_calling_out() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let selector = ink::selector_bytes!("on_tokens_received");
+            assert_eq!(token.mint(accounts.alice, 100), Ok(()));
+
+            assert_eq!(
+                token.transfer_and_call(accounts.bob, 0, selector, Vec::new()),
+                Err(Error::InvalidAmount)
+            );
+            assert_eq!(
+                token.transfer_and_call(accounts.bob, 101, selector, Vec::new()),
+                Err(Error::InsufficientBalance)
+            );
+            assert_eq!(
+                token.transfer_and_call(accounts.bob, 10, selector, Vec::new()),
+                Err(Error::CalleeNotWhitelisted)
+            );
+
+            // A whitelisted recipient is not reached while another callback runs
+            assert_eq!(token.set_callback_gas_limit(accounts.bob, Some(1_000_000)), Ok(()));
+            assert_eq!(token.enter_non_reentrant(), Ok(()));
+            assert_eq!(
+                token.transfer_and_call(accounts.bob, 10, selector, Vec::new()),
+                Err(Error::ReentrancyDetected)
+            );
+        }
+
+        fn factory_config(owner: Option<AccountId>, allocations: Vec<(AccountId, Balance)>) -> TokenConfig {
+            TokenConfig {
+                name: Some(String::from("Child")),
+                symbol: Some(String::from("CHD")),
+                decimals: 6,
+                max_supply: Some(1_000),
+                owner,
+                fee_bps: 0,
+                fee_routes: Vec::new(),
+                paused: false,
+                allocations,
+            }
+        }
+
+        #[ink::test]
+        fn factory_config_sets_owner_cap_and_allocations() {
+            let accounts = accounts();
+            let allocations = vec![(accounts.charlie, 600), (accounts.django, 400)];
+            let config = factory_config(Some(accounts.bob), allocations);
+            let token = SimpleToken::new_with_config(config).expect("valid config");
+            assert_eq!(token.get_owner(), accounts.bob);
+            assert_eq!(token.token_symbol(), Some(String::from("CHD")));
+            assert_eq!(token.token_decimals(), 6);
+            assert_eq!(token.max_supply(), Some(1_000));
+            assert_eq!(token.balance_of(accounts.charlie), 600);
+            assert_eq!(token.total_supply(), 1_000);
+            assert_eq!(token.created_token_count(), 0);
+            assert_eq!(token.created_token(0), None);
+
+            // Genesis allocations respect the cap and fees must be fully routed
+            let over_cap = factory_config(None, vec![(accounts.charlie, 600), (accounts.django, 401)]);
+            assert_eq!(SimpleToken::new_with_config(over_cap).err(), Some(Error::CapExceeded));
+            let unrouted = TokenConfig {
+                fee_bps: 100,
+                ..factory_config(None, Vec::new())
+            };
+            assert_eq!(SimpleToken::new_with_config(unrouted).err(), Some(Error::InvalidFeeConfig));
+        }
+
+        #[ink::test]
+        fn ve_rewards_are_shared_by_locked_amount() {
+            let accounts = accounts();
+            let mut token = SimpleToken::new();
+            let contract = ink::env::account_id::<CustomEnvironment>();
+            ink::env::test::set_account_balance::<CustomEnvironment>(contract, 1_000);
+            assert_eq!(token.mint(accounts.alice, 300), Ok(()));
+            assert_eq!(token.mint(accounts.bob, 100), Ok(()));
+            assert_eq!(token.mint(accounts.charlie, 100), Ok(()));
+
+            // Nothing to share with while nobody has a lock
+            ink::env::test::set_value_transferred::<CustomEnvironment>(400);
+            assert_eq!(token.fund_ve_rewards(0), Err(Error::InvalidAmount));
+            ink::env::test::set_value_transferred::<CustomEnvironment>(0);
+
+            assert_eq!(token.create_lock(300, 1_000), Ok(()));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.create_lock(100, 1_000), Ok(()));
+            assert_eq!(token.fund_ve_rewards(0), Err(Error::Unauthorized));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            ink::env::test::set_value_transferred::<CustomEnvironment>(400);
+            assert_eq!(token.fund_ve_rewards(0), Ok(()));
+            ink::env::test::set_value_transferred::<CustomEnvironment>(0);
+            assert_eq!(token.fund_ve_rewards(50), Err(Error::NoRewardToken));
+            assert_eq!(token.pending_ve_rewards(accounts.alice), (300, 0));
+            assert_eq!(token.pending_ve_rewards(accounts.bob), (100, 0));
+
+            // A later locker does not share in rewards funded before it locked
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.charlie);
+            assert_eq!(token.create_lock(100, 1_000), Ok(()));
+            assert_eq!(token.pending_ve_rewards(accounts.charlie), (0, 0));
+            assert_eq!(token.claim_ve_rewards(), Err(Error::InvalidAmount));
+
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.bob);
+            assert_eq!(token.claim_ve_rewards(), Ok((100, 0)));
+            assert_eq!(token.claim_ve_rewards(), Err(Error::InvalidAmount));
+            ink::env::test::set_caller::<CustomEnvironment>(accounts.alice);
+            assert_eq!(token.claim_ve_rewards(), Ok((300, 0)));
+            assert_eq!(token.pending_ve_rewards(accounts.alice), (0, 0));
+        }
+    }
+
+    /// End-to-end tests run against a real `substrate-contracts-node`
+    ///
+    /// Run with `cargo test --features e2e-tests` and a node on the default port.
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test(environment = crate::CustomEnvironment)]
+        async fn deploy_sets_owner_and_empty_supply<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = SimpleTokenRef::new();
+            let contract = client
+                .instantiate("simple_token_balance", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let call_builder = contract.call_builder::<SimpleToken>();
+
+            // then
+            let owner = client
+                .call(&ink_e2e::bob(), &call_builder.get_owner())
+                .dry_run()
+                .await?;
+            assert_eq!(
+                owner.return_value(),
+                ink_e2e::account_id(ink_e2e::AccountKeyring::Alice)
+            );
+
+            let supply = client
+                .call(&ink_e2e::bob(), &call_builder.total_supply())
+                .dry_run()
+                .await?;
+            assert_eq!(supply.return_value(), 0);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test(environment = crate::CustomEnvironment)]
+        async fn mint_and_transfer_work<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            // given
+            let mut constructor = SimpleTokenRef::new();
+            let contract = client
+                .instantiate("simple_token_balance", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<SimpleToken>();
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            // when
+            let mint = call_builder.mint(alice, 1_000);
+            let mint_result = client
+                .call(&ink_e2e::alice(), &mint)
+                .submit()
+                .await
+                .expect("mint failed");
+            assert!(!mint_result.contract_emitted_events()?.is_empty());
+
+            let transfer = call_builder.transfer(bob, 400, Vec::new());
+            let transfer_result = client
+                .call(&ink_e2e::alice(), &transfer)
+                .submit()
+                .await
+                .expect("transfer failed");
+            assert!(!transfer_result.contract_emitted_events()?.is_empty());
+
+            // then
+            let alice_balance = client
+                .call(&ink_e2e::alice(), &call_builder.balance_of(alice))
+                .dry_run()
+                .await?;
+            assert_eq!(alice_balance.return_value(), 600);
+
+            let bob_balance = client
+                .call(&ink_e2e::alice(), &call_builder.balance_of(bob))
+                .dry_run()
+                .await?;
+            assert_eq!(bob_balance.return_value(), 400);
+
+            let supply = client
+                .call(&ink_e2e::alice(), &call_builder.total_supply())
+                .dry_run()
+                .await?;
+            assert_eq!(supply.return_value(), 1_000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test(environment = crate::CustomEnvironment)]
+        async fn only_owner_can_mint<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            // given
+            let mut constructor = SimpleTokenRef::new();
+            let contract = client
+                .instantiate("simple_token_balance", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<SimpleToken>();
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            // when
+            let mint = call_builder.mint(bob, 1_000);
+            let result = client.call(&ink_e2e::bob(), &mint).dry_run().await?;
+
+            // then
+            assert_eq!(result.return_value(), Err(Error::Unauthorized));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test(environment = crate::CustomEnvironment)]
+        async fn approve_and_transfer_from_work<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = SimpleTokenRef::new();
+            let contract = client
+                .instantiate("simple_token_balance", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<SimpleToken>();
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+
+            client
+                .call(&ink_e2e::alice(), &call_builder.mint(alice, 1_000))
+                .submit()
+                .await
+                .expect("mint failed");
+
+            // when
+            client
+                .call(&ink_e2e::alice(), &call_builder.approve(bob, 300))
+                .submit()
+                .await
+                .expect("approve failed");
+
+            let over_allowance = call_builder.transfer_from(alice, charlie, 301, Vec::new());
+            let rejected = client
+                .call(&ink_e2e::bob(), &over_allowance)
+                .dry_run()
+                .await?;
+            assert_eq!(rejected.return_value(), Err(PSP22Error::InsufficientAllowance));
+
+            client
+                .call(&ink_e2e::bob(), &call_builder.transfer_from(alice, charlie, 200, Vec::new()))
+                .submit()
+                .await
+                .expect("transfer_from failed");
+
+            // then
+            let allowance = client
+                .call(&ink_e2e::alice(), &call_builder.allowance(alice, bob))
+                .dry_run()
+                .await?;
+            assert_eq!(allowance.return_value(), 100);
+
+            let charlie_balance = client
+                .call(&ink_e2e::alice(), &call_builder.balance_of(charlie))
+                .dry_run()
+                .await?;
+            assert_eq!(charlie_balance.return_value(), 200);
+
+            Ok(())
+        }
+
+        #[cfg(feature = "pause")]
+        #[ink_e2e::test(environment = crate::CustomEnvironment)]
+        async fn pause_blocks_transfers<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            // given
+            let mut constructor = SimpleTokenRef::new();
+            let contract = client
+                .instantiate("simple_token_balance", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<SimpleToken>();
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            client
+                .call(&ink_e2e::alice(), &call_builder.mint(alice, 1_000))
+                .submit()
+                .await
+                .expect("mint failed");
+
+            // when
+            client
+                .call(&ink_e2e::alice(), &call_builder.pause())
+                .submit()
+                .await
+                .expect("pause failed");
+
+            // then
+            let transfer = call_builder.transfer(bob, 100, Vec::new());
+            let paused = client.call(&ink_e2e::alice(), &transfer).dry_run().await?;
+            assert_eq!(paused.return_value(), Err(PSP22Error::Custom(String::from("ContractPaused"))));
+
+            client
+                .call(&ink_e2e::alice(), &call_builder.unpause())
+                .submit()
+                .await
+                .expect("unpause failed");
+
+            let resumed = client.call(&ink_e2e::alice(), &transfer).dry_run().await?;
+            assert_eq!(resumed.return_value(), Ok(()));
+
+            Ok(())
+        }
+
+        #[cfg(feature = "blacklist")]
+        #[ink_e2e::test(environment = crate::CustomEnvironment)]
+        async fn blacklist_blocks_both_directions<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = SimpleTokenRef::new();
+            let contract = client
+                .instantiate("simple_token_balance", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<SimpleToken>();
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            client
+                .call(&ink_e2e::alice(), &call_builder.mint(alice, 1_000))
+                .submit()
+                .await
+                .expect("mint failed");
+            client
+                .call(&ink_e2e::alice(), &call_builder.mint(bob, 1_000))
+                .submit()
+                .await
+                .expect("mint failed");
+
+            // when
+            client
+                .call(&ink_e2e::alice(), &call_builder.add_to_blacklist(bob))
+                .submit()
+                .await
+                .expect("blacklist failed");
+
+            // then
+            let to_bob = client
+                .call(&ink_e2e::alice(), &call_builder.transfer(bob, 100, Vec::new()))
+                .dry_run()
+                .await?;
+            assert_eq!(to_bob.return_value(), Err(PSP22Error::Custom(String::from("AccountBlacklisted"))));
+
+            let from_bob = client
+                .call(&ink_e2e::bob(), &call_builder.transfer(alice, 100, Vec::new()))
+                .dry_run()
+                .await?;
+            assert_eq!(from_bob.return_value(), Err(PSP22Error::Custom(String::from("AccountBlacklisted"))));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test(environment = crate::CustomEnvironment)]
+        async fn factory_deploys_configured_token<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given
+            let mut constructor = SimpleTokenRef::new();
+            let contract = client
+                .instantiate("simple_token_balance", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<SimpleToken>();
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+            let config = TokenConfig {
+                name: Some(String::from("Child")),
+                symbol: Some(String::from("CHD")),
+                decimals: 6,
+                max_supply: Some(1_000),
+                owner: None,
+                fee_bps: 0,
+                fee_routes: Vec::new(),
+                paused: false,
+                allocations: vec![(charlie, 500)],
+            };
+
+            // when
+            let created = client
+                .call(&ink_e2e::bob(), &call_builder.create_token(config))
+                .submit()
+                .await
+                .expect("create_token failed");
+            let token = created.return_value().expect("token not created");
+
+            // then
+            let stored = client
+                .call(&ink_e2e::alice(), &call_builder.created_token(0))
+                .dry_run()
+                .await?;
+            assert_eq!(stored.return_value(), Some(token));
+
+            let count = client
+                .call(&ink_e2e::alice(), &call_builder.created_token_count())
+                .dry_run()
+                .await?;
+            assert_eq!(count.return_value(), 1);
+
+            // The caller owns the new token, which starts with its allocations
+            let child = ink_e2e::create_call_builder::<SimpleToken>(token);
+            let owner = client
+                .call(&ink_e2e::alice(), &child.get_owner())
+                .dry_run()
+                .await?;
+            assert_eq!(owner.return_value(), bob);
+
+            let charlie_balance = client
+                .call(&ink_e2e::alice(), &child.balance_of(charlie))
+                .dry_run()
+                .await?;
+            assert_eq!(charlie_balance.return_value(), 500);
+
+            Ok(())
         }
     }
 }
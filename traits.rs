@@ -0,0 +1,114 @@
+//! Interfaces implemented by the token
+//!
+//! These are the canonical on-chain ABI: the PSP22 messages are only exposed
+//! as `PSP22::*` selectors, with no inherent duplicates. Other contracts can
+//! call the token through `contract_ref!(PSP22)` and friends, or implement
+//! these traits themselves to expose the same ABI.
+//!
+//! Only the interfaces are reusable so far. The token's logic still lives in
+//! the contract; there are no storage-generic default implementations
+//! (pendzl/openbrush style) a downstream contract could embed and extend.
+
+use ink::prelude::vec::Vec;
+
+use crate::environment::{AccountId, Balance};
+use crate::errors::{PSP22Error, Result};
+use crate::types::RoleId;
+
+/// Standard PSP22 fungible token interface
+#[ink::trait_definition]
+pub trait PSP22 {
+    /// Get the total token supply
+    #[ink(message)]
+    fn total_supply(&self) -> Balance;
+
+    /// Get the balance of `owner`
+    #[ink(message)]
+    fn balance_of(&self, owner: AccountId) -> Balance;
+
+    /// Get how much `spender` may still spend on behalf of `owner`
+    #[ink(message)]
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance;
+
+    /// Transfer `value` tokens from the caller to `to`
+    #[ink(message)]
+    fn transfer(&mut self, to: AccountId, value: Balance, data: Vec<u8>) -> core::result::Result<(), PSP22Error>;
+
+    /// Transfer `value` tokens from `from` to `to` using the caller's allowance
+    #[ink(message)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> core::result::Result<(), PSP22Error>;
+
+    /// Allow `spender` to spend up to `value` of the caller's tokens
+    #[ink(message)]
+    fn approve(&mut self, spender: AccountId, value: Balance) -> core::result::Result<(), PSP22Error>;
+
+    /// Raise the allowance of `spender` by `delta_value`
+    #[ink(message)]
+    fn increase_allowance(&mut self, spender: AccountId, delta_value: Balance) -> core::result::Result<(), PSP22Error>;
+
+    /// Lower the allowance of `spender` by `delta_value`
+    #[ink(message)]
+    fn decrease_allowance(&mut self, spender: AccountId, delta_value: Balance) -> core::result::Result<(), PSP22Error>;
+}
+
+/// Single-owner administration with a timed handover
+#[ink::trait_definition]
+pub trait Ownable {
+    /// Get the current owner
+    #[ink(message)]
+    fn owner(&self) -> AccountId;
+
+    /// Schedule handing ownership to `new_owner` at `effective_at`
+    #[ink(message)]
+    fn schedule_ownership_transfer(&mut self, new_owner: AccountId, effective_at: u64) -> Result<()>;
+
+    /// Cancel the scheduled ownership handover
+    #[ink(message)]
+    fn cancel_ownership_transfer(&mut self) -> Result<()>;
+
+    /// Complete the scheduled handover once it is effective
+    #[ink(message)]
+    fn finalize_ownership_transfer(&mut self) -> Result<()>;
+}
+
+/// Global pause switch
+#[ink::trait_definition]
+pub trait Pausable {
+    /// Pause all transfers
+    #[ink(message)]
+    fn pause(&mut self) -> Result<()>;
+
+    /// Resume transfers
+    #[ink(message)]
+    fn unpause(&mut self) -> Result<()>;
+
+    /// Check whether the contract is paused
+    #[ink(message)]
+    fn is_paused(&self) -> bool;
+}
+
+/// Role-based access control
+#[ink::trait_definition]
+pub trait AccessControl {
+    /// Check whether an account holds a role
+    #[ink(message)]
+    fn has_role(&self, role: RoleId, account: AccountId) -> bool;
+
+    /// Get the admin role of a role (None = owner only)
+    #[ink(message)]
+    fn get_role_admin(&self, role: RoleId) -> Option<RoleId>;
+
+    /// Give an account a role
+    #[ink(message)]
+    fn grant_role(&mut self, role: RoleId, account: AccountId) -> Result<()>;
+
+    /// Take a role away from an account
+    #[ink(message)]
+    fn revoke_role(&mut self, role: RoleId, account: AccountId) -> Result<()>;
+}
@@ -0,0 +1,770 @@
+//! Data types stored by the token and passed to its messages
+
+use ink::prelude::{string::String, vec::Vec};
+
+use crate::environment::{AccountId, Balance, BlockNumber, Hash};
+
+/// Identifier of an access-control role
+pub type RoleId = u32;
+
+/// Identifier of a chain tokens are bridged to
+pub type ChainId = u32;
+
+/// An offer to swap escrowed tokens for another PSP22 token
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct SwapOffer {
+    /// Account that escrowed the tokens
+    pub maker: AccountId,
+    /// Amount of this token escrowed
+    pub amount: Balance,
+    /// PSP22 token wanted in return
+    pub ask_token: AccountId,
+    /// Amount of `ask_token` wanted
+    pub ask_amount: Balance,
+    /// Only account allowed to accept (None = anyone)
+    pub taker: Option<AccountId>,
+    /// Time after which the offer can only be refunded
+    pub expires_at: u64,
+}
+
+/// Tokens locked until a preimage is revealed or the timelock passes
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct Htlc {
+    /// Account that locked the tokens and gets them back after expiry
+    pub sender: AccountId,
+    /// Account that can claim with the preimage
+    pub recipient: AccountId,
+    /// Amount locked
+    pub amount: Balance,
+    /// SHA-256 hash of the secret preimage
+    pub hashlock: [u8; 32],
+    /// Time from which the sender can refund
+    pub timelock: u64,
+}
+
+/// An invoice a payer can settle with one call
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct PaymentRequest {
+    /// Account that will be paid
+    pub payee: AccountId,
+    /// Account asked to pay
+    pub payer: AccountId,
+    /// Amount requested
+    pub amount: Balance,
+    /// Free-form reference (e.g. invoice number)
+    pub memo: Vec<u8>,
+    /// When the request stops being payable
+    pub expires_at: u64,
+}
+
+/// Everything `new_with_config` sets up at deployment
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct TokenConfig {
+    /// Token name
+    pub name: Option<String>,
+    /// Token symbol
+    pub symbol: Option<String>,
+    /// Number of decimals used for display
+    pub decimals: u8,
+    /// Supply cap (None = uncapped)
+    pub max_supply: Option<Balance>,
+    /// Initial owner (None = the deployer)
+    pub owner: Option<AccountId>,
+    /// Fee in basis points of each transfer
+    pub fee_bps: u16,
+    /// Fee routing table, in basis points of the fee
+    pub fee_routes: Vec<(FeeDestination, u16)>,
+    /// Whether the token starts paused
+    pub paused: bool,
+    /// Tokens minted at deployment (account, amount)
+    pub allocations: Vec<(AccountId, Balance)>,
+}
+
+/// An ownership handover waiting for its effective time
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct PendingOwnershipTransfer {
+    /// Account that will become owner
+    pub new_owner: AccountId,
+    /// Time from which the handover can be finalized
+    pub effective_at: u64,
+}
+
+/// Guardians an account trusts to freeze and unfreeze it
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct AccountGuardians {
+    /// Guardian accounts
+    pub guardians: Vec<AccountId>,
+    /// Votes needed to freeze or unfreeze
+    pub threshold: u32,
+}
+
+/// A secondary key allowed to spend a limited budget for an account
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct SessionKey {
+    /// Tokens the key can still spend
+    pub remaining: Balance,
+    /// Time after which the key can no longer be used
+    pub expires_at: u64,
+}
+
+/// One operation inside a `multicall`, run on behalf of the caller
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum Call {
+    /// `transfer(to, amount)`
+    Transfer { to: AccountId, amount: Balance },
+    /// `transfer_from(from, to, amount)`
+    TransferFrom {
+        from: AccountId,
+        to: AccountId,
+        amount: Balance,
+    },
+    /// `approve(spender, amount)`
+    Approve { spender: AccountId, amount: Balance },
+    /// `burn(amount)`
+    Burn { amount: Balance },
+    /// `deposit_to_vault(amount)`
+    DepositToVault { amount: Balance },
+    /// `withdraw_from_vault(shares)`
+    WithdrawFromVault { shares: Balance },
+}
+
+/// How transfers to contract accounts are treated
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum ContractRecipientPolicy {
+    /// Any contract can receive tokens
+    #[default]
+    Allow,
+    /// No contract can receive tokens
+    Deny,
+    /// Only contracts whose code hash is on the accept-list can receive tokens
+    AcceptList,
+}
+
+/// Where a share of the transfer fee goes
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum FeeDestination {
+    /// Credited to an account (e.g. a charity)
+    Account(AccountId),
+    /// Credited to the contract-held treasury
+    Treasury,
+    /// Removed from the supply
+    Burn,
+    /// Shared pro-rata among all holders
+    Reflection,
+    /// Paid into the savings vault, raising the share price
+    Vault,
+    /// Paid into the lottery pot
+    Lottery,
+}
+
+/// A proposed payment out of the contract-held treasury
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct TreasurySpend {
+    /// Account that will be paid
+    pub to: AccountId,
+    /// Amount to pay
+    pub amount: Balance,
+    /// Earliest time the spend can be executed
+    pub executable_at: u64,
+}
+
+/// Tokens escrowed for a delayed burn (redemption)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct BurnRequest {
+    /// Account that requested the burn
+    pub from: AccountId,
+    /// Amount in escrow
+    pub amount: Balance,
+    /// Earliest time the burn can be finalized
+    pub ready_at: u64,
+}
+
+/// A mint proposed by a MINTER and waiting for an APPROVER
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct MintRequest {
+    /// Account that will receive the tokens
+    pub to: AccountId,
+    /// Amount to mint
+    pub amount: Balance,
+    /// Minter that created the request
+    pub requested_by: AccountId,
+    /// When the request stops being approvable
+    pub expires_at: u64,
+}
+
+/// Linear vesting lock on freshly minted tokens
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct VestingSchedule {
+    /// Total amount under vesting
+    pub amount: Balance,
+    /// When vesting starts (ms)
+    pub start: u64,
+    /// Time (ms) after `start` before anything unlocks
+    pub cliff: u64,
+    /// Time (ms) after `start` when everything is unlocked
+    pub duration: u64,
+}
+
+/// A supply cap change queued behind `CAP_CHANGE_DELAY`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct PendingCapChange {
+    /// Cap that will apply (None = uncapped)
+    pub new_cap: Option<Balance>,
+    /// Earliest time the change can be applied
+    pub effective_at: u64,
+}
+
+/// Privileged operation that the multisig can propose and execute
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum AdminAction {
+    /// Mint new tokens to an account
+    Mint { to: AccountId, amount: Balance },
+    /// Pause all transfers
+    #[cfg(feature = "pause")]
+    Pause,
+    /// Resume transfers
+    #[cfg(feature = "pause")]
+    Unpause,
+    /// Add an account to the blacklist
+    #[cfg(feature = "blacklist")]
+    Blacklist(AccountId),
+    /// Remove an account from the blacklist
+    #[cfg(feature = "blacklist")]
+    Unblacklist(AccountId),
+    /// Change the admin set and threshold (no admins and threshold 0 hand control back to the owner)
+    SetMultisig { admins: Vec<AccountId>, threshold: u32 },
+    /// Change the transfer fee configuration
+    #[cfg(feature = "fees")]
+    ConfigureFees {
+        fee_bps: u16,
+        routes: Vec<(FeeDestination, u16)>,
+    },
+    /// Pay out of the treasury (the multisig replaces the timelock)
+    TreasurySpend { to: AccountId, amount: Balance },
+    /// `safe_mint(to, amount, data)`
+    SafeMint {
+        to: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    },
+    /// `mint_with_vesting(to, amount, start, cliff, duration)`
+    MintWithVesting {
+        to: AccountId,
+        amount: Balance,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+    },
+    /// `escalate_blacklist(account)`
+    #[cfg(feature = "blacklist")]
+    EscalateBlacklist(AccountId),
+    /// `set_minter_allowance(minter, amount)`
+    SetMinterAllowance { minter: AccountId, amount: Balance },
+    /// `set_minter_daily_quota(minter, quota)`
+    SetMinterDailyQuota {
+        minter: AccountId,
+        quota: Option<Balance>,
+    },
+    /// `set_treasury_spend_limit(limit)`
+    SetTreasurySpendLimit(Option<(u16, u64)>),
+    /// `buyback_and_burn(max_spend, min_tokens_out)`
+    BuybackAndBurn {
+        max_spend: Balance,
+        min_tokens_out: Balance,
+    },
+    /// `configure_lottery(epoch_length)`
+    ConfigureLottery { epoch_length: u64 },
+    /// `set_incident_mode(active)`
+    SetIncidentMode { active: bool },
+    /// `set_circuit_breaker(threshold, window)`
+    #[cfg(feature = "pause")]
+    SetCircuitBreaker {
+        threshold: Option<Balance>,
+        window: u64,
+    },
+    /// `set_bridge(bridge, cap)`
+    SetBridge { bridge: AccountId, cap: Balance },
+    /// `remove_bridge(bridge)`
+    RemoveBridge(AccountId),
+    /// `set_bridge_rate_limits(bridge, mint, burn)`
+    SetBridgeRateLimits {
+        bridge: AccountId,
+        mint: Option<(Balance, u64)>,
+        burn: Option<(Balance, u64)>,
+    },
+    /// `set_balance_observer(observer)`
+    SetBalanceObserver(Option<AccountId>),
+    /// `set_callback_gas_limit(callee, gas_limit)`
+    SetCallbackGasLimit {
+        callee: AccountId,
+        gas_limit: Option<u64>,
+    },
+    /// `configure_emission(distributor, amount_per_era, era_length, halving_interval)`
+    ConfigureEmission {
+        distributor: Option<AccountId>,
+        amount_per_era: Balance,
+        era_length: BlockNumber,
+        halving_interval: BlockNumber,
+    },
+    /// `queue_cap_change(new_cap)`
+    QueueCapChange(Option<Balance>),
+    /// `cancel_cap_change()`
+    CancelCapChange,
+    /// `set_token_uri(uri)`
+    SetTokenUri(Option<String>),
+    /// `set_selector_paused(selector, paused)`
+    SetSelectorPaused { selector: [u8; 4], paused: bool },
+    /// `set_selector_access(selector, role)`
+    SetSelectorAccess {
+        selector: [u8; 4],
+        role: Option<RoleId>,
+    },
+    /// `set_chain_genesis(genesis_hash)`
+    SetChainGenesis(Hash),
+    /// `set_guardian(guardian)`
+    SetGuardian(Option<AccountId>),
+    /// `set_blacklist_grace_period(period)`
+    #[cfg(feature = "blacklist")]
+    SetBlacklistGracePeriod(u64),
+    /// `rescue_token(token_address, to, amount)`
+    RescueToken {
+        token_address: AccountId,
+        to: AccountId,
+        amount: Balance,
+    },
+    /// `withdraw_native(to, amount)`
+    WithdrawNative { to: AccountId, amount: Balance },
+    /// `set_role_admin(role, admin_role)`
+    SetRoleAdmin {
+        role: RoleId,
+        admin_role: Option<RoleId>,
+    },
+    /// `AccessControl::grant_role(role, account)`
+    GrantRole { role: RoleId, account: AccountId },
+    /// `AccessControl::revoke_role(role, account)`
+    RevokeRole { role: RoleId, account: AccountId },
+    /// `set_mint_request_ttl(ttl)`
+    SetMintRequestTtl(u64),
+    /// `set_burn_request_delay(delay)`
+    SetBurnRequestDelay(u64),
+    /// `set_fee_discount_tiers(tiers)`
+    #[cfg(feature = "fees")]
+    SetFeeDiscountTiers(Vec<(Balance, u16)>),
+    /// `cancel_treasury_spend(spend_id)`
+    CancelTreasurySpend(u32),
+    /// `set_buyback_router(router)`
+    SetBuybackRouter(Option<(AccountId, [u8; 4])>),
+    /// `configure_oracle(oracle, max_age, fallback_price)`
+    ConfigureOracle {
+        oracle: Option<AccountId>,
+        max_age: u64,
+        fallback_price: Option<Balance>,
+    },
+    /// `enable_trading()`
+    EnableTrading,
+    /// `set_launch_blocks(launch_blocks)`
+    SetLaunchBlocks(BlockNumber),
+    /// `set_launch_whitelisted(account, whitelisted)`
+    SetLaunchWhitelisted { account: AccountId, whitelisted: bool },
+    /// `set_sell_limit(limit, window_blocks)`
+    SetSellLimit {
+        limit: Option<Balance>,
+        window_blocks: BlockNumber,
+    },
+    /// `set_market_account(account, market)`
+    SetMarketAccount { account: AccountId, market: bool },
+    /// `set_sell_limit_exempt(account, exempt)`
+    SetSellLimitExempt { account: AccountId, exempt: bool },
+    /// `set_contract_recipient_policy(policy)`
+    SetContractRecipientPolicy(ContractRecipientPolicy),
+    /// `set_code_hash_accepted(code_hash, accepted)`
+    SetCodeHashAccepted { code_hash: Hash, accepted: bool },
+    /// `reindex_holders(start, limit)`
+    ReindexHolders { start: u32, limit: u32 },
+    /// `set_claim_sponsor(sponsor, allowed)`
+    SetClaimSponsor { sponsor: AccountId, allowed: bool },
+    /// `deposit_dividend(expires_at)`, paid from native currency the contract holds
+    DepositDividend { amount: Balance, expires_at: u64 },
+    /// `sweep_dividend(id, to)`
+    SweepDividend { id: u32, to: AccountId },
+    /// `snapshot()`
+    Snapshot,
+    /// `set_reward_token(token, rewards_account)`
+    SetRewardToken {
+        token: Option<AccountId>,
+        rewards_account: Option<AccountId>,
+    },
+    /// `fund_ve_rewards(token_amount)`, with the native part taken from currency the contract holds
+    FundVeRewards {
+        native_amount: Balance,
+        token_amount: Balance,
+    },
+    /// `set_large_transfer_threshold(threshold)`
+    SetLargeTransferThreshold(Option<Balance>),
+    /// `set_ownership_probe_required(required)`
+    SetOwnershipProbeRequired(bool),
+    /// `set_recovery_guardians(guardians, threshold, window, delay)`
+    SetRecoveryGuardians {
+        guardians: Vec<AccountId>,
+        threshold: u32,
+        window: u64,
+        delay: u64,
+    },
+    /// `cancel_recovery()`
+    CancelRecovery,
+    /// `configure_dead_man_switch(successor, interval)`
+    ConfigureDeadManSwitch {
+        successor: Option<AccountId>,
+        interval: u64,
+    },
+    /// `heartbeat()`
+    Heartbeat,
+    /// `Ownable::schedule_ownership_transfer(new_owner, effective_at)`
+    ScheduleOwnershipTransfer { new_owner: AccountId, effective_at: u64 },
+    /// `Ownable::cancel_ownership_transfer()`
+    CancelOwnershipTransfer,
+    /// `set_incident_recovery_address(recovery_address)`
+    SetIncidentRecoveryAddress(Option<AccountId>),
+}
+
+/// A multisig proposal waiting for confirmations
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct Proposal {
+    /// What will be executed once the threshold is reached
+    pub action: AdminAction,
+    /// Admin that created the proposal
+    pub proposer: AccountId,
+    /// Number of admins that confirmed so far
+    pub confirmations: u32,
+    /// Whether the action has already been executed
+    pub executed: bool,
+    /// Admin set the proposal was created under
+    pub epoch: u32,
+}
+
+/// An ownership recovery collecting guardian approvals
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct RecoveryRequest {
+    /// Round number used to key the approvals
+    pub round: u32,
+    /// Account that will become owner
+    pub new_owner: AccountId,
+    /// When the first approval came in
+    pub started_at: u64,
+    /// Approvals collected so far
+    pub approvals: u32,
+    /// When the threshold was reached (None = still collecting)
+    pub approved_at: Option<u64>,
+}
+
+/// Privileged operation recorded in the admin audit log
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum AdminLogAction {
+    /// Tokens were minted by the owner, the multisig or an approver
+    Mint { to: AccountId, amount: Balance },
+    /// Transfers were paused
+    Pause,
+    /// Transfers were resumed
+    Unpause,
+    /// An account was blacklisted
+    Blacklist(AccountId),
+    /// An account was removed from the blacklist
+    Unblacklist(AccountId),
+    /// The transfer fee configuration changed
+    ConfigureFees { fee_bps: u16 },
+    /// A role was granted
+    GrantRole { role: RoleId, account: AccountId },
+    /// A role was revoked
+    RevokeRole { role: RoleId, account: AccountId },
+    /// Incident mode was switched on or off
+    IncidentMode { active: bool },
+    /// An account was scheduled to be blacklisted once the grace period runs out
+    ScheduleBlacklist { account: AccountId, effective_at: u64 },
+    /// The metadata URI was set or cleared
+    SetTokenUri(Option<String>),
+}
+
+/// One entry of the admin audit log
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct AdminLogEntry {
+    /// Account that performed the action
+    pub actor: AccountId,
+    /// What was done
+    pub action: AdminLogAction,
+    /// When it was done
+    pub timestamp: u64,
+}
+
+/// Per-sender limits above which an account is flagged as suspicious
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct VelocityLimits {
+    /// Window length in milliseconds
+    pub window: u64,
+    /// Most sends allowed per window
+    pub max_sends: u32,
+    /// Most tokens sent per window
+    pub max_volume: Balance,
+    /// Freeze flagged accounts until COMPLIANCE releases them
+    pub auto_freeze: bool,
+}
+
+/// A sender's activity in its current velocity window
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct SendActivity {
+    /// When the window started
+    pub window_start: u64,
+    /// Sends in this window
+    pub sends: u32,
+    /// Tokens sent in this window
+    pub volume: Balance,
+    /// Whether the account was already flagged in this window
+    pub flagged: bool,
+}
+
+/// A bridge allowed to mint and burn against its own cap
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct Bridge {
+    /// Most this bridge may have outstanding
+    pub cap: Balance,
+    /// Minted by this bridge and not yet burned
+    pub outstanding: Balance,
+    /// Limit on how fast the bridge can mint (None = only the cap applies)
+    pub mint_rate: Option<RateLimit>,
+    /// Limit on how fast the bridge can burn (None = unlimited)
+    pub burn_rate: Option<RateLimit>,
+}
+
+/// Token bucket that refills linearly to `limit` over `period`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct RateLimit {
+    /// Most that can be used within one period
+    pub limit: Balance,
+    /// Refill period in milliseconds (e.g. one hour or one day)
+    pub period: u64,
+    /// Amount left as of `updated_at`
+    pub available: Balance,
+    /// When `available` was last brought up to date
+    pub updated_at: u64,
+}
+
+/// Tokens locked for voting weight until `end`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct VeLock {
+    /// Locked tokens
+    pub amount: Balance,
+    /// When the tokens unlock
+    pub end: u64,
+}
+
+/// Tokens escrowed for accounts listed in a merkle tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct Airdrop {
+    /// Account that funded the airdrop
+    pub creator: AccountId,
+    /// Root of the (account, amount) allocation tree
+    pub merkle_root: [u8; 32],
+    /// Hash function the tree was built with
+    pub hash: MerkleHash,
+    /// Tokens not yet claimed
+    pub remaining: Balance,
+    /// Largest share of an allocation (bps) a sponsor may keep as a tip
+    pub max_tip_bps: u16,
+}
+
+/// Hash function used for the leaves and nodes of a merkle tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum MerkleHash {
+    /// Blake2-256, the Substrate default
+    Blake2x256,
+    /// Keccak-256, as produced by EVM tooling
+    Keccak256,
+}
+
+/// Kind of signed operation; each kind has its own nonce sequence per account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum NonceKind {
+    /// Signed allowances (`permit_ecdsa`)
+    Permit,
+    /// Relayed transfers (`transfer_with_authorization`)
+    MetaTx,
+    /// Signed cheques (`cash_cheque`)
+    Cheque,
+    /// Signed delegations
+    Delegation,
+}
+
+/// Result of a `transfer_idempotent` call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum TransferOutcome {
+    /// Tokens were moved
+    Executed,
+    /// The key was already used; nothing was moved
+    Duplicate,
+}
+
+/// Native currency paid out pro rata to holders at a balance snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct Dividend {
+    /// Snapshot the shares are measured against
+    pub snapshot_id: u32,
+    /// Native currency deposited
+    pub amount: Balance,
+    /// Token supply sharing in the dividend at the snapshot
+    pub eligible_supply: Balance,
+    /// Native currency claimed so far
+    pub claimed: Balance,
+    /// Timestamp after which claims stop and the rest can be swept
+    pub expires_at: u64,
+}
+
+/// What changed an allowance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum AllowanceChangeCause {
+    /// `approve` or `approve_from_to`
+    Approve,
+    /// `increase_allowance`
+    Increase,
+    /// `decrease_allowance`
+    Decrease,
+    /// A signed permit
+    Permit,
+    /// The spender used part of it
+    Spend,
+}
+
+/// Progress of one vesting schedule, as returned by `vesting_of`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct VestingInfo {
+    /// Total amount under vesting
+    pub amount: Balance,
+    /// When vesting starts (ms)
+    pub start: u64,
+    /// When the cliff ends and tokens start to unlock (ms)
+    pub cliff_end: u64,
+    /// When everything is unlocked (ms)
+    pub end: u64,
+    /// Amount unlocked so far
+    pub unlocked: Balance,
+    /// Amount still locked
+    pub locked: Balance,
+}
+
+/// What holds tokens in a `LockInfo`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum LockKind {
+    /// Tokens locked in the vote escrow
+    VoteEscrow,
+    /// Balance held by a guardian freeze, risk freeze or blacklisting
+    Freeze,
+}
+
+/// One lock on an account's tokens, as returned by `locks_of`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct LockInfo {
+    /// What holds the tokens
+    pub kind: LockKind,
+    /// Amount held
+    pub amount: Balance,
+    /// When the tokens unlock (None = until released)
+    pub unlock_at: Option<u64>,
+}
+
+/// Outcome of a seizure case
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum SeizureStatus {
+    /// Tokens are in escrow
+    Open,
+    /// Appealed and returned to the account
+    Reversed,
+    /// Burned after the dispute window
+    Burned,
+    /// Sent to another account after the dispute window
+    Redistributed { to: AccountId },
+}
+
+/// Tokens seized from an account and held through a dispute window
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct SeizureCase {
+    /// Account the tokens were taken from
+    pub account: AccountId,
+    /// Amount seized
+    pub amount: Balance,
+    /// Free-form reason code
+    pub reason: Vec<u8>,
+    /// When the seizure happened
+    pub seized_at: u64,
+    /// End of the window in which the seizure can be reversed
+    pub dispute_ends: u64,
+    /// Current outcome
+    pub status: SeizureStatus,
+}